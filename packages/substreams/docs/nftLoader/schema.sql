@@ -13,25 +13,260 @@ create table transfers
     contract_address    text,
     tx_hash             text,
     ordinal             text,
-    timestamp           text
+    timestamp           text,
+    held_by_contract    text,
+    held_by_token_id    text,
+    tx_from             text,
+    tx_to               text,
+    tx_value            text,
+    tx_gas_used         text,
+    tx_gas_price        text,
+    tx_selector         text,
+    call_index          text,
+    call_depth          text,
+    call_caller         text,
+    call_selector       text,
+    call_method         text
 );
 
 create table nfts
 (
-    id          text not null constraint nft_transfer_pk primary key,
-    contract    text,
-    token_id    text,
-    owner       text,
-    tokenUri    text,
-    metadata    text
+    id                  text not null constraint nft_transfer_pk primary key,
+    contract            text,
+    token_id            text,
+    owner               text,
+    tokenUri            text,
+    canonical_uri       text,
+    metadata            text,
+    schema              text,
+    royalty_receiver    text,
+    royalty_bps         text,
+    block_number        text,
+    ordinal             text,
+    timestamp           text,
+    name                text,
+    image               text,
+    attributes          text
 );
 
 create table contracts
 (
-    id          text not null constraint contract_pk primary key,
-    contract    text,
-    base_uri    text,
-    deployer    text
+    id                  text not null constraint contract_pk primary key,
+    contract            text,
+    base_uri            text,
+    deployer            text,
+    schema              text,
+    supports_royalties  text,
+    royalty_receiver    text,
+    royalty_bps         text,
+    block_number        text,
+    ordinal             text,
+    timestamp           text,
+    name                text,
+    symbol              text,
+    total_supply        text,
+    interfaces          text,
+    owner               text,
+    admin               text,
+    implementation      text,
+    beacon              text
+);
+
+create table sales
+(
+    id                  text not null constraint sales_pk primary key,
+    marketplace         text,
+    order_hash          text,
+    maker               text,
+    taker               text,
+    seller              text,
+    buyer               text,
+    schema              text,
+    contract_address    text,
+    token_id            text,
+    quantity            text,
+    payment_token       text,
+    price               text,
+    protocol_fee        text,
+    royalty_fee         text,
+    tx_hash             text,
+    block_number        text,
+    ordinal             text,
+    timestamp           text,
+    confidence          text,
+    bundle_id           text,
+    bundle_size         text,
+    bundle_price        text,
+    allocation          text,
+    eth_usd_price       text,
+    price_usd           text,
+    wash_score          text,
+    wash_reasons        text
+);
+
+create table collection_daily_market
+(
+    id                  text not null constraint collection_daily_market_pk primary key,
+    contract            text,
+    day                 text,
+    sale_count          text,
+    volume              text,
+    volume_usd          text,
+    min_price           text,
+    max_price           text,
+    median_price        text,
+    average_price       text
+);
+
+create table contract_spam_signals
+(
+    id                  text not null constraint contract_spam_signals_pk primary key,
+    contract            text,
+    transfer_count      text,
+    sale_count          text,
+    max_mint_fan_out    text,
+    max_airdrop_fan_out text,
+    phishing_name       text
+);
+
+create table pools
+(
+    id                  text not null constraint pools_pk primary key,
+    amm                 text,
+    nft_contract        text,
+    payment_token       text,
+    tx_hash             text,
+    block_number        text,
+    ordinal             text,
+    timestamp           text
+);
+
+create table pool_inventory
+(
+    id                  text not null constraint pool_inventory_pk primary key,
+    pool                text,
+    contract            text,
+    token_id            text,
+    quantity            text
+);
+
+create table mints
+(
+    id                  text not null constraint mints_pk primary key,
+    schema              text,
+    contract_address    text,
+    token_id            text,
+    quantity            text,
+    minter              text,
+    payer               text,
+    payment_token       text,
+    tx_price            text,
+    tx_quantity         text,
+    price_per_token     text,
+    eth_usd_price       text,
+    price_per_token_usd text,
+    free                text,
+    tx_gas_used         text,
+    tx_gas_price        text,
+    tx_hash             text,
+    block_number        text,
+    ordinal             text,
+    timestamp           text
+);
+
+create table order_invalidations
+(
+    id                  text not null constraint order_invalidations_pk primary key,
+    marketplace         text,
+    kind                text,
+    maker               text,
+    order_hash          text,
+    nonce               text,
+    tx_hash             text,
+    block_number        text,
+    ordinal             text,
+    timestamp           text
+);
+
+create table profile_associations
+(
+    id              text not null constraint profile_associations_pk primary key,
+    profile_url     text,
+    kind            text,
+    address         text,
+    profile_owner   text
+);
+
+create table ownership_intervals
+(
+    id              text not null constraint ownership_intervals_pk primary key,
+    contract        text,
+    token_id        text,
+    owner           text,
+    start_block     text,
+    start_time      text,
+    end_block       text,
+    end_time        text
+);
+
+create table nft_users
+(
+    id              text not null constraint nft_users_pk primary key,
+    contract        text,
+    token_id        text,
+    user_address    text,
+    expires         text,
+    block_number    text
+);
+
+create table nft_locks
+(
+    id              text not null constraint nft_locks_pk primary key,
+    contract        text,
+    token_id        text,
+    locked          text,
+    block_number    text
+);
+
+create table token_bound_accounts
+(
+    id                  text not null constraint token_bound_accounts_pk primary key,
+    account             text,
+    implementation      text,
+    chain_id            text,
+    token_contract      text,
+    token_id            text,
+    salt                text,
+    registry            text,
+    tx_hash             text,
+    block_number        text,
+    ordinal             text,
+    timestamp           text
+);
+
+create table metadata_refresh_requests
+(
+    id                  text not null constraint metadata_refresh_requests_pk primary key,
+    kind                text,
+    contract            text,
+    from_token_id       text,
+    to_token_id         text,
+    uri                 text,
+    tx_hash             text,
+    block_number        text,
+    ordinal             text,
+    timestamp           text
+);
+
+create table contract_role_changes
+(
+    id                  text not null constraint contract_role_change_pk primary key,
+    contract            text,
+    role                text,
+    previous_address    text,
+    new_address         text,
+    block_number        text,
+    ordinal             text
 );
 
 create table cursors
@@ -0,0 +1,191 @@
+const INTERNAL_ERR: &'static str = "`ethabi_derive` internal error";
+/// Contract's functions.
+#[allow(dead_code, unused_imports, unused_variables)]
+pub mod functions {
+    use super::INTERNAL_ERR;
+}
+/// Contract's events.
+#[allow(dead_code, unused_imports, unused_variables)]
+pub mod events {
+    use super::INTERNAL_ERR;
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct BatchMetadataUpdate {
+        pub from_token_id: substreams::scalar::BigInt,
+        pub to_token_id: substreams::scalar::BigInt,
+    }
+    impl BatchMetadataUpdate {
+        const TOPIC_ID: [u8; 32] = [
+            107u8,
+            213u8,
+            201u8,
+            80u8,
+            168u8,
+            216u8,
+            223u8,
+            23u8,
+            247u8,
+            114u8,
+            245u8,
+            175u8,
+            55u8,
+            203u8,
+            54u8,
+            85u8,
+            115u8,
+            120u8,
+            153u8,
+            203u8,
+            249u8,
+            3u8,
+            38u8,
+            75u8,
+            151u8,
+            149u8,
+            89u8,
+            45u8,
+            164u8,
+            57u8,
+            102u8,
+            28u8,
+        ];
+        pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+            if log.topics.len() != 1usize {
+                return false;
+            }
+            if log.data.len() != 64usize {
+                return false;
+            }
+            return log.topics.get(0).expect("bounds already checked").as_ref()
+                == Self::TOPIC_ID;
+        }
+        pub fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, String> {
+            let mut values = ethabi::decode(
+                    &[
+                        ethabi::ParamType::Uint(256usize),
+                        ethabi::ParamType::Uint(256usize),
+                    ],
+                    log.data.as_ref(),
+                )
+                .map_err(|e| format!("unable to decode log.data: {:?}", e))?;
+            values.reverse();
+            Ok(Self {
+                from_token_id: {
+                    let mut v = [0 as u8; 32];
+                    values
+                        .pop()
+                        .expect(INTERNAL_ERR)
+                        .into_uint()
+                        .expect(INTERNAL_ERR)
+                        .to_big_endian(v.as_mut_slice());
+                    substreams::scalar::BigInt::from_unsigned_bytes_be(&v)
+                },
+                to_token_id: {
+                    let mut v = [0 as u8; 32];
+                    values
+                        .pop()
+                        .expect(INTERNAL_ERR)
+                        .into_uint()
+                        .expect(INTERNAL_ERR)
+                        .to_big_endian(v.as_mut_slice());
+                    substreams::scalar::BigInt::from_unsigned_bytes_be(&v)
+                },
+            })
+        }
+    }
+    impl substreams_ethereum::Event for BatchMetadataUpdate {
+        const NAME: &'static str = "BatchMetadataUpdate";
+        fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+            Self::match_log(log)
+        }
+        fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, String> {
+            Self::decode(log)
+        }
+    }
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct MetadataUpdate {
+        pub token_id: substreams::scalar::BigInt,
+    }
+    impl MetadataUpdate {
+        const TOPIC_ID: [u8; 32] = [
+            248u8,
+            225u8,
+            161u8,
+            90u8,
+            186u8,
+            147u8,
+            152u8,
+            224u8,
+            25u8,
+            240u8,
+            180u8,
+            157u8,
+            241u8,
+            164u8,
+            253u8,
+            233u8,
+            142u8,
+            225u8,
+            122u8,
+            227u8,
+            69u8,
+            203u8,
+            95u8,
+            107u8,
+            94u8,
+            44u8,
+            39u8,
+            245u8,
+            3u8,
+            62u8,
+            140u8,
+            231u8,
+        ];
+        pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+            if log.topics.len() != 1usize {
+                return false;
+            }
+            if log.data.len() != 32usize {
+                return false;
+            }
+            return log.topics.get(0).expect("bounds already checked").as_ref()
+                == Self::TOPIC_ID;
+        }
+        pub fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, String> {
+            let mut values = ethabi::decode(
+                    &[ethabi::ParamType::Uint(256usize)],
+                    log.data.as_ref(),
+                )
+                .map_err(|e| format!("unable to decode log.data: {:?}", e))?;
+            values.reverse();
+            Ok(Self {
+                token_id: {
+                    let mut v = [0 as u8; 32];
+                    values
+                        .pop()
+                        .expect(INTERNAL_ERR)
+                        .into_uint()
+                        .expect(INTERNAL_ERR)
+                        .to_big_endian(v.as_mut_slice());
+                    substreams::scalar::BigInt::from_unsigned_bytes_be(&v)
+                },
+            })
+        }
+    }
+    impl substreams_ethereum::Event for MetadataUpdate {
+        const NAME: &'static str = "MetadataUpdate";
+        fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+            Self::match_log(log)
+        }
+        fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, String> {
+            Self::decode(log)
+        }
+    }
+}
\ No newline at end of file
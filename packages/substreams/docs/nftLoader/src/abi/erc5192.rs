@@ -0,0 +1,177 @@
+const INTERNAL_ERR: &'static str = "`ethabi_derive` internal error";
+/// Contract's functions.
+#[allow(dead_code, unused_imports, unused_variables)]
+pub mod functions {
+    use super::INTERNAL_ERR;
+}
+/// Contract's events.
+#[allow(dead_code, unused_imports, unused_variables)]
+pub mod events {
+    use super::INTERNAL_ERR;
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Locked {
+        pub token_id: substreams::scalar::BigInt,
+    }
+    impl Locked {
+        const TOPIC_ID: [u8; 32] = [
+            3u8,
+            43u8,
+            198u8,
+            107u8,
+            228u8,
+            61u8,
+            188u8,
+            203u8,
+            116u8,
+            135u8,
+            120u8,
+            29u8,
+            22u8,
+            142u8,
+            183u8,
+            189u8,
+            162u8,
+            36u8,
+            98u8,
+            138u8,
+            59u8,
+            44u8,
+            51u8,
+            136u8,
+            189u8,
+            246u8,
+            155u8,
+            83u8,
+            42u8,
+            58u8,
+            22u8,
+            17u8,
+        ];
+        pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+            if log.topics.len() != 1usize {
+                return false;
+            }
+            if log.data.len() != 32usize {
+                return false;
+            }
+            return log.topics.get(0).expect("bounds already checked").as_ref()
+                == Self::TOPIC_ID;
+        }
+        pub fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, String> {
+            let mut values = ethabi::decode(
+                    &[ethabi::ParamType::Uint(256usize)],
+                    log.data.as_ref(),
+                )
+                .map_err(|e| format!("unable to decode log.data: {:?}", e))?;
+            values.reverse();
+            Ok(Self {
+                token_id: {
+                    let mut v = [0 as u8; 32];
+                    values
+                        .pop()
+                        .expect(INTERNAL_ERR)
+                        .into_uint()
+                        .expect(INTERNAL_ERR)
+                        .to_big_endian(v.as_mut_slice());
+                    substreams::scalar::BigInt::from_unsigned_bytes_be(&v)
+                },
+            })
+        }
+    }
+    impl substreams_ethereum::Event for Locked {
+        const NAME: &'static str = "Locked";
+        fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+            Self::match_log(log)
+        }
+        fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, String> {
+            Self::decode(log)
+        }
+    }
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Unlocked {
+        pub token_id: substreams::scalar::BigInt,
+    }
+    impl Unlocked {
+        const TOPIC_ID: [u8; 32] = [
+            242u8,
+            123u8,
+            108u8,
+            229u8,
+            178u8,
+            245u8,
+            230u8,
+            141u8,
+            219u8,
+            47u8,
+            217u8,
+            90u8,
+            138u8,
+            144u8,
+            157u8,
+            78u8,
+            207u8,
+            29u8,
+            170u8,
+            172u8,
+            39u8,
+            9u8,
+            53u8,
+            255u8,
+            240u8,
+            82u8,
+            254u8,
+            172u8,
+            178u8,
+            79u8,
+            24u8,
+            66u8,
+        ];
+        pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+            if log.topics.len() != 1usize {
+                return false;
+            }
+            if log.data.len() != 32usize {
+                return false;
+            }
+            return log.topics.get(0).expect("bounds already checked").as_ref()
+                == Self::TOPIC_ID;
+        }
+        pub fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, String> {
+            let mut values = ethabi::decode(
+                    &[ethabi::ParamType::Uint(256usize)],
+                    log.data.as_ref(),
+                )
+                .map_err(|e| format!("unable to decode log.data: {:?}", e))?;
+            values.reverse();
+            Ok(Self {
+                token_id: {
+                    let mut v = [0 as u8; 32];
+                    values
+                        .pop()
+                        .expect(INTERNAL_ERR)
+                        .into_uint()
+                        .expect(INTERNAL_ERR)
+                        .to_big_endian(v.as_mut_slice());
+                    substreams::scalar::BigInt::from_unsigned_bytes_be(&v)
+                },
+            })
+        }
+    }
+    impl substreams_ethereum::Event for Unlocked {
+        const NAME: &'static str = "Unlocked";
+        fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+            Self::match_log(log)
+        }
+        fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, String> {
+            Self::decode(log)
+        }
+    }
+}
\ No newline at end of file
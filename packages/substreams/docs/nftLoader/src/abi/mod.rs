@@ -1,3 +1,21 @@
+pub mod chainlink_aggregator;
 pub mod erc1155;
+pub mod erc1967;
 pub mod erc20;
-pub mod erc721;
\ No newline at end of file
+pub mod erc2981;
+pub mod erc4906;
+pub mod erc4907;
+pub mod erc5192;
+pub mod erc6551_registry;
+pub mod erc721;
+pub mod looksrare_exchange;
+pub mod nftcom_marketplace;
+pub mod nftcom_marketplace_event;
+pub mod nftcom_resolver;
+pub mod nftx_vault;
+pub mod nftx_vault_factory;
+pub mod ownable;
+pub mod seaport;
+pub mod sudoswap_pair;
+pub mod sudoswap_pair_factory;
+pub mod uniswap_v3_pool;
\ No newline at end of file
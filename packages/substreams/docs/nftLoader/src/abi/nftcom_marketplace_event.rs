@@ -0,0 +1,1055 @@
+const INTERNAL_ERR: &'static str = "`ethabi_derive` internal error";
+/// Contract's functions.
+#[allow(dead_code, unused_imports, unused_variables)]
+pub mod functions {
+    use super::INTERNAL_ERR;
+}
+/// Contract's events.
+#[allow(dead_code, unused_imports, unused_variables)]
+pub mod events {
+    use super::INTERNAL_ERR;
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct BuyNowInfo {
+        pub maker_struct_hash: [u8; 32usize],
+        pub taker_address: Vec<u8>,
+    }
+    impl BuyNowInfo {
+        const TOPIC_ID: [u8; 32] = [
+            124u8,
+            235u8,
+            204u8,
+            161u8,
+            85u8,
+            141u8,
+            14u8,
+            211u8,
+            115u8,
+            225u8,
+            253u8,
+            176u8,
+            91u8,
+            211u8,
+            236u8,
+            213u8,
+            113u8,
+            122u8,
+            27u8,
+            86u8,
+            210u8,
+            174u8,
+            34u8,
+            118u8,
+            130u8,
+            229u8,
+            171u8,
+            159u8,
+            170u8,
+            75u8,
+            91u8,
+            37u8,
+        ];
+        pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+            if log.topics.len() != 2usize {
+                return false;
+            }
+            if log.data.len() != 32usize {
+                return false;
+            }
+            return log.topics.get(0).expect("bounds already checked").as_ref()
+                == Self::TOPIC_ID;
+        }
+        pub fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, String> {
+            let mut values = ethabi::decode(
+                    &[ethabi::ParamType::Address],
+                    log.data.as_ref(),
+                )
+                .map_err(|e| format!("unable to decode log.data: {:?}", e))?;
+            values.reverse();
+            Ok(Self {
+                maker_struct_hash: {
+                    let mut result = [0u8; 32];
+                    let v = ethabi::decode(
+                            &[ethabi::ParamType::FixedBytes(32usize)],
+                            log.topics[1usize].as_ref(),
+                        )
+                        .map_err(|e| {
+                            format!(
+                                "unable to decode param 'maker_struct_hash' from topic of type 'bytes32': {:?}",
+                                e
+                            )
+                        })?
+                        .pop()
+                        .expect(INTERNAL_ERR)
+                        .into_fixed_bytes()
+                        .expect(INTERNAL_ERR);
+                    result.copy_from_slice(&v);
+                    result
+                },
+                taker_address: values
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_address()
+                    .expect(INTERNAL_ERR)
+                    .as_bytes()
+                    .to_vec(),
+            })
+        }
+    }
+    impl substreams_ethereum::Event for BuyNowInfo {
+        const NAME: &'static str = "BuyNowInfo";
+        fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+            Self::match_log(log)
+        }
+        fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, String> {
+            Self::decode(log)
+        }
+    }
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Match {
+        pub maker_struct_hash: [u8; 32usize],
+        pub taker_struct_hash: [u8; 32usize],
+        pub auction_type: substreams::scalar::BigInt,
+        pub maker_sig: (substreams::scalar::BigInt, [u8; 32usize], [u8; 32usize]),
+        pub taker_sig: (substreams::scalar::BigInt, [u8; 32usize], [u8; 32usize]),
+        pub private_sale: bool,
+    }
+    impl Match {
+        const TOPIC_ID: [u8; 32] = [
+            154u8,
+            64u8,
+            42u8,
+            180u8,
+            146u8,
+            17u8,
+            235u8,
+            1u8,
+            115u8,
+            114u8,
+            83u8,
+            174u8,
+            173u8,
+            242u8,
+            172u8,
+            231u8,
+            182u8,
+            196u8,
+            139u8,
+            64u8,
+            121u8,
+            116u8,
+            241u8,
+            199u8,
+            101u8,
+            214u8,
+            18u8,
+            40u8,
+            126u8,
+            10u8,
+            64u8,
+            235u8,
+        ];
+        pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+            if log.topics.len() != 3usize {
+                return false;
+            }
+            if log.data.len() != 256usize {
+                return false;
+            }
+            return log.topics.get(0).expect("bounds already checked").as_ref()
+                == Self::TOPIC_ID;
+        }
+        pub fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, String> {
+            let mut values = ethabi::decode(
+                    &[
+                        ethabi::ParamType::Uint(8usize),
+                        ethabi::ParamType::Tuple(
+                            vec![
+                                ethabi::ParamType::Uint(8usize),
+                                ethabi::ParamType::FixedBytes(32usize),
+                                ethabi::ParamType::FixedBytes(32usize),
+                            ],
+                        ),
+                        ethabi::ParamType::Tuple(
+                            vec![
+                                ethabi::ParamType::Uint(8usize),
+                                ethabi::ParamType::FixedBytes(32usize),
+                                ethabi::ParamType::FixedBytes(32usize),
+                            ],
+                        ),
+                        ethabi::ParamType::Bool,
+                    ],
+                    log.data.as_ref(),
+                )
+                .map_err(|e| format!("unable to decode log.data: {:?}", e))?;
+            values.reverse();
+            Ok(Self {
+                maker_struct_hash: {
+                    let mut result = [0u8; 32];
+                    let v = ethabi::decode(
+                            &[ethabi::ParamType::FixedBytes(32usize)],
+                            log.topics[1usize].as_ref(),
+                        )
+                        .map_err(|e| {
+                            format!(
+                                "unable to decode param 'maker_struct_hash' from topic of type 'bytes32': {:?}",
+                                e
+                            )
+                        })?
+                        .pop()
+                        .expect(INTERNAL_ERR)
+                        .into_fixed_bytes()
+                        .expect(INTERNAL_ERR);
+                    result.copy_from_slice(&v);
+                    result
+                },
+                taker_struct_hash: {
+                    let mut result = [0u8; 32];
+                    let v = ethabi::decode(
+                            &[ethabi::ParamType::FixedBytes(32usize)],
+                            log.topics[2usize].as_ref(),
+                        )
+                        .map_err(|e| {
+                            format!(
+                                "unable to decode param 'taker_struct_hash' from topic of type 'bytes32': {:?}",
+                                e
+                            )
+                        })?
+                        .pop()
+                        .expect(INTERNAL_ERR)
+                        .into_fixed_bytes()
+                        .expect(INTERNAL_ERR);
+                    result.copy_from_slice(&v);
+                    result
+                },
+                auction_type: {
+                    let mut v = [0 as u8; 32];
+                    values
+                        .pop()
+                        .expect(INTERNAL_ERR)
+                        .into_uint()
+                        .expect(INTERNAL_ERR)
+                        .to_big_endian(v.as_mut_slice());
+                    substreams::scalar::BigInt::from_unsigned_bytes_be(&v)
+                },
+                maker_sig: {
+                    let tuple_elements = values
+                        .pop()
+                        .expect(INTERNAL_ERR)
+                        .into_tuple()
+                        .expect(INTERNAL_ERR);
+                    (
+                        {
+                            let mut v = [0 as u8; 32];
+                            tuple_elements[0usize]
+                                .clone()
+                                .into_uint()
+                                .expect(INTERNAL_ERR)
+                                .to_big_endian(v.as_mut_slice());
+                            substreams::scalar::BigInt::from_unsigned_bytes_be(&v)
+                        },
+                        {
+                            let mut result = [0u8; 32];
+                            let v = tuple_elements[1usize]
+                                .clone()
+                                .into_fixed_bytes()
+                                .expect(INTERNAL_ERR);
+                            result.copy_from_slice(&v);
+                            result
+                        },
+                        {
+                            let mut result = [0u8; 32];
+                            let v = tuple_elements[2usize]
+                                .clone()
+                                .into_fixed_bytes()
+                                .expect(INTERNAL_ERR);
+                            result.copy_from_slice(&v);
+                            result
+                        },
+                    )
+                },
+                taker_sig: {
+                    let tuple_elements = values
+                        .pop()
+                        .expect(INTERNAL_ERR)
+                        .into_tuple()
+                        .expect(INTERNAL_ERR);
+                    (
+                        {
+                            let mut v = [0 as u8; 32];
+                            tuple_elements[0usize]
+                                .clone()
+                                .into_uint()
+                                .expect(INTERNAL_ERR)
+                                .to_big_endian(v.as_mut_slice());
+                            substreams::scalar::BigInt::from_unsigned_bytes_be(&v)
+                        },
+                        {
+                            let mut result = [0u8; 32];
+                            let v = tuple_elements[1usize]
+                                .clone()
+                                .into_fixed_bytes()
+                                .expect(INTERNAL_ERR);
+                            result.copy_from_slice(&v);
+                            result
+                        },
+                        {
+                            let mut result = [0u8; 32];
+                            let v = tuple_elements[2usize]
+                                .clone()
+                                .into_fixed_bytes()
+                                .expect(INTERNAL_ERR);
+                            result.copy_from_slice(&v);
+                            result
+                        },
+                    )
+                },
+                private_sale: values
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_bool()
+                    .expect(INTERNAL_ERR),
+            })
+        }
+    }
+    impl substreams_ethereum::Event for Match {
+        const NAME: &'static str = "Match";
+        fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+            Self::match_log(log)
+        }
+        fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, String> {
+            Self::decode(log)
+        }
+    }
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Match2A {
+        pub maker_struct_hash: [u8; 32usize],
+        pub maker_address: Vec<u8>,
+        pub taker_address: Vec<u8>,
+        pub start: substreams::scalar::BigInt,
+        pub end: substreams::scalar::BigInt,
+        pub nonce: substreams::scalar::BigInt,
+        pub salt: substreams::scalar::BigInt,
+    }
+    impl Match2A {
+        const TOPIC_ID: [u8; 32] = [
+            167u8,
+            100u8,
+            73u8,
+            237u8,
+            138u8,
+            108u8,
+            33u8,
+            239u8,
+            91u8,
+            157u8,
+            48u8,
+            91u8,
+            164u8,
+            250u8,
+            34u8,
+            218u8,
+            197u8,
+            246u8,
+            183u8,
+            106u8,
+            255u8,
+            105u8,
+            223u8,
+            95u8,
+            224u8,
+            168u8,
+            166u8,
+            194u8,
+            76u8,
+            249u8,
+            154u8,
+            160u8,
+        ];
+        pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+            if log.topics.len() != 4usize {
+                return false;
+            }
+            if log.data.len() != 128usize {
+                return false;
+            }
+            return log.topics.get(0).expect("bounds already checked").as_ref()
+                == Self::TOPIC_ID;
+        }
+        pub fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, String> {
+            let mut values = ethabi::decode(
+                    &[
+                        ethabi::ParamType::Uint(256usize),
+                        ethabi::ParamType::Uint(256usize),
+                        ethabi::ParamType::Uint(256usize),
+                        ethabi::ParamType::Uint(256usize),
+                    ],
+                    log.data.as_ref(),
+                )
+                .map_err(|e| format!("unable to decode log.data: {:?}", e))?;
+            values.reverse();
+            Ok(Self {
+                maker_struct_hash: {
+                    let mut result = [0u8; 32];
+                    let v = ethabi::decode(
+                            &[ethabi::ParamType::FixedBytes(32usize)],
+                            log.topics[1usize].as_ref(),
+                        )
+                        .map_err(|e| {
+                            format!(
+                                "unable to decode param 'maker_struct_hash' from topic of type 'bytes32': {:?}",
+                                e
+                            )
+                        })?
+                        .pop()
+                        .expect(INTERNAL_ERR)
+                        .into_fixed_bytes()
+                        .expect(INTERNAL_ERR);
+                    result.copy_from_slice(&v);
+                    result
+                },
+                maker_address: ethabi::decode(
+                        &[ethabi::ParamType::Address],
+                        log.topics[2usize].as_ref(),
+                    )
+                    .map_err(|e| {
+                        format!(
+                            "unable to decode param 'maker_address' from topic of type 'address': {:?}",
+                            e
+                        )
+                    })?
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_address()
+                    .expect(INTERNAL_ERR)
+                    .as_bytes()
+                    .to_vec(),
+                taker_address: ethabi::decode(
+                        &[ethabi::ParamType::Address],
+                        log.topics[3usize].as_ref(),
+                    )
+                    .map_err(|e| {
+                        format!(
+                            "unable to decode param 'taker_address' from topic of type 'address': {:?}",
+                            e
+                        )
+                    })?
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_address()
+                    .expect(INTERNAL_ERR)
+                    .as_bytes()
+                    .to_vec(),
+                start: {
+                    let mut v = [0 as u8; 32];
+                    values
+                        .pop()
+                        .expect(INTERNAL_ERR)
+                        .into_uint()
+                        .expect(INTERNAL_ERR)
+                        .to_big_endian(v.as_mut_slice());
+                    substreams::scalar::BigInt::from_unsigned_bytes_be(&v)
+                },
+                end: {
+                    let mut v = [0 as u8; 32];
+                    values
+                        .pop()
+                        .expect(INTERNAL_ERR)
+                        .into_uint()
+                        .expect(INTERNAL_ERR)
+                        .to_big_endian(v.as_mut_slice());
+                    substreams::scalar::BigInt::from_unsigned_bytes_be(&v)
+                },
+                nonce: {
+                    let mut v = [0 as u8; 32];
+                    values
+                        .pop()
+                        .expect(INTERNAL_ERR)
+                        .into_uint()
+                        .expect(INTERNAL_ERR)
+                        .to_big_endian(v.as_mut_slice());
+                    substreams::scalar::BigInt::from_unsigned_bytes_be(&v)
+                },
+                salt: {
+                    let mut v = [0 as u8; 32];
+                    values
+                        .pop()
+                        .expect(INTERNAL_ERR)
+                        .into_uint()
+                        .expect(INTERNAL_ERR)
+                        .to_big_endian(v.as_mut_slice());
+                    substreams::scalar::BigInt::from_unsigned_bytes_be(&v)
+                },
+            })
+        }
+    }
+    impl substreams_ethereum::Event for Match2A {
+        const NAME: &'static str = "Match2A";
+        fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+            Self::match_log(log)
+        }
+        fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, String> {
+            Self::decode(log)
+        }
+    }
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Match2B {
+        pub maker_struct_hash: [u8; 32usize],
+        pub seller_maker_order_asset_data: Vec<Vec<u8>>,
+        pub seller_maker_order_asset_type_data: Vec<Vec<u8>>,
+        pub seller_maker_order_asset_class: Vec<[u8; 4usize]>,
+        pub seller_taker_order_asset_data: Vec<Vec<u8>>,
+        pub seller_taker_order_asset_type_data: Vec<Vec<u8>>,
+        pub seller_taker_order_asset_class: Vec<[u8; 4usize]>,
+    }
+    impl Match2B {
+        const TOPIC_ID: [u8; 32] = [
+            57u8,
+            12u8,
+            17u8,
+            2u8,
+            187u8,
+            173u8,
+            46u8,
+            149u8,
+            12u8,
+            233u8,
+            246u8,
+            67u8,
+            39u8,
+            193u8,
+            45u8,
+            125u8,
+            142u8,
+            175u8,
+            98u8,
+            71u8,
+            19u8,
+            106u8,
+            195u8,
+            24u8,
+            249u8,
+            172u8,
+            75u8,
+            86u8,
+            171u8,
+            243u8,
+            42u8,
+            144u8,
+        ];
+        pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+            if log.topics.len() != 2usize {
+                return false;
+            }
+            if log.data.len() < 384usize {
+                return false;
+            }
+            return log.topics.get(0).expect("bounds already checked").as_ref()
+                == Self::TOPIC_ID;
+        }
+        pub fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, String> {
+            let mut values = ethabi::decode(
+                    &[
+                        ethabi::ParamType::Array(Box::new(ethabi::ParamType::Bytes)),
+                        ethabi::ParamType::Array(Box::new(ethabi::ParamType::Bytes)),
+                        ethabi::ParamType::Array(
+                            Box::new(ethabi::ParamType::FixedBytes(4usize)),
+                        ),
+                        ethabi::ParamType::Array(Box::new(ethabi::ParamType::Bytes)),
+                        ethabi::ParamType::Array(Box::new(ethabi::ParamType::Bytes)),
+                        ethabi::ParamType::Array(
+                            Box::new(ethabi::ParamType::FixedBytes(4usize)),
+                        ),
+                    ],
+                    log.data.as_ref(),
+                )
+                .map_err(|e| format!("unable to decode log.data: {:?}", e))?;
+            values.reverse();
+            Ok(Self {
+                maker_struct_hash: {
+                    let mut result = [0u8; 32];
+                    let v = ethabi::decode(
+                            &[ethabi::ParamType::FixedBytes(32usize)],
+                            log.topics[1usize].as_ref(),
+                        )
+                        .map_err(|e| {
+                            format!(
+                                "unable to decode param 'maker_struct_hash' from topic of type 'bytes32': {:?}",
+                                e
+                            )
+                        })?
+                        .pop()
+                        .expect(INTERNAL_ERR)
+                        .into_fixed_bytes()
+                        .expect(INTERNAL_ERR);
+                    result.copy_from_slice(&v);
+                    result
+                },
+                seller_maker_order_asset_data: values
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_array()
+                    .expect(INTERNAL_ERR)
+                    .into_iter()
+                    .map(|inner| {
+                        inner
+                            .into_bytes()
+                            .expect(INTERNAL_ERR)
+                    })
+                    .collect(),
+                seller_maker_order_asset_type_data: values
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_array()
+                    .expect(INTERNAL_ERR)
+                    .into_iter()
+                    .map(|inner| {
+                        inner
+                            .into_bytes()
+                            .expect(INTERNAL_ERR)
+                    })
+                    .collect(),
+                seller_maker_order_asset_class: values
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_array()
+                    .expect(INTERNAL_ERR)
+                    .into_iter()
+                    .map(|inner| {
+                        let mut result = [0u8; 4];
+                        let v = inner
+                            .into_fixed_bytes()
+                            .expect(INTERNAL_ERR);
+                        result.copy_from_slice(&v);
+                        result
+                    })
+                    .collect(),
+                seller_taker_order_asset_data: values
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_array()
+                    .expect(INTERNAL_ERR)
+                    .into_iter()
+                    .map(|inner| {
+                        inner
+                            .into_bytes()
+                            .expect(INTERNAL_ERR)
+                    })
+                    .collect(),
+                seller_taker_order_asset_type_data: values
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_array()
+                    .expect(INTERNAL_ERR)
+                    .into_iter()
+                    .map(|inner| {
+                        inner
+                            .into_bytes()
+                            .expect(INTERNAL_ERR)
+                    })
+                    .collect(),
+                seller_taker_order_asset_class: values
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_array()
+                    .expect(INTERNAL_ERR)
+                    .into_iter()
+                    .map(|inner| {
+                        let mut result = [0u8; 4];
+                        let v = inner
+                            .into_fixed_bytes()
+                            .expect(INTERNAL_ERR);
+                        result.copy_from_slice(&v);
+                        result
+                    })
+                    .collect(),
+            })
+        }
+    }
+    impl substreams_ethereum::Event for Match2B {
+        const NAME: &'static str = "Match2B";
+        fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+            Self::match_log(log)
+        }
+        fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, String> {
+            Self::decode(log)
+        }
+    }
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Match3A {
+        pub taker_struct_hash: [u8; 32usize],
+        pub maker_address: Vec<u8>,
+        pub taker_address: Vec<u8>,
+        pub start: substreams::scalar::BigInt,
+        pub end: substreams::scalar::BigInt,
+        pub nonce: substreams::scalar::BigInt,
+        pub salt: substreams::scalar::BigInt,
+    }
+    impl Match3A {
+        const TOPIC_ID: [u8; 32] = [
+            93u8,
+            64u8,
+            82u8,
+            178u8,
+            2u8,
+            230u8,
+            116u8,
+            241u8,
+            25u8,
+            179u8,
+            107u8,
+            220u8,
+            5u8,
+            156u8,
+            203u8,
+            231u8,
+            100u8,
+            192u8,
+            220u8,
+            55u8,
+            129u8,
+            253u8,
+            234u8,
+            156u8,
+            175u8,
+            57u8,
+            13u8,
+            247u8,
+            40u8,
+            100u8,
+            223u8,
+            101u8,
+        ];
+        pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+            if log.topics.len() != 4usize {
+                return false;
+            }
+            if log.data.len() != 128usize {
+                return false;
+            }
+            return log.topics.get(0).expect("bounds already checked").as_ref()
+                == Self::TOPIC_ID;
+        }
+        pub fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, String> {
+            let mut values = ethabi::decode(
+                    &[
+                        ethabi::ParamType::Uint(256usize),
+                        ethabi::ParamType::Uint(256usize),
+                        ethabi::ParamType::Uint(256usize),
+                        ethabi::ParamType::Uint(256usize),
+                    ],
+                    log.data.as_ref(),
+                )
+                .map_err(|e| format!("unable to decode log.data: {:?}", e))?;
+            values.reverse();
+            Ok(Self {
+                taker_struct_hash: {
+                    let mut result = [0u8; 32];
+                    let v = ethabi::decode(
+                            &[ethabi::ParamType::FixedBytes(32usize)],
+                            log.topics[1usize].as_ref(),
+                        )
+                        .map_err(|e| {
+                            format!(
+                                "unable to decode param 'taker_struct_hash' from topic of type 'bytes32': {:?}",
+                                e
+                            )
+                        })?
+                        .pop()
+                        .expect(INTERNAL_ERR)
+                        .into_fixed_bytes()
+                        .expect(INTERNAL_ERR);
+                    result.copy_from_slice(&v);
+                    result
+                },
+                maker_address: ethabi::decode(
+                        &[ethabi::ParamType::Address],
+                        log.topics[2usize].as_ref(),
+                    )
+                    .map_err(|e| {
+                        format!(
+                            "unable to decode param 'maker_address' from topic of type 'address': {:?}",
+                            e
+                        )
+                    })?
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_address()
+                    .expect(INTERNAL_ERR)
+                    .as_bytes()
+                    .to_vec(),
+                taker_address: ethabi::decode(
+                        &[ethabi::ParamType::Address],
+                        log.topics[3usize].as_ref(),
+                    )
+                    .map_err(|e| {
+                        format!(
+                            "unable to decode param 'taker_address' from topic of type 'address': {:?}",
+                            e
+                        )
+                    })?
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_address()
+                    .expect(INTERNAL_ERR)
+                    .as_bytes()
+                    .to_vec(),
+                start: {
+                    let mut v = [0 as u8; 32];
+                    values
+                        .pop()
+                        .expect(INTERNAL_ERR)
+                        .into_uint()
+                        .expect(INTERNAL_ERR)
+                        .to_big_endian(v.as_mut_slice());
+                    substreams::scalar::BigInt::from_unsigned_bytes_be(&v)
+                },
+                end: {
+                    let mut v = [0 as u8; 32];
+                    values
+                        .pop()
+                        .expect(INTERNAL_ERR)
+                        .into_uint()
+                        .expect(INTERNAL_ERR)
+                        .to_big_endian(v.as_mut_slice());
+                    substreams::scalar::BigInt::from_unsigned_bytes_be(&v)
+                },
+                nonce: {
+                    let mut v = [0 as u8; 32];
+                    values
+                        .pop()
+                        .expect(INTERNAL_ERR)
+                        .into_uint()
+                        .expect(INTERNAL_ERR)
+                        .to_big_endian(v.as_mut_slice());
+                    substreams::scalar::BigInt::from_unsigned_bytes_be(&v)
+                },
+                salt: {
+                    let mut v = [0 as u8; 32];
+                    values
+                        .pop()
+                        .expect(INTERNAL_ERR)
+                        .into_uint()
+                        .expect(INTERNAL_ERR)
+                        .to_big_endian(v.as_mut_slice());
+                    substreams::scalar::BigInt::from_unsigned_bytes_be(&v)
+                },
+            })
+        }
+    }
+    impl substreams_ethereum::Event for Match3A {
+        const NAME: &'static str = "Match3A";
+        fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+            Self::match_log(log)
+        }
+        fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, String> {
+            Self::decode(log)
+        }
+    }
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Match3B {
+        pub taker_struct_hash: [u8; 32usize],
+        pub buyer_maker_order_asset_data: Vec<Vec<u8>>,
+        pub buyer_maker_order_asset_type_data: Vec<Vec<u8>>,
+        pub buyer_maker_order_asset_class: Vec<[u8; 4usize]>,
+        pub buyer_taker_order_asset_data: Vec<Vec<u8>>,
+        pub buyer_taker_order_asset_type_data: Vec<Vec<u8>>,
+        pub buyer_taker_order_asset_class: Vec<[u8; 4usize]>,
+    }
+    impl Match3B {
+        const TOPIC_ID: [u8; 32] = [
+            150u8,
+            60u8,
+            168u8,
+            30u8,
+            115u8,
+            218u8,
+            18u8,
+            168u8,
+            185u8,
+            12u8,
+            3u8,
+            97u8,
+            184u8,
+            242u8,
+            195u8,
+            239u8,
+            152u8,
+            112u8,
+            118u8,
+            211u8,
+            52u8,
+            136u8,
+            183u8,
+            70u8,
+            58u8,
+            107u8,
+            190u8,
+            222u8,
+            211u8,
+            193u8,
+            30u8,
+            84u8,
+        ];
+        pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+            if log.topics.len() != 2usize {
+                return false;
+            }
+            if log.data.len() < 384usize {
+                return false;
+            }
+            return log.topics.get(0).expect("bounds already checked").as_ref()
+                == Self::TOPIC_ID;
+        }
+        pub fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, String> {
+            let mut values = ethabi::decode(
+                    &[
+                        ethabi::ParamType::Array(Box::new(ethabi::ParamType::Bytes)),
+                        ethabi::ParamType::Array(Box::new(ethabi::ParamType::Bytes)),
+                        ethabi::ParamType::Array(
+                            Box::new(ethabi::ParamType::FixedBytes(4usize)),
+                        ),
+                        ethabi::ParamType::Array(Box::new(ethabi::ParamType::Bytes)),
+                        ethabi::ParamType::Array(Box::new(ethabi::ParamType::Bytes)),
+                        ethabi::ParamType::Array(
+                            Box::new(ethabi::ParamType::FixedBytes(4usize)),
+                        ),
+                    ],
+                    log.data.as_ref(),
+                )
+                .map_err(|e| format!("unable to decode log.data: {:?}", e))?;
+            values.reverse();
+            Ok(Self {
+                taker_struct_hash: {
+                    let mut result = [0u8; 32];
+                    let v = ethabi::decode(
+                            &[ethabi::ParamType::FixedBytes(32usize)],
+                            log.topics[1usize].as_ref(),
+                        )
+                        .map_err(|e| {
+                            format!(
+                                "unable to decode param 'taker_struct_hash' from topic of type 'bytes32': {:?}",
+                                e
+                            )
+                        })?
+                        .pop()
+                        .expect(INTERNAL_ERR)
+                        .into_fixed_bytes()
+                        .expect(INTERNAL_ERR);
+                    result.copy_from_slice(&v);
+                    result
+                },
+                buyer_maker_order_asset_data: values
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_array()
+                    .expect(INTERNAL_ERR)
+                    .into_iter()
+                    .map(|inner| {
+                        inner
+                            .into_bytes()
+                            .expect(INTERNAL_ERR)
+                    })
+                    .collect(),
+                buyer_maker_order_asset_type_data: values
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_array()
+                    .expect(INTERNAL_ERR)
+                    .into_iter()
+                    .map(|inner| {
+                        inner
+                            .into_bytes()
+                            .expect(INTERNAL_ERR)
+                    })
+                    .collect(),
+                buyer_maker_order_asset_class: values
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_array()
+                    .expect(INTERNAL_ERR)
+                    .into_iter()
+                    .map(|inner| {
+                        let mut result = [0u8; 4];
+                        let v = inner
+                            .into_fixed_bytes()
+                            .expect(INTERNAL_ERR);
+                        result.copy_from_slice(&v);
+                        result
+                    })
+                    .collect(),
+                buyer_taker_order_asset_data: values
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_array()
+                    .expect(INTERNAL_ERR)
+                    .into_iter()
+                    .map(|inner| {
+                        inner
+                            .into_bytes()
+                            .expect(INTERNAL_ERR)
+                    })
+                    .collect(),
+                buyer_taker_order_asset_type_data: values
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_array()
+                    .expect(INTERNAL_ERR)
+                    .into_iter()
+                    .map(|inner| {
+                        inner
+                            .into_bytes()
+                            .expect(INTERNAL_ERR)
+                    })
+                    .collect(),
+                buyer_taker_order_asset_class: values
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_array()
+                    .expect(INTERNAL_ERR)
+                    .into_iter()
+                    .map(|inner| {
+                        let mut result = [0u8; 4];
+                        let v = inner
+                            .into_fixed_bytes()
+                            .expect(INTERNAL_ERR);
+                        result.copy_from_slice(&v);
+                        result
+                    })
+                    .collect(),
+            })
+        }
+    }
+    impl substreams_ethereum::Event for Match3B {
+        const NAME: &'static str = "Match3B";
+        fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+            Self::match_log(log)
+        }
+        fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, String> {
+            Self::decode(log)
+        }
+    }
+}
\ No newline at end of file
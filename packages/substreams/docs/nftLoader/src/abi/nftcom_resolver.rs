@@ -0,0 +1,753 @@
+const INTERNAL_ERR: &'static str = "`ethabi_derive` internal error";
+/// Contract's functions.
+#[allow(dead_code, unused_imports, unused_variables)]
+pub mod functions {
+    use super::INTERNAL_ERR;
+}
+/// Contract's events.
+#[allow(dead_code, unused_imports, unused_variables)]
+pub mod events {
+    use super::INTERNAL_ERR;
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct AssociateEvmUser {
+        pub owner: Vec<u8>,
+        pub profile_url: String,
+        pub associated_address: Vec<u8>,
+    }
+    impl AssociateEvmUser {
+        const TOPIC_ID: [u8; 32] = [
+            60u8,
+            254u8,
+            13u8,
+            30u8,
+            87u8,
+            153u8,
+            127u8,
+            37u8,
+            66u8,
+            136u8,
+            193u8,
+            123u8,
+            206u8,
+            206u8,
+            166u8,
+            231u8,
+            211u8,
+            221u8,
+            22u8,
+            171u8,
+            158u8,
+            206u8,
+            46u8,
+            254u8,
+            12u8,
+            212u8,
+            243u8,
+            126u8,
+            26u8,
+            122u8,
+            201u8,
+            29u8,
+        ];
+        pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+            if log.topics.len() != 3usize {
+                return false;
+            }
+            if log.data.len() < 64usize {
+                return false;
+            }
+            return log.topics.get(0).expect("bounds already checked").as_ref()
+                == Self::TOPIC_ID;
+        }
+        pub fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, String> {
+            let mut values = ethabi::decode(
+                    &[ethabi::ParamType::String],
+                    log.data.as_ref(),
+                )
+                .map_err(|e| format!("unable to decode log.data: {:?}", e))?;
+            values.reverse();
+            Ok(Self {
+                owner: ethabi::decode(
+                        &[ethabi::ParamType::Address],
+                        log.topics[1usize].as_ref(),
+                    )
+                    .map_err(|e| {
+                        format!(
+                            "unable to decode param 'owner' from topic of type 'address': {:?}",
+                            e
+                        )
+                    })?
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_address()
+                    .expect(INTERNAL_ERR)
+                    .as_bytes()
+                    .to_vec(),
+                associated_address: ethabi::decode(
+                        &[ethabi::ParamType::Address],
+                        log.topics[2usize].as_ref(),
+                    )
+                    .map_err(|e| {
+                        format!(
+                            "unable to decode param 'associated_address' from topic of type 'address': {:?}",
+                            e
+                        )
+                    })?
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_address()
+                    .expect(INTERNAL_ERR)
+                    .as_bytes()
+                    .to_vec(),
+                profile_url: values
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_string()
+                    .expect(INTERNAL_ERR),
+            })
+        }
+    }
+    impl substreams_ethereum::Event for AssociateEvmUser {
+        const NAME: &'static str = "AssociateEvmUser";
+        fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+            Self::match_log(log)
+        }
+        fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, String> {
+            Self::decode(log)
+        }
+    }
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct AssociateSelfWithUser {
+        pub receiver: Vec<u8>,
+        pub profile_url: String,
+        pub profile_owner: Vec<u8>,
+    }
+    impl AssociateSelfWithUser {
+        const TOPIC_ID: [u8; 32] = [
+            171u8,
+            247u8,
+            135u8,
+            239u8,
+            187u8,
+            74u8,
+            142u8,
+            122u8,
+            197u8,
+            214u8,
+            189u8,
+            121u8,
+            221u8,
+            23u8,
+            122u8,
+            86u8,
+            176u8,
+            138u8,
+            29u8,
+            173u8,
+            128u8,
+            144u8,
+            125u8,
+            172u8,
+            61u8,
+            223u8,
+            248u8,
+            233u8,
+            83u8,
+            223u8,
+            61u8,
+            44u8,
+        ];
+        pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+            if log.topics.len() != 3usize {
+                return false;
+            }
+            if log.data.len() < 64usize {
+                return false;
+            }
+            return log.topics.get(0).expect("bounds already checked").as_ref()
+                == Self::TOPIC_ID;
+        }
+        pub fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, String> {
+            let mut values = ethabi::decode(
+                    &[ethabi::ParamType::String],
+                    log.data.as_ref(),
+                )
+                .map_err(|e| format!("unable to decode log.data: {:?}", e))?;
+            values.reverse();
+            Ok(Self {
+                receiver: ethabi::decode(
+                        &[ethabi::ParamType::Address],
+                        log.topics[1usize].as_ref(),
+                    )
+                    .map_err(|e| {
+                        format!(
+                            "unable to decode param 'receiver' from topic of type 'address': {:?}",
+                            e
+                        )
+                    })?
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_address()
+                    .expect(INTERNAL_ERR)
+                    .as_bytes()
+                    .to_vec(),
+                profile_owner: ethabi::decode(
+                        &[ethabi::ParamType::Address],
+                        log.topics[2usize].as_ref(),
+                    )
+                    .map_err(|e| {
+                        format!(
+                            "unable to decode param 'profile_owner' from topic of type 'address': {:?}",
+                            e
+                        )
+                    })?
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_address()
+                    .expect(INTERNAL_ERR)
+                    .as_bytes()
+                    .to_vec(),
+                profile_url: values
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_string()
+                    .expect(INTERNAL_ERR),
+            })
+        }
+    }
+    impl substreams_ethereum::Event for AssociateSelfWithUser {
+        const NAME: &'static str = "AssociateSelfWithUser";
+        fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+            Self::match_log(log)
+        }
+        fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, String> {
+            Self::decode(log)
+        }
+    }
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct CancelledEvmAssociation {
+        pub owner: Vec<u8>,
+        pub profile_url: String,
+        pub associated_addresses: Vec<u8>,
+    }
+    impl CancelledEvmAssociation {
+        const TOPIC_ID: [u8; 32] = [
+            28u8,
+            59u8,
+            110u8,
+            110u8,
+            254u8,
+            61u8,
+            208u8,
+            159u8,
+            52u8,
+            192u8,
+            41u8,
+            68u8,
+            86u8,
+            88u8,
+            122u8,
+            172u8,
+            192u8,
+            8u8,
+            75u8,
+            196u8,
+            195u8,
+            6u8,
+            155u8,
+            105u8,
+            35u8,
+            130u8,
+            225u8,
+            208u8,
+            43u8,
+            7u8,
+            202u8,
+            227u8,
+        ];
+        pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+            if log.topics.len() != 3usize {
+                return false;
+            }
+            if log.data.len() < 64usize {
+                return false;
+            }
+            return log.topics.get(0).expect("bounds already checked").as_ref()
+                == Self::TOPIC_ID;
+        }
+        pub fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, String> {
+            let mut values = ethabi::decode(
+                    &[ethabi::ParamType::String],
+                    log.data.as_ref(),
+                )
+                .map_err(|e| format!("unable to decode log.data: {:?}", e))?;
+            values.reverse();
+            Ok(Self {
+                owner: ethabi::decode(
+                        &[ethabi::ParamType::Address],
+                        log.topics[1usize].as_ref(),
+                    )
+                    .map_err(|e| {
+                        format!(
+                            "unable to decode param 'owner' from topic of type 'address': {:?}",
+                            e
+                        )
+                    })?
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_address()
+                    .expect(INTERNAL_ERR)
+                    .as_bytes()
+                    .to_vec(),
+                associated_addresses: ethabi::decode(
+                        &[ethabi::ParamType::Address],
+                        log.topics[2usize].as_ref(),
+                    )
+                    .map_err(|e| {
+                        format!(
+                            "unable to decode param 'associated_addresses' from topic of type 'address': {:?}",
+                            e
+                        )
+                    })?
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_address()
+                    .expect(INTERNAL_ERR)
+                    .as_bytes()
+                    .to_vec(),
+                profile_url: values
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_string()
+                    .expect(INTERNAL_ERR),
+            })
+        }
+    }
+    impl substreams_ethereum::Event for CancelledEvmAssociation {
+        const NAME: &'static str = "CancelledEvmAssociation";
+        fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+            Self::match_log(log)
+        }
+        fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, String> {
+            Self::decode(log)
+        }
+    }
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct ClearAllAssociatedAddresses {
+        pub owner: Vec<u8>,
+        pub profile_url: String,
+    }
+    impl ClearAllAssociatedAddresses {
+        const TOPIC_ID: [u8; 32] = [
+            93u8,
+            158u8,
+            14u8,
+            202u8,
+            182u8,
+            129u8,
+            123u8,
+            252u8,
+            116u8,
+            146u8,
+            202u8,
+            59u8,
+            112u8,
+            25u8,
+            31u8,
+            250u8,
+            207u8,
+            89u8,
+            166u8,
+            0u8,
+            236u8,
+            182u8,
+            129u8,
+            79u8,
+            91u8,
+            3u8,
+            110u8,
+            23u8,
+            200u8,
+            91u8,
+            67u8,
+            144u8,
+        ];
+        pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+            if log.topics.len() != 2usize {
+                return false;
+            }
+            if log.data.len() < 64usize {
+                return false;
+            }
+            return log.topics.get(0).expect("bounds already checked").as_ref()
+                == Self::TOPIC_ID;
+        }
+        pub fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, String> {
+            let mut values = ethabi::decode(
+                    &[ethabi::ParamType::String],
+                    log.data.as_ref(),
+                )
+                .map_err(|e| format!("unable to decode log.data: {:?}", e))?;
+            values.reverse();
+            Ok(Self {
+                owner: ethabi::decode(
+                        &[ethabi::ParamType::Address],
+                        log.topics[1usize].as_ref(),
+                    )
+                    .map_err(|e| {
+                        format!(
+                            "unable to decode param 'owner' from topic of type 'address': {:?}",
+                            e
+                        )
+                    })?
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_address()
+                    .expect(INTERNAL_ERR)
+                    .as_bytes()
+                    .to_vec(),
+                profile_url: values
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_string()
+                    .expect(INTERNAL_ERR),
+            })
+        }
+    }
+    impl substreams_ethereum::Event for ClearAllAssociatedAddresses {
+        const NAME: &'static str = "ClearAllAssociatedAddresses";
+        fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+            Self::match_log(log)
+        }
+        fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, String> {
+            Self::decode(log)
+        }
+    }
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct ClearAssociatedContract {
+        pub owner: Vec<u8>,
+        pub profile_url: String,
+    }
+    impl ClearAssociatedContract {
+        const TOPIC_ID: [u8; 32] = [
+            151u8,
+            191u8,
+            89u8,
+            6u8,
+            41u8,
+            161u8,
+            78u8,
+            195u8,
+            141u8,
+            208u8,
+            40u8,
+            186u8,
+            42u8,
+            72u8,
+            247u8,
+            135u8,
+            232u8,
+            196u8,
+            62u8,
+            163u8,
+            80u8,
+            190u8,
+            89u8,
+            36u8,
+            117u8,
+            191u8,
+            159u8,
+            60u8,
+            53u8,
+            202u8,
+            166u8,
+            156u8,
+        ];
+        pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+            if log.topics.len() != 2usize {
+                return false;
+            }
+            if log.data.len() < 64usize {
+                return false;
+            }
+            return log.topics.get(0).expect("bounds already checked").as_ref()
+                == Self::TOPIC_ID;
+        }
+        pub fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, String> {
+            let mut values = ethabi::decode(
+                    &[ethabi::ParamType::String],
+                    log.data.as_ref(),
+                )
+                .map_err(|e| format!("unable to decode log.data: {:?}", e))?;
+            values.reverse();
+            Ok(Self {
+                owner: ethabi::decode(
+                        &[ethabi::ParamType::Address],
+                        log.topics[1usize].as_ref(),
+                    )
+                    .map_err(|e| {
+                        format!(
+                            "unable to decode param 'owner' from topic of type 'address': {:?}",
+                            e
+                        )
+                    })?
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_address()
+                    .expect(INTERNAL_ERR)
+                    .as_bytes()
+                    .to_vec(),
+                profile_url: values
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_string()
+                    .expect(INTERNAL_ERR),
+            })
+        }
+    }
+    impl substreams_ethereum::Event for ClearAssociatedContract {
+        const NAME: &'static str = "ClearAssociatedContract";
+        fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+            Self::match_log(log)
+        }
+        fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, String> {
+            Self::decode(log)
+        }
+    }
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct RemovedAssociateProfile {
+        pub receiver: Vec<u8>,
+        pub profile_url: String,
+        pub profile_owner: Vec<u8>,
+    }
+    impl RemovedAssociateProfile {
+        const TOPIC_ID: [u8; 32] = [
+            141u8,
+            182u8,
+            231u8,
+            201u8,
+            11u8,
+            76u8,
+            216u8,
+            160u8,
+            210u8,
+            96u8,
+            71u8,
+            144u8,
+            140u8,
+            192u8,
+            165u8,
+            240u8,
+            203u8,
+            15u8,
+            26u8,
+            9u8,
+            111u8,
+            26u8,
+            80u8,
+            147u8,
+            217u8,
+            51u8,
+            76u8,
+            114u8,
+            37u8,
+            223u8,
+            23u8,
+            32u8,
+        ];
+        pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+            if log.topics.len() != 3usize {
+                return false;
+            }
+            if log.data.len() < 64usize {
+                return false;
+            }
+            return log.topics.get(0).expect("bounds already checked").as_ref()
+                == Self::TOPIC_ID;
+        }
+        pub fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, String> {
+            let mut values = ethabi::decode(
+                    &[ethabi::ParamType::String],
+                    log.data.as_ref(),
+                )
+                .map_err(|e| format!("unable to decode log.data: {:?}", e))?;
+            values.reverse();
+            Ok(Self {
+                receiver: ethabi::decode(
+                        &[ethabi::ParamType::Address],
+                        log.topics[1usize].as_ref(),
+                    )
+                    .map_err(|e| {
+                        format!(
+                            "unable to decode param 'receiver' from topic of type 'address': {:?}",
+                            e
+                        )
+                    })?
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_address()
+                    .expect(INTERNAL_ERR)
+                    .as_bytes()
+                    .to_vec(),
+                profile_owner: ethabi::decode(
+                        &[ethabi::ParamType::Address],
+                        log.topics[2usize].as_ref(),
+                    )
+                    .map_err(|e| {
+                        format!(
+                            "unable to decode param 'profile_owner' from topic of type 'address': {:?}",
+                            e
+                        )
+                    })?
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_address()
+                    .expect(INTERNAL_ERR)
+                    .as_bytes()
+                    .to_vec(),
+                profile_url: values
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_string()
+                    .expect(INTERNAL_ERR),
+            })
+        }
+    }
+    impl substreams_ethereum::Event for RemovedAssociateProfile {
+        const NAME: &'static str = "RemovedAssociateProfile";
+        fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+            Self::match_log(log)
+        }
+        fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, String> {
+            Self::decode(log)
+        }
+    }
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct SetAssociatedContract {
+        pub owner: Vec<u8>,
+        pub profile_url: String,
+        pub associated_contract: String,
+    }
+    impl SetAssociatedContract {
+        const TOPIC_ID: [u8; 32] = [
+            157u8,
+            199u8,
+            229u8,
+            73u8,
+            87u8,
+            62u8,
+            175u8,
+            12u8,
+            100u8,
+            12u8,
+            208u8,
+            23u8,
+            39u8,
+            6u8,
+            254u8,
+            14u8,
+            166u8,
+            228u8,
+            201u8,
+            125u8,
+            33u8,
+            61u8,
+            84u8,
+            100u8,
+            19u8,
+            181u8,
+            163u8,
+            92u8,
+            212u8,
+            193u8,
+            161u8,
+            61u8,
+        ];
+        pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+            if log.topics.len() != 2usize {
+                return false;
+            }
+            if log.data.len() < 128usize {
+                return false;
+            }
+            return log.topics.get(0).expect("bounds already checked").as_ref()
+                == Self::TOPIC_ID;
+        }
+        pub fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, String> {
+            let mut values = ethabi::decode(
+                    &[
+                        ethabi::ParamType::String,
+                        ethabi::ParamType::String,
+                    ],
+                    log.data.as_ref(),
+                )
+                .map_err(|e| format!("unable to decode log.data: {:?}", e))?;
+            values.reverse();
+            Ok(Self {
+                owner: ethabi::decode(
+                        &[ethabi::ParamType::Address],
+                        log.topics[1usize].as_ref(),
+                    )
+                    .map_err(|e| {
+                        format!(
+                            "unable to decode param 'owner' from topic of type 'address': {:?}",
+                            e
+                        )
+                    })?
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_address()
+                    .expect(INTERNAL_ERR)
+                    .as_bytes()
+                    .to_vec(),
+                profile_url: values
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_string()
+                    .expect(INTERNAL_ERR),
+                associated_contract: values
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_string()
+                    .expect(INTERNAL_ERR),
+            })
+        }
+    }
+    impl substreams_ethereum::Event for SetAssociatedContract {
+        const NAME: &'static str = "SetAssociatedContract";
+        fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+            Self::match_log(log)
+        }
+        fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, String> {
+            Self::decode(log)
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,91 @@
+const INTERNAL_ERR: &'static str = "`ethabi_derive` internal error";
+/// Contract's functions.
+#[allow(dead_code, unused_imports, unused_variables)]
+pub mod functions {
+    use super::INTERNAL_ERR;
+}
+/// Contract's events.
+#[allow(dead_code, unused_imports, unused_variables)]
+pub mod events {
+    use super::INTERNAL_ERR;
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct NewPair {
+        pub pool_address: Vec<u8>,
+    }
+    impl NewPair {
+        const TOPIC_ID: [u8; 32] = [
+            245u8,
+            189u8,
+            193u8,
+            3u8,
+            195u8,
+            230u8,
+            138u8,
+            32u8,
+            213u8,
+            249u8,
+            125u8,
+            45u8,
+            70u8,
+            121u8,
+            45u8,
+            63u8,
+            221u8,
+            223u8,
+            164u8,
+            239u8,
+            235u8,
+            103u8,
+            97u8,
+            248u8,
+            20u8,
+            30u8,
+            106u8,
+            123u8,
+            147u8,
+            108u8,
+            166u8,
+            108u8,
+        ];
+        pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+            if log.topics.len() != 1usize {
+                return false;
+            }
+            if log.data.len() != 32usize {
+                return false;
+            }
+            return log.topics.get(0).expect("bounds already checked").as_ref()
+                == Self::TOPIC_ID;
+        }
+        pub fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, String> {
+            let mut values = ethabi::decode(
+                    &[ethabi::ParamType::Address],
+                    log.data.as_ref(),
+                )
+                .map_err(|e| format!("unable to decode log.data: {:?}", e))?;
+            values.reverse();
+            Ok(Self {
+                pool_address: values
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_address()
+                    .expect(INTERNAL_ERR)
+                    .as_bytes()
+                    .to_vec(),
+            })
+        }
+    }
+    impl substreams_ethereum::Event for NewPair {
+        const NAME: &'static str = "NewPair";
+        fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+            Self::match_log(log)
+        }
+        fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, String> {
+            Self::decode(log)
+        }
+    }
+}
\ No newline at end of file
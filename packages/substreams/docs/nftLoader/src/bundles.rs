@@ -0,0 +1,219 @@
+use std::collections::HashMap;
+
+use substreams::scalar::BigInt;
+use substreams::store::{StoreGet, StoreGetBigInt, StoreSet, StoreSetBigInt};
+use substreams::Hex;
+
+use crate::allocation_to_string;
+use crate::pb::sales;
+use crate::pb::sales::sale::Allocation;
+
+// Inferred sales below this confidence do not move the floor of a collection.
+const FLOOR_MIN_CONFIDENCE: u32 = 75;
+
+pub fn floor_key(contract_address: &[u8], payment_token: &[u8]) -> String {
+    format!("floor:{}:{}", Hex(contract_address), Hex(payment_token))
+}
+
+fn bundle_sizes(sales: &[sales::Sale]) -> HashMap<&str, usize> {
+    let mut sizes: HashMap<&str, usize> = HashMap::new();
+    for sale in sales.iter().filter(|sale| !sale.bundle_id.is_empty()) {
+        *sizes.entry(sale.bundle_id.as_str()).or_default() += 1;
+    }
+
+    sizes
+}
+
+/// Tracks the floor of each collection per payment token, approximated by the unit price of its
+/// latest sale of a single NFT.
+pub fn store_floors(sales: &[sales::Sale], store: &StoreSetBigInt) {
+    let sizes = bundle_sizes(sales);
+
+    for sale in sales {
+        let bundle_size = sizes.get(sale.bundle_id.as_str()).cloned().unwrap_or(1);
+        if bundle_size > 1 || sale.confidence < FLOOR_MIN_CONFIDENCE {
+            continue;
+        }
+
+        let price = BigInt::try_from(sale.price.clone());
+        let quantity = BigInt::try_from(sale.quantity.clone());
+        let (price, quantity) = match (price, quantity) {
+            (Ok(price), Ok(quantity)) if quantity != BigInt::zero() => (price, quantity),
+            _ => continue,
+        };
+
+        store.set(
+            sale.ordinal,
+            floor_key(&sale.contract_address, &sale.payment_token),
+            &(price / quantity),
+        );
+    }
+}
+
+/// Splits the price of the sales sharing a `bundle_id`, which is the total price of the bundle
+/// when decoded, between their NFTs. Sales without a `bundle_id` are sold alone. The split is
+/// pro-rata to the floor of the collections when all of them have one in the payment token, and
+/// even per unit otherwise. The last NFT of a bundle gets the rounding remainder so that prices
+/// always add up to the bundle price, fees being scaled down by the same ratio as prices.
+pub fn allocate(sales: Vec<sales::Sale>, floors: &StoreGetBigInt) -> Vec<sales::Sale> {
+    let mut bundles: Vec<Vec<sales::Sale>> = Vec::new();
+    let mut bundle_indexes: HashMap<String, usize> = HashMap::new();
+    for sale in sales {
+        match bundle_indexes.get(&sale.bundle_id) {
+            Some(index) if !sale.bundle_id.is_empty() => bundles[*index].push(sale),
+            _ => {
+                bundle_indexes.insert(sale.bundle_id.clone(), bundles.len());
+                bundles.push(vec![sale]);
+            }
+        }
+    }
+
+    bundles
+        .into_iter()
+        .flat_map(|bundle| {
+            allocate_bundle(bundle, |sale| {
+                floors.get_at(sale.ordinal, floor_key(&sale.contract_address, &sale.payment_token))
+            })
+        })
+        .collect()
+}
+
+// `floor` gives the floor of the collection of a sale, in its payment token, at the sale.
+fn allocate_bundle(mut bundle: Vec<sales::Sale>, floor: impl Fn(&sales::Sale) -> Option<BigInt>) -> Vec<sales::Sale> {
+    let bundle_price = BigInt::try_from(bundle[0].price.clone()).unwrap_or_else(|_| BigInt::zero());
+    let bundle_size = bundle.len() as u32;
+
+    if bundle_size == 1 {
+        let sale = &mut bundle[0];
+        sale.bundle_id = String::new();
+        sale.bundle_size = 1;
+        sale.bundle_price = sale.price.clone();
+        sale.allocation = allocation_to_string(Allocation::Single);
+
+        return bundle;
+    }
+
+    let units: Vec<BigInt> = bundle
+        .iter()
+        .map(|sale| BigInt::try_from(sale.quantity.clone()).unwrap_or_else(|_| BigInt::from(1)))
+        .collect();
+    let floor_weights: Option<Vec<BigInt>> = bundle
+        .iter()
+        .zip(units.iter())
+        .map(|(sale, units)| {
+            floor(sale)
+                .filter(|floor| *floor > BigInt::zero())
+                .map(|floor| floor * units.clone())
+        })
+        .collect();
+
+    let (allocation, weights) = match floor_weights {
+        Some(weights) => (Allocation::Floor, weights),
+        None => (Allocation::Even, units),
+    };
+    let total_weight = weights.iter().fold(BigInt::zero(), |total, weight| total + weight.clone());
+
+    let mut allocated = BigInt::zero();
+    for (i, (sale, weight)) in bundle.iter_mut().zip(weights).enumerate() {
+        let price = if i + 1 == bundle_size as usize || total_weight == BigInt::zero() {
+            bundle_price.clone() - allocated.clone()
+        } else {
+            bundle_price.clone() * weight / total_weight.clone()
+        };
+        allocated = allocated + price.clone();
+
+        let scale = |fee: &str| match BigInt::try_from(fee.to_string()) {
+            Ok(fee) if bundle_price != BigInt::zero() => (fee * price.clone() / bundle_price.clone()).to_string(),
+            _ => fee.to_string(),
+        };
+        sale.protocol_fee = scale(&sale.protocol_fee);
+        sale.royalty_fee = scale(&sale.royalty_fee);
+
+        sale.price = price.to_string();
+        sale.bundle_size = bundle_size;
+        sale.bundle_price = bundle_price.to_string();
+        sale.allocation = allocation_to_string(allocation);
+    }
+
+    bundle
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sale(contract: u8, price: &str, quantity: &str, protocol_fee: &str) -> sales::Sale {
+        sales::Sale {
+            contract_address: vec![contract; 20],
+            price: price.to_string(),
+            quantity: quantity.to_string(),
+            protocol_fee: protocol_fee.to_string(),
+            bundle_id: "bundle".to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn prices(bundle: &[sales::Sale]) -> Vec<&str> {
+        bundle.iter().map(|sale| sale.price.as_str()).collect()
+    }
+
+    #[test]
+    fn it_allocate_bundle_pro_rata_to_floors() {
+        let bundle = vec![sale(1, "1000", "1", ""), sale(2, "1000", "1", ""), sale(2, "1000", "1", "")];
+        let floor = |sale: &sales::Sale| match sale.contract_address[0] {
+            1 => Some(BigInt::from(100)),
+            _ => Some(BigInt::from(200)),
+        };
+
+        let bundle = allocate_bundle(bundle, floor);
+        assert_eq!(prices(&bundle), vec!["200", "400", "400"]);
+        assert!(bundle.iter().all(|sale| sale.allocation == allocation_to_string(Allocation::Floor)));
+        assert!(bundle.iter().all(|sale| sale.bundle_price == "1000" && sale.bundle_size == 3));
+    }
+
+    #[test]
+    fn it_allocate_bundle_sums_to_bundle_price() {
+        let bundle = vec![sale(1, "1000", "1", ""), sale(2, "1000", "1", ""), sale(3, "1000", "1", "")];
+        let floor = |sale: &sales::Sale| Some(BigInt::from(sale.contract_address[0] as u64));
+
+        let bundle = allocate_bundle(bundle, floor);
+        assert_eq!(prices(&bundle), vec!["166", "333", "501"]);
+        let total = bundle
+            .iter()
+            .fold(BigInt::zero(), |total, sale| total + BigInt::try_from(sale.price.clone()).unwrap());
+        assert_eq!(total, BigInt::from(1000));
+    }
+
+    #[test]
+    fn it_allocate_bundle_evenly_without_every_floor() {
+        let bundle = vec![sale(1, "900", "1", ""), sale(2, "900", "2", "")];
+        let floor = |sale: &sales::Sale| match sale.contract_address[0] {
+            1 => Some(BigInt::from(100)),
+            _ => None,
+        };
+
+        let bundle = allocate_bundle(bundle, floor);
+        assert_eq!(prices(&bundle), vec!["300", "600"]);
+        assert!(bundle.iter().all(|sale| sale.allocation == allocation_to_string(Allocation::Even)));
+    }
+
+    #[test]
+    fn it_allocate_bundle_scales_fees() {
+        let bundle = vec![sale(1, "1000", "1", "50"), sale(2, "1000", "3", "50")];
+
+        let bundle = allocate_bundle(bundle, |_| None);
+        assert_eq!(prices(&bundle), vec!["250", "750"]);
+        assert_eq!(bundle[0].protocol_fee, "12");
+        assert_eq!(bundle[1].protocol_fee, "37");
+        assert_eq!(bundle[0].royalty_fee, "");
+    }
+
+    #[test]
+    fn it_allocate_bundle_single_sale() {
+        let bundle = allocate_bundle(vec![sale(1, "1000", "1", "50")], |_| None);
+        assert_eq!(prices(&bundle), vec!["1000"]);
+        assert_eq!(bundle[0].protocol_fee, "50");
+        assert_eq!(bundle[0].bundle_id, "");
+        assert_eq!(bundle[0].allocation, allocation_to_string(Allocation::Single));
+    }
+}
@@ -0,0 +1,179 @@
+use substreams::pb::substreams::store_delta::Operation as DeltaOperation;
+use substreams::store::{DeltaString, Deltas, StoreGet, StoreGetInt64, StoreGetString, StoreSet, StoreSetString};
+use substreams::Hex;
+use substreams_database_change::pb::database::{table_change::Operation, DatabaseChanges};
+use substreams_ethereum::pb::eth::v2 as eth;
+use substreams_ethereum::Event;
+
+use crate::abi::erc1967::events::AdminChanged as AdminChangedEvent;
+use crate::abi::erc1967::events::BeaconUpgraded as BeaconUpgradedEvent;
+use crate::abi::erc1967::events::Upgraded as UpgradedEvent;
+use crate::abi::ownable::events::OwnershipTransferred as OwnershipTransferredEvent;
+use crate::contracts;
+use crate::pb::contracts as contracts_pb;
+
+// Each role is also the name of its column in the `contracts` table.
+const OWNER_ROLE: &str = "owner";
+const ADMIN_ROLE: &str = "admin";
+const IMPLEMENTATION_ROLE: &str = "implementation";
+const BEACON_ROLE: &str = "beacon";
+
+pub fn role_key(contract_address: &[u8], role: &str) -> String {
+    format!("{}:{}", contracts::contract_key(contract_address), role)
+}
+
+/// Tracks the Ownable owner and the ERC-1967 proxy admin, implementation and beacon of every
+/// contract emitting their events, values being the hex address. Proxies announce their
+/// implementation from their constructor, long before their first transfer, so the events are
+/// followed for any contract and new contracts pick up their roles from the store.
+pub fn store_roles(blk: &eth::Block, store: &StoreSetString) {
+    for log in blk.receipts().flat_map(|receipt| receipt.receipt.logs.iter()) {
+        let role = if let Some(event) = OwnershipTransferredEvent::match_and_decode(log) {
+            Some((OWNER_ROLE, event.new_owner))
+        } else if let Some(event) = AdminChangedEvent::match_and_decode(log) {
+            Some((ADMIN_ROLE, event.new_admin))
+        } else if let Some(event) = UpgradedEvent::match_and_decode(log) {
+            Some((IMPLEMENTATION_ROLE, event.implementation))
+        } else if let Some(event) = BeaconUpgradedEvent::match_and_decode(log) {
+            Some((BEACON_ROLE, event.beacon))
+        } else {
+            None
+        };
+
+        if let Some((role, address)) = role {
+            store.set(log.ordinal, role_key(&log.address, role), &Hex(&address).to_string());
+        }
+    }
+}
+
+/// The address currently holding the role of the contract, empty if the role was never set.
+pub fn role_address(roles: &StoreGetString, contract_address: &[u8], role: &str) -> Vec<u8> {
+    roles
+        .get_last(role_key(contract_address, role))
+        .and_then(|address| Hex::decode(address).ok())
+        .unwrap_or_default()
+}
+
+/// Fills the roles of a new contract from the roles store, the owner falling back to `owner()` for
+/// contracts never emitting `OwnershipTransferred`.
+pub fn set_roles(contract: &mut contracts_pb::Contract, roles: &StoreGetString) {
+    let owner = role_address(roles, &contract.address, OWNER_ROLE);
+    if !owner.is_empty() {
+        contract.owner = owner;
+    }
+    contract.admin = role_address(roles, &contract.address, ADMIN_ROLE);
+    contract.implementation = role_address(roles, &contract.address, IMPLEMENTATION_ROLE);
+    contract.beacon = role_address(roles, &contract.address, BEACON_ROLE);
+}
+
+/// Keeps the role columns of `contracts` current from the roles store deltas and records the
+/// changes in `contract_role_changes`, for the contracts of the `contracts` table only. Only the
+/// contracts seen in an earlier block are updated, the ones first seen in the block being created
+/// with their current roles.
+pub fn push_role_changes(
+    changes: &mut DatabaseChanges,
+    block_number: u64,
+    deltas: Deltas<DeltaString>,
+    first_seen: &StoreGetInt64,
+) {
+    push_known_role_changes(changes, block_number, deltas, |key| first_seen.get_last(key));
+}
+
+// `first_seen` gives the block a contract was first transferred at from its contract key.
+fn push_known_role_changes(
+    changes: &mut DatabaseChanges,
+    block_number: u64,
+    deltas: Deltas<DeltaString>,
+    first_seen: impl Fn(&str) -> Option<i64>,
+) {
+    for delta in deltas.deltas {
+        // Setting a role to its current holder, e.g. upgrading to the same implementation, is no
+        // change.
+        let old_value = match delta.operation {
+            DeltaOperation::Create => None,
+            DeltaOperation::Update if delta.old_value != delta.new_value => Some(delta.old_value),
+            _ => continue,
+        };
+
+        let (contract_key, role) = delta.key.rsplit_once(':').unwrap_or_default();
+        let first_block = match first_seen(contract_key) {
+            Some(first_block) => first_block as u64,
+            // Not an NFT contract, or one never transferred yet.
+            None => continue,
+        };
+        let contract = contract_key.trim_start_matches("contract:").to_string();
+        let role = role.to_string();
+
+        if first_block < block_number {
+            changes
+                .push_change("contracts", &contract, delta.ordinal, Operation::Update)
+                .change(role.as_str(), (old_value.clone(), delta.new_value.clone()));
+        }
+
+        let key = format!("{}-{}-{}-{}", contract, role, block_number, delta.ordinal);
+        changes
+            .push_change("contract_role_changes", &key, delta.ordinal, Operation::Create)
+            .change("contract", (None, contract))
+            .change("role", (None, role))
+            .change("previous_address", (None, old_value.unwrap_or_default()))
+            .change("new_address", (None, delta.new_value))
+            .change("block_number", (None, block_number))
+            .change("ordinal", (None, delta.ordinal));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NFT: [u8; 20] = [0x11; 20];
+    const NEW_NFT: [u8; 20] = [0x22; 20];
+    const TOKEN: [u8; 20] = [0x33; 20];
+
+    fn delta(contract: &[u8], ordinal: u64) -> DeltaString {
+        DeltaString {
+            operation: DeltaOperation::Create,
+            ordinal,
+            key: role_key(contract, IMPLEMENTATION_ROLE),
+            old_value: String::new(),
+            new_value: "44".repeat(20),
+        }
+    }
+
+    #[test]
+    fn it_push_role_changes_of_known_contracts_only() {
+        let first_seen = |key: &str| match key {
+            key if key == contracts::contract_key(&NFT) => Some(90),
+            key if key == contracts::contract_key(&NEW_NFT) => Some(100),
+            _ => None,
+        };
+
+        let mut changes = DatabaseChanges::default();
+        push_known_role_changes(
+            &mut changes,
+            100,
+            Deltas {
+                deltas: vec![delta(&NFT, 1), delta(&NEW_NFT, 2), delta(&TOKEN, 3)],
+            },
+            first_seen,
+        );
+
+        let rows: Vec<(&str, &str)> = changes
+            .table_changes
+            .iter()
+            .map(|change| (change.table.as_str(), change.pk.as_str()))
+            .collect();
+        let nft = Hex(&NFT).to_string();
+        let nft_history = format!("{}-implementation-100-1", nft);
+        let new_nft_history = format!("{}-implementation-100-2", Hex(&NEW_NFT));
+        // The new contract is created with its roles, the token contract has no row at all.
+        assert_eq!(
+            rows,
+            vec![
+                ("contracts", nft.as_str()),
+                ("contract_role_changes", nft_history.as_str()),
+                ("contract_role_changes", new_nft_history.as_str()),
+            ]
+        );
+    }
+}
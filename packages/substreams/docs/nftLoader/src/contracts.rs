@@ -0,0 +1,339 @@
+use std::collections::HashSet;
+
+use hex_literal::hex;
+use substreams::scalar::BigInt;
+use substreams::pb::substreams::store_delta::Operation as DeltaOperation;
+use substreams::store::{
+    DeltaBigInt, Deltas, StoreAdd, StoreAddBigInt, StoreGet, StoreGetInt64, StoreGetProto, StoreGetString, StoreMin,
+    StoreMinInt64, StoreSet, StoreSetProto,
+};
+use substreams::Hex;
+use substreams_database_change::pb::database::{table_change::Operation, DatabaseChanges};
+use substreams_ethereum::pb::eth::rpc::RpcResponse;
+use substreams_ethereum::rpc::RpcBatch;
+
+use crate::abi::erc2981::functions::RoyaltyInfo as RoyaltyInfoFunction;
+use crate::abi::erc721::functions::Name as NameFunction;
+use crate::abi::erc721::functions::SupportsInterface as SupportsInterfaceFunction;
+use crate::abi::erc721::functions::Symbol as SymbolFunction;
+use crate::abi::ownable::functions::Owner as OwnerFunction;
+use crate::contract_roles;
+use crate::pb::contracts;
+use crate::pb::transfers;
+use crate::rpc;
+use crate::token_uri;
+
+// The interfaces probed on every new contract, in the order of their bit in `Contract.interfaces`.
+pub const INTERFACE_IDS: [[u8; 4]; 8] = [
+    hex!("80ac58cd"), // ERC721
+    hex!("5b5e139f"), // ERC721Metadata
+    hex!("780e9d63"), // ERC721Enumerable
+    hex!("d9b67a26"), // ERC1155
+    hex!("0e89341c"), // ERC1155MetadataURI
+    hex!("2a55205a"), // ERC2981
+    hex!("ad092b5c"), // ERC4907
+    hex!("b45a3c0e"), // ERC5192
+];
+
+const ERC2981_INTERFACE_BIT: usize = 5;
+
+// The calls made for every new contract, after the `supportsInterface` ones of `INTERFACE_IDS`.
+const NAME_CALL: usize = INTERFACE_IDS.len();
+const SYMBOL_CALL: usize = NAME_CALL + 1;
+const OWNER_CALL: usize = NAME_CALL + 2;
+const ROYALTY_CALL: usize = NAME_CALL + 3;
+const CONTRACT_CALLS: usize = NAME_CALL + 4;
+
+const ZERO_ADDRESS: [u8; 20] = [0u8; 20];
+
+// Asking the royalty of a sale of 10000 gives it directly in basis points.
+const ROYALTY_SALE_PRICE: u64 = 10_000;
+
+pub fn contract_key(contract_address: &[u8]) -> String {
+    format!("contract:{}", Hex(contract_address))
+}
+
+pub fn nft_key(contract_address: &[u8], token_id: &[u8]) -> String {
+    format!("nft:{}:{}", Hex(contract_address), Hex(token_id))
+}
+
+/// Records the block at which each contract and token was first transferred.
+pub fn store_first_seen(transfers: &[transfers::Transfer], store: &StoreMinInt64) {
+    for transfer in transfers {
+        let block_number = transfer.block_number as i64;
+
+        store.min(transfer.ordinal, contract_key(&transfer.contract_address), block_number);
+        store.min(
+            transfer.ordinal,
+            nft_key(&transfer.contract_address, &transfer.token_id), block_number);
+    }
+}
+
+fn first_seen_in_block(first_seen: &StoreGetInt64, key: &str, block_number: u64) -> bool {
+    first_seen.get_last(key) == Some(block_number as i64)
+}
+
+/// Builds the contracts transferred for the first time in the block. Name and symbol are only
+/// fetched once, collections renaming themselves afterwards are not followed. The owner is only
+/// used when the contract never emitted `OwnershipTransferred`, the roles being taken from the
+/// contract roles store.
+///
+/// The calls of all the new contracts are made in a single batch. The royalty is asked along with
+/// the rest and ignored when the contract turns out not to support EIP-2981.
+pub fn new_contracts(
+    transfers: &[transfers::Transfer],
+    first_seen: &StoreGetInt64,
+    roles: &StoreGetString,
+) -> Vec<contracts::Contract> {
+    let mut seen: HashSet<String> = HashSet::new();
+    let transfers: Vec<&transfers::Transfer> = transfers
+        .iter()
+        .filter(|transfer| {
+            let key = contract_key(&transfer.contract_address);
+
+            first_seen_in_block(first_seen, &key, transfer.block_number) && seen.insert(key)
+        })
+        .collect();
+    if transfers.is_empty() {
+        return Vec::new();
+    }
+
+    let mut batch = RpcBatch::new();
+    for transfer in transfers.iter() {
+        let address = &transfer.contract_address;
+
+        for interface_id in INTERFACE_IDS.iter() {
+            batch = batch.add(
+                SupportsInterfaceFunction {
+                    interface_id: *interface_id,
+                },
+                address.clone(),
+            );
+        }
+        batch = batch
+            .add(NameFunction {}, address.clone())
+            .add(SymbolFunction {}, address.clone())
+            .add(OwnerFunction {}, address.clone())
+            .add(royalty_call(&transfer.token_id), address.clone());
+    }
+    let responses = rpc::execute(batch);
+
+    transfers
+        .into_iter()
+        .enumerate()
+        .map(|(index, transfer)| {
+            let response = |call: usize| responses.get(index * CONTRACT_CALLS + call);
+
+            let interfaces = interfaces((0..INTERFACE_IDS.len()).map(response));
+            let supports_royalties = interfaces & (1 << ERC2981_INTERFACE_BIT) != 0;
+            let (royalty_receiver, royalty_bps) = if supports_royalties {
+                royalty(response(ROYALTY_CALL))
+            } else {
+                (Vec::new(), String::new())
+            };
+
+            let mut contract = contracts::Contract {
+                address: transfer.contract_address.clone(),
+                schema: transfer.schema.clone(),
+                supports_royalties,
+                royalty_receiver,
+                royalty_bps,
+                ordinal: transfer.ordinal,
+                block_number: transfer.block_number,
+                timestamp: transfer.timestamp.clone(),
+                name: rpc::decode::<_, NameFunction>(response(NAME_CALL)).unwrap_or_default(),
+                symbol: rpc::decode::<_, SymbolFunction>(response(SYMBOL_CALL)).unwrap_or_default(),
+                interfaces,
+                owner: rpc::decode::<_, OwnerFunction>(response(OWNER_CALL)).unwrap_or_default(),
+                ..Default::default()
+            };
+            contract_roles::set_roles(&mut contract, roles);
+
+            contract
+        })
+        .collect()
+}
+
+pub fn store_contracts(contracts: &[contracts::Contract], store: &StoreSetProto<contracts::Contract>) {
+    for contract in contracts {
+        store.set(contract.ordinal, contract_key(&contract.address), contract);
+    }
+}
+
+/// Builds the tokens transferred for the first time in the block. The royalty of the token is
+/// only asked when its contract supports EIP-2981, and the metadata is only decoded when the
+/// token URI embeds it as a `data:` URI. The calls of all the new tokens are made in a single batch.
+pub fn new_nfts(
+    transfers: &[transfers::Transfer],
+    first_seen: &StoreGetInt64,
+    contracts: &StoreGetProto<contracts::Contract>,
+) -> Vec<contracts::Nft> {
+    let mut seen: HashSet<String> = HashSet::new();
+    let transfers: Vec<&transfers::Transfer> = transfers
+        .iter()
+        .filter(|transfer| {
+            let key = nft_key(&transfer.contract_address, &transfer.token_id);
+
+            first_seen_in_block(first_seen, &key, transfer.block_number) && seen.insert(key)
+        })
+        .collect();
+    if transfers.is_empty() {
+        return Vec::new();
+    }
+
+    // The index in the batch of the royalty call, if any, and of the token URI call of each token.
+    let mut calls: Vec<(Option<usize>, usize)> = Vec::new();
+    let mut batch = RpcBatch::new();
+    let mut call_count = 0;
+    for transfer in transfers.iter() {
+        let supports_royalties = contracts
+            .get_last(contract_key(&transfer.contract_address))
+            .map(|contract| contract.supports_royalties)
+            .unwrap_or(false);
+
+        let royalty_index = if supports_royalties {
+            batch = batch.add(royalty_call(&transfer.token_id), transfer.contract_address.clone());
+            call_count += 1;
+            Some(call_count - 1)
+        } else {
+            None
+        };
+        batch = token_uri::add_token_uri_call(batch, &transfer.contract_address, &transfer.token_id, &transfer.schema);
+        call_count += 1;
+
+        calls.push((royalty_index, call_count - 1));
+    }
+    let responses = rpc::execute(batch);
+
+    transfers
+        .into_iter()
+        .zip(calls)
+        .map(|(transfer, (royalty_index, token_uri_index))| {
+            let (royalty_receiver, royalty_bps) = match royalty_index {
+                Some(index) => royalty(responses.get(index)),
+                None => (Vec::new(), String::new()),
+            };
+            let token_uri = token_uri::decode_token_uri(responses.get(token_uri_index), &transfer.schema);
+            let metadata = token_uri::decode_data_uri(&token_uri).unwrap_or_default();
+
+            contracts::Nft {
+                contract_address: transfer.contract_address.clone(),
+                token_id: transfer.token_id.clone(),
+                schema: transfer.schema.clone(),
+                royalty_receiver,
+                royalty_bps,
+                ordinal: transfer.ordinal,
+                block_number: transfer.block_number,
+                timestamp: transfer.timestamp.clone(),
+                // `data:` URIs are kept as is, the metadata they embed being capped the same way.
+                canonical_uri: token_uri::cap(token_uri::canonical_uri(&token_uri, &transfer.token_id)),
+                token_uri: token_uri::cap(token_uri),
+                metadata: metadata.json,
+                name: metadata.name,
+                image: metadata.image,
+                attributes: metadata.attributes,
+            }
+        })
+        .collect()
+}
+
+/// Tracks the total supply of each contract from its mints (transfers from the zero address) and
+/// burns (transfers to the zero address), counted in units for ERC1155.
+pub fn store_supply(transfers: &[transfers::Transfer], store: &StoreAddBigInt) {
+    for transfer in transfers {
+        let quantity = match BigInt::try_from(transfer.quantity.clone()) {
+            Ok(quantity) => quantity,
+            Err(_) => continue,
+        };
+
+        if transfer.from_address == ZERO_ADDRESS {
+            store.add(transfer.ordinal, contract_key(&transfer.contract_address), quantity);
+        } else if transfer.to_address == ZERO_ADDRESS {
+            store.add(transfer.ordinal, contract_key(&transfer.contract_address), BigInt::zero() - quantity);
+        }
+    }
+}
+
+/// Decodes the `supportsInterface` responses for each of `INTERFACE_IDS`, a failing call counting as
+/// unsupported.
+fn interfaces<'a>(responses: impl Iterator<Item = Option<&'a RpcResponse>>) -> u64 {
+    responses
+        .enumerate()
+        .filter(|(_, response)| rpc::decode::<_, SupportsInterfaceFunction>(*response).unwrap_or(false))
+        .fold(0, |interfaces, (bit, _)| interfaces | (1 << bit))
+}
+
+// Asks `royaltyInfo(tokenId, 10000)`, which gives the royalty in basis points.
+fn royalty_call(token_id: &[u8]) -> RoyaltyInfoFunction {
+    RoyaltyInfoFunction {
+        token_id: BigInt::from_unsigned_bytes_be(token_id),
+        sale_price: BigInt::from(ROYALTY_SALE_PRICE),
+    }
+}
+
+/// Decodes the response to `royalty_call`, returning the receiver and the royalty in basis points.
+fn royalty(response: Option<&RpcResponse>) -> (Vec<u8>, String) {
+    match rpc::decode::<_, RoyaltyInfoFunction>(response) {
+        Some((receiver, amount)) => (receiver, amount.to_string()),
+        None => (Vec::new(), String::new()),
+    }
+}
+
+pub fn push_contract_create(changes: &mut DatabaseChanges, value: contracts::Contract) {
+    let key = Hex(&value.address).to_string();
+
+    changes
+        .push_change("contracts", &key, value.ordinal, Operation::Create)
+        .change("contract", (None, Hex(value.address)))
+        .change("schema", (None, value.schema))
+        .change("supports_royalties", (None, value.supports_royalties.to_string()))
+        .change("royalty_receiver", (None, Hex(value.royalty_receiver)))
+        .change("royalty_bps", (None, value.royalty_bps))
+        .change("block_number", (None, value.block_number))
+        .change("ordinal", (None, value.ordinal))
+        .change("timestamp", (None, value.timestamp.unwrap()))
+        .change("name", (None, value.name))
+        .change("symbol", (None, value.symbol))
+        .change("interfaces", (None, value.interfaces))
+        .change("owner", (None, Hex(value.owner)))
+        .change("admin", (None, Hex(value.admin)))
+        .change("implementation", (None, Hex(value.implementation)))
+        .change("beacon", (None, Hex(value.beacon)));
+}
+
+/// Updates `contracts.total_supply` from the supply store deltas. The contract row is always
+/// created in the same block or earlier, by its first transfer.
+pub fn push_supply_changes(changes: &mut DatabaseChanges, deltas: Deltas<DeltaBigInt>) {
+    for delta in deltas.deltas {
+        let key = delta.key.trim_start_matches("contract:");
+        let old_value = match delta.operation {
+            DeltaOperation::Create => None,
+            _ => Some(delta.old_value.to_string()),
+        };
+
+        changes
+            .push_change("contracts", key, delta.ordinal, Operation::Update)
+            .change("total_supply", (old_value, delta.new_value.to_string()));
+    }
+}
+
+pub fn push_nft_create(changes: &mut DatabaseChanges, value: contracts::Nft) {
+    let key = format!("{}-{}", Hex(&value.contract_address), Hex(&value.token_id));
+
+    changes
+        .push_change("nfts", &key, value.ordinal, Operation::Create)
+        .change("contract", (None, Hex(value.contract_address)))
+        .change("token_id", (None, Hex(value.token_id)))
+        .change("schema", (None, value.schema))
+        .change("royalty_receiver", (None, Hex(value.royalty_receiver)))
+        .change("royalty_bps", (None, value.royalty_bps))
+        .change("block_number", (None, value.block_number))
+        .change("ordinal", (None, value.ordinal))
+        .change("timestamp", (None, value.timestamp.unwrap()))
+        .change("tokenUri", (None, value.token_uri))
+        .change("canonical_uri", (None, value.canonical_uri))
+        .change("metadata", (None, value.metadata))
+        .change("name", (None, value.name))
+        .change("image", (None, value.image))
+        .change("attributes", (None, value.attributes));
+}
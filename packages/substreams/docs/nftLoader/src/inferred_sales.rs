@@ -0,0 +1,307 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+use hex_literal::hex;
+use substreams::scalar::BigInt;
+use substreams::Hex;
+use substreams_ethereum::pb::eth::v2 as eth;
+use substreams_ethereum::pb::eth::v2::balance_change::Reason as BalanceChangeReason;
+use substreams_ethereum::Event;
+
+use crate::abi::erc20::events::Transfer as ERC20TransferEvent;
+use crate::looksrare;
+use crate::pb::sales;
+use crate::pb::transfers;
+use crate::seaport;
+
+pub const MARKETPLACE_NAME: &str = "unknown";
+
+// Both sides of the trade were observed: the buyer paid and the seller got paid, in the same
+// currency.
+const CONFIDENCE_MATCHED: u32 = 90;
+// The transaction signer paid for an NFT received by someone else, usually through an aggregator,
+// and the seller got paid.
+const CONFIDENCE_MATCHED_SIGNER: u32 = 75;
+// Only the buyer is seen paying, the seller being paid through an escrow or a later withdrawal.
+const CONFIDENCE_BUYER_ONLY: u32 = 50;
+// Only the seller is seen getting paid, the payer not being part of the trade.
+const CONFIDENCE_SELLER_ONLY: u32 = 40;
+
+const ZERO_ADDRESS: [u8; 20] = [0u8; 20];
+
+const BLUR_EXCHANGE_ADDRESS: [u8; 20] = hex!("000000000000ad05ccc4f10045630fb830b95127");
+
+const SEAPORT_ORDER_FULFILLED: [u8; 32] = hex!("9d9af8e38d66c62e2c12f0225249fd9d721c54b83f48d9352c97c6cacdcb6f31");
+const LOOKSRARE_TAKER_ASK: [u8; 32] = hex!("68cd251d4d267c6e2034ff0088b990352b97b2002c0476587d0c4da889c11330");
+const LOOKSRARE_TAKER_BID: [u8; 32] = hex!("95fb6205e23ff6bda16a2d1dba56b9ad7c783f67c96fa149785052f47696f2be");
+const BLUR_ORDERS_MATCHED: [u8; 32] = hex!("61cbb2a3dee0b6064c2e681aadd61677fb4ef319f0b547508d495626f5a62f64");
+
+// The fulfilment events of the marketplaces whose sales are not decoded yet, along with the
+// contract emitting them. Inferring their trades would record them as `unknown` sales.
+const MARKETPLACE_FULFILMENTS: [([u8; 20], [u8; 32]); 5] = [
+    (seaport::SEAPORT_ADDRESSES[0], SEAPORT_ORDER_FULFILLED),
+    (seaport::SEAPORT_ADDRESSES[1], SEAPORT_ORDER_FULFILLED),
+    (looksrare::EXCHANGE_ADDRESS, LOOKSRARE_TAKER_ASK),
+    (looksrare::EXCHANGE_ADDRESS, LOOKSRARE_TAKER_BID),
+    (BLUR_EXCHANGE_ADDRESS, BLUR_ORDERS_MATCHED),
+];
+
+/// Net value moved per `(payment token, account)` within a transaction, the payment token being
+/// empty for ETH. Ordered so that ETH is always considered first and inference is deterministic.
+pub type ValueFlows = BTreeMap<(Vec<u8>, Vec<u8>), BigInt>;
+
+/// Computes the value flows of each transaction of the block, keyed by transaction hash. ETH flows
+/// come from the balance changes of the calls that were not reverted, ERC20 flows from the
+/// `Transfer` logs.
+pub fn value_flows(blk: &eth::Block) -> HashMap<Vec<u8>, ValueFlows> {
+    let mut value_flows: HashMap<Vec<u8>, ValueFlows> = HashMap::new();
+
+    for receipt in blk.receipts() {
+        let flows = value_flows.entry(receipt.transaction.hash.clone()).or_default();
+        let mut add = |token: &[u8], account: &[u8], value: BigInt| {
+            let flow = flows.entry((token.to_vec(), account.to_vec())).or_insert_with(BigInt::zero);
+            *flow = flow.clone() + value;
+        };
+
+        for call in receipt.transaction.calls.iter().filter(|call| !call.state_reverted) {
+            for change in &call.balance_changes {
+                if change.reason != BalanceChangeReason::Transfer as i32 {
+                    continue;
+                }
+
+                add(&[], &change.address, to_bigint(&change.new_value) - to_bigint(&change.old_value));
+            }
+        }
+
+        for log in receipt.receipt.logs.iter() {
+            if let Some(event) = ERC20TransferEvent::match_and_decode(log) {
+                add(&log.address, &event.from, BigInt::zero() - event.value.clone());
+                add(&log.address, &event.to, event.value);
+            }
+        }
+    }
+
+    value_flows
+}
+
+/// The hashes of the transactions of the block in which Seaport, LooksRare or Blur fulfilled an
+/// order. Their transfers are left to these marketplaces rather than inferred.
+pub fn marketplace_txs(blk: &eth::Block) -> HashSet<Vec<u8>> {
+    blk.receipts()
+        .filter(|receipt| {
+            receipt.receipt.logs.iter().any(|log| {
+                MARKETPLACE_FULFILMENTS.iter().any(|(address, topic)| {
+                    log.address == address && log.topics.first().map(|first| first == topic).unwrap_or(false)
+                })
+            })
+        })
+        .map(|receipt| receipt.transaction.hash.clone())
+        .collect()
+}
+
+fn to_bigint(value: &Option<eth::BigInt>) -> BigInt {
+    BigInt::from_unsigned_bytes_be(value.as_ref().map(|value| value.bytes.as_slice()).unwrap_or_default())
+}
+
+// The account is the one whose flow gave the amount, the NFTs of a transaction sharing the same
+// account and currency are a bundle sold for that amount.
+struct Payment {
+    account: Vec<u8>,
+    payment_token: Vec<u8>,
+    amount: BigInt,
+    confidence: u32,
+}
+
+/// Infers the sales of transactions no known marketplace settled, by pairing each NFT transfer with
+/// the value flowing in the opposite direction. Transactions of `known_sales` and `marketplace_txs`
+/// are skipped. A payment covering several NFTs is a bundle, split by `bundles::allocate`.
+pub fn sales_from_transfers(
+    transfers: &[transfers::Transfer],
+    value_flows: &HashMap<Vec<u8>, ValueFlows>,
+    known_sales: &[sales::Sale],
+    marketplace_txs: &HashSet<Vec<u8>>,
+) -> Vec<sales::Sale> {
+    let known_txs: HashSet<&[u8]> = known_sales
+        .iter()
+        .map(|sale| sale.tx_hash.as_slice())
+        .chain(marketplace_txs.iter().map(|tx_hash| tx_hash.as_slice()))
+        .collect();
+    let empty_flows = ValueFlows::new();
+
+    transfers
+        .iter()
+        .filter(|transfer| transfer.from_address != ZERO_ADDRESS && transfer.to_address != ZERO_ADDRESS)
+        .filter(|transfer| transfer.from_address != transfer.to_address)
+        .filter(|transfer| !known_txs.contains(transfer.tx_hash.as_slice()))
+        .filter_map(|transfer| {
+            let flows = value_flows.get(&transfer.tx_hash).unwrap_or(&empty_flows);
+            let payment = payment(transfer, flows)?;
+
+            Some(sales::Sale {
+                marketplace: MARKETPLACE_NAME.to_string(),
+                order_hash: Vec::new(),
+                maker: Vec::new(),
+                taker: Vec::new(),
+                seller: transfer.from_address.clone(),
+                buyer: transfer.to_address.clone(),
+                schema: transfer.schema.clone(),
+                contract_address: transfer.contract_address.clone(),
+                token_id: transfer.token_id.clone(),
+                quantity: transfer.quantity.clone(),
+                payment_token: payment.payment_token.clone(),
+                price: payment.amount.to_string(),
+                protocol_fee: String::new(),
+                royalty_fee: String::new(),
+                tx_hash: transfer.tx_hash.clone(),
+                ordinal: transfer.ordinal,
+                block_number: transfer.block_number,
+                timestamp: transfer.timestamp.clone(),
+                confidence: payment.confidence,
+                bundle_id: format!(
+                    "{}-{}-{}",
+                    Hex(&transfer.tx_hash),
+                    Hex(&payment.account),
+                    Hex(&payment.payment_token)
+                ),
+                ..Default::default()
+            })
+        })
+        .collect()
+}
+
+// Picks the best payment for a transfer over all currencies, the buyer paying and the seller
+// getting paid in the same currency being preferred to a one-sided flow.
+fn payment(transfer: &transfers::Transfer, flows: &ValueFlows) -> Option<Payment> {
+    let currencies: BTreeSet<&Vec<u8>> = flows.keys().map(|(token, _)| token).collect();
+    let flow = |token: &Vec<u8>, account: &[u8]| {
+        flows
+            .get(&(token.clone(), account.to_vec()))
+            .cloned()
+            .unwrap_or_else(BigInt::zero)
+    };
+
+    let mut best: Option<Payment> = None;
+    for token in currencies {
+        let buyer_paid = BigInt::zero() - flow(token, &transfer.to_address);
+        let signer_paid = BigInt::zero() - flow(token, &transfer.tx_from);
+        let seller_received = flow(token, &transfer.from_address);
+
+        let seller_paid = seller_received > BigInt::zero();
+        let candidate = if buyer_paid > BigInt::zero() {
+            let confidence = if seller_paid { CONFIDENCE_MATCHED } else { CONFIDENCE_BUYER_ONLY };
+            Payment {
+                account: transfer.to_address.clone(),
+                payment_token: token.clone(),
+                amount: buyer_paid,
+                confidence,
+            }
+        } else if signer_paid > BigInt::zero() && seller_paid {
+            Payment {
+                account: transfer.tx_from.clone(),
+                payment_token: token.clone(),
+                amount: signer_paid,
+                confidence: CONFIDENCE_MATCHED_SIGNER,
+            }
+        } else if seller_paid {
+            Payment {
+                account: transfer.from_address.clone(),
+                payment_token: token.clone(),
+                amount: seller_received,
+                confidence: CONFIDENCE_SELLER_ONLY,
+            }
+        } else {
+            continue;
+        };
+
+        if best.as_ref().map(|best| candidate.confidence > best.confidence).unwrap_or(true) {
+            best = Some(candidate);
+        }
+    }
+
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+
+    const SELLER: [u8; 20] = [0x01; 20];
+    const BUYER: [u8; 20] = [0x02; 20];
+    const SIGNER: [u8; 20] = [0x03; 20];
+    const WETH: [u8; 20] = [0x04; 20];
+
+    fn transfer(tx_hash: u8) -> transfers::Transfer {
+        transfers::Transfer {
+            schema: "erc721".to_string(),
+            contract_address: vec![0x11; 20],
+            token_id: vec![1],
+            from_address: SELLER.to_vec(),
+            to_address: BUYER.to_vec(),
+            quantity: "1".to_string(),
+            tx_hash: vec![tx_hash; 32],
+            tx_from: SIGNER.to_vec(),
+            ..Default::default()
+        }
+    }
+
+    fn flows(values: &[(&[u8], [u8; 20], i64)]) -> ValueFlows {
+        values
+            .iter()
+            .map(|(token, account, value)| ((token.to_vec(), account.to_vec()), BigInt::from(*value)))
+            .collect()
+    }
+
+    fn assert_payment(flows: ValueFlows, account: [u8; 20], amount: i64, confidence: u32) {
+        let payment = payment(&transfer(0xaa), &flows).unwrap();
+        assert_eq!(payment.account, account.to_vec());
+        assert_eq!(payment.amount, BigInt::from(amount));
+        assert_eq!(payment.confidence, confidence);
+    }
+
+    #[test]
+    fn it_payment_confidence_tiers() {
+        assert_payment(flows(&[(&[], BUYER, -100), (&[], SELLER, 95)]), BUYER, 100, CONFIDENCE_MATCHED);
+        assert_payment(flows(&[(&[], SIGNER, -100), (&[], SELLER, 95)]), SIGNER, 100, CONFIDENCE_MATCHED_SIGNER);
+        assert_payment(flows(&[(&[], BUYER, -100)]), BUYER, 100, CONFIDENCE_BUYER_ONLY);
+        assert_payment(flows(&[(&[], SELLER, 95)]), SELLER, 95, CONFIDENCE_SELLER_ONLY);
+        assert!(payment(&transfer(0xaa), &flows(&[(&[], SIGNER, -100)])).is_none());
+    }
+
+    #[test]
+    fn it_payment_picks_best_currency() {
+        let flows = flows(&[(&[], SELLER, 5), (&WETH, BUYER, -100), (&WETH, SELLER, 95)]);
+
+        let payment = payment(&transfer(0xaa), &flows).unwrap();
+        assert_eq!(payment.payment_token, WETH.to_vec());
+        assert_eq!(payment.amount, BigInt::from(100));
+        assert_eq!(payment.confidence, CONFIDENCE_MATCHED);
+    }
+
+    #[test]
+    fn it_skips_known_transactions() {
+        let fulfilment = |address: &[u8]| eth::Log {
+            address: address.to_vec(),
+            topics: vec![SEAPORT_ORDER_FULFILLED.to_vec()],
+            ..Default::default()
+        };
+        let settled = marketplace_txs(&test_utils::block(1, vec![fulfilment(&seaport::SEAPORT_ADDRESSES[0])]));
+        assert!(settled.contains(test_utils::TX_HASH.as_slice()));
+        assert!(marketplace_txs(&test_utils::block(1, vec![fulfilment(&[0x05; 20])])).is_empty());
+
+        let paid = flows(&[(&[], BUYER, -100), (&[], SELLER, 95)]);
+        let value_flows: HashMap<Vec<u8>, ValueFlows> =
+            [0xaa, 0xbb, 0xcc].iter().map(|tx_hash| (vec![*tx_hash; 32], paid.clone())).collect();
+        let known_sales = vec![sales::Sale {
+            tx_hash: vec![0xbb; 32],
+            ..Default::default()
+        }];
+        let transfers = vec![transfer(0xaa), transfer(0xbb), transfer(0xcc)];
+
+        let sales = sales_from_transfers(&transfers, &value_flows, &known_sales, &settled);
+        assert_eq!(sales.len(), 1);
+        assert_eq!(sales[0].tx_hash, vec![0xcc; 32]);
+        assert_eq!(sales[0].marketplace, MARKETPLACE_NAME);
+        assert_eq!(sales[0].price, "100");
+        assert_eq!(sales[0].confidence, CONFIDENCE_MATCHED);
+    }
+}
@@ -2,10 +2,42 @@
 mod abi;
 mod pb;
 mod block_timestamp;
+mod bundles;
+mod contract_roles;
+mod contracts;
+mod inferred_sales;
+mod looksrare;
+mod market;
+mod metadata;
+mod mints;
+mod nftcom;
+mod nft_locks;
+mod nft_users;
+mod nftcom_resolver;
+mod nftx;
+mod ownership;
+mod pools;
+mod prices;
+mod rpc;
+mod seaport;
+mod spam;
+mod sudoswap;
+#[cfg(test)]
+mod test_utils;
+mod token_bound_accounts;
+mod token_uri;
+mod wash_trading;
 
 use std::collections::HashSet;
 use std::fmt::Write;
 use sha2::{Digest, Sha256};
+use pb::contracts as contracts_pb;
+use pb::orders;
+use pb::orders::order_invalidation::Kind as InvalidationKind;
+use pb::ownership as ownership_pb;
+use pb::pools as pools_pb;
+use pb::sales;
+use pb::sales::sale::{Allocation, WashReason};
 use pb::transfers;
 use pb::transfers::transfer::Schema;
 use substreams_database_change::pb::database::{table_change::Operation, DatabaseChanges};
@@ -14,30 +46,401 @@ use substreams_ethereum::pb as ethpb;
 use prost_types::Timestamp;
 use self::block_timestamp::BlockTimestamp;
 use substreams::{log, Hex};
+use substreams::store::{
+    Appender, DeltaBigInt, DeltaInt64, DeltaProto, DeltaString, Deltas, StoreAddBigDecimal, StoreAddBigInt,
+    StoreAddInt64, StoreAppend, StoreGet, StoreGetArray, StoreGetBigDecimal, StoreGetBigInt, StoreGetInt64,
+    StoreGetProto, StoreGetString, StoreMaxBigInt, StoreMaxInt64, StoreMinBigInt, StoreMinInt64, StoreNew,
+    StoreSetBigInt, StoreSetInt64, StoreSetProto, StoreSetString,
+};
 
 use substreams::scalar::BigInt;
-use substreams_ethereum::Event;
+use substreams_ethereum::{Event, Function};
 
 use abi::erc1155::events::TransferBatch as ERC1155TransferBatchEvent;
 use abi::erc1155::events::TransferSingle as ERC1155TransferSingleEvent;
+use abi::erc1155::functions::SafeBatchTransferFrom as ERC1155SafeBatchTransferFromFunction;
+use abi::erc1155::functions::SafeTransferFrom as ERC1155SafeTransferFromFunction;
 use abi::erc20::events::Transfer as ERC20TransferEvent;
 use abi::erc721::events::Transfer as ERC721TransferEvent;
+use abi::erc721::functions::SafeTransferFrom1 as ERC721SafeTransferFrom1Function;
+use abi::erc721::functions::SafeTransferFrom2 as ERC721SafeTransferFrom2Function;
+use abi::erc721::functions::TransferFrom as ERC721TransferFromFunction;
 
 substreams_ethereum::init!();
 
+#[substreams::handlers::store]
+fn store_nftcom_fees(blk: ethpb::eth::v2::Block, store: StoreSetBigInt) {
+    nftcom::store_fees(&blk, &store);
+}
+
+#[substreams::handlers::store]
+fn store_profile_associations(blk: ethpb::eth::v2::Block, store: StoreSetString) {
+    nftcom_resolver::store_associations(&blk, &store);
+}
+
+#[substreams::handlers::store]
+fn store_nft_owners(blk: ethpb::eth::v2::Block, store: StoreSetProto<ownership_pb::Ownership>) {
+    let (_timestamp, transfers) = transform_block_to_transfers(blk);
+
+    ownership::store_owners(&transfers, &store);
+}
+
+#[substreams::handlers::store]
+fn store_nft_users(blk: ethpb::eth::v2::Block, store: StoreSetProto<ownership_pb::NftUser>) {
+    let updates = nft_users::user_updates_from_block(&blk);
+    let (_timestamp, transfers) = transform_block_to_transfers(blk);
+
+    nft_users::store_users(updates, &transfers, &store);
+}
+
+#[substreams::handlers::store]
+fn store_nft_locks(blk: ethpb::eth::v2::Block, store: StoreSetInt64) {
+    nft_locks::store_locks(&blk, &store);
+}
+
+#[substreams::handlers::map]
+fn map_ownership_changes(
+    nft_owners: Deltas<DeltaProto<ownership_pb::Ownership>>,
+) -> Result<ownership_pb::OwnershipChanges, substreams::errors::Error> {
+    let ownership_changes = ownership::changes_from_deltas(nft_owners);
+
+    Ok(ownership_pb::OwnershipChanges { ownership_changes })
+}
+
+#[substreams::handlers::map]
+fn map_token_bound_accounts(
+    blk: ethpb::eth::v2::Block,
+) -> Result<ownership_pb::TokenBoundAccounts, substreams::errors::Error> {
+    let token_bound_accounts = token_bound_accounts::accounts_from_block(&blk);
+
+    Ok(ownership_pb::TokenBoundAccounts { token_bound_accounts })
+}
+
+#[substreams::handlers::store]
+fn store_token_bound_accounts(
+    accounts: ownership_pb::TokenBoundAccounts,
+    store: StoreSetProto<ownership_pb::TokenBoundAccount>,
+) {
+    token_bound_accounts::store_accounts(&accounts.token_bound_accounts, &store);
+}
+
+#[substreams::handlers::store]
+fn store_first_seen(blk: ethpb::eth::v2::Block, store: StoreMinInt64) {
+    let (_timestamp, transfers) = transform_block_to_transfers(blk);
+
+    contracts::store_first_seen(&transfers, &store);
+}
+
+#[substreams::handlers::map]
+fn map_new_contracts(
+    blk: ethpb::eth::v2::Block,
+    first_seen: StoreGetInt64,
+    contract_roles: StoreGetString,
+) -> Result<contracts_pb::Contracts, substreams::errors::Error> {
+    let (_timestamp, transfers) = transform_block_to_transfers(blk);
+    let contracts = contracts::new_contracts(&transfers, &first_seen, &contract_roles);
+
+    Ok(contracts_pb::Contracts { contracts })
+}
+
+#[substreams::handlers::store]
+fn store_contracts(new_contracts: contracts_pb::Contracts, store: StoreSetProto<contracts_pb::Contract>) {
+    contracts::store_contracts(&new_contracts.contracts, &store);
+}
+
+#[substreams::handlers::store]
+fn store_contract_roles(blk: ethpb::eth::v2::Block, store: StoreSetString) {
+    contract_roles::store_roles(&blk, &store);
+}
+
+#[substreams::handlers::store]
+fn store_contract_supply(blk: ethpb::eth::v2::Block, store: StoreAddBigInt) {
+    let (_timestamp, transfers) = transform_block_to_transfers(blk);
+
+    contracts::store_supply(&transfers, &store);
+}
+
+#[substreams::handlers::store]
+fn store_contract_spam_counts(blk: ethpb::eth::v2::Block, sales: sales::Sales, store: StoreAddInt64) {
+    let (_timestamp, transfers) = transform_block_to_transfers(blk);
+
+    spam::store_counts(&transfers, &sales.sales, &store);
+}
+
+#[substreams::handlers::store]
+fn store_contract_spam_signals(
+    blk: ethpb::eth::v2::Block,
+    new_contracts: contracts_pb::Contracts,
+    store: StoreMaxInt64,
+) {
+    let (_timestamp, transfers) = transform_block_to_transfers(blk);
+
+    spam::store_signals(&transfers, &new_contracts.contracts, &store);
+}
+
+#[substreams::handlers::map]
+fn map_new_nfts(
+    blk: ethpb::eth::v2::Block,
+    first_seen: StoreGetInt64,
+    contracts: StoreGetProto<contracts_pb::Contract>,
+) -> Result<contracts_pb::Nfts, substreams::errors::Error> {
+    let (_timestamp, transfers) = transform_block_to_transfers(blk);
+    let nfts = contracts::new_nfts(&transfers, &first_seen, &contracts);
+
+    Ok(contracts_pb::Nfts { nfts })
+}
+
+#[substreams::handlers::map]
+fn map_new_pools(blk: ethpb::eth::v2::Block) -> Result<pools_pb::Pools, substreams::errors::Error> {
+    let mut pools = sudoswap::pools_from_block(&blk);
+    pools.extend(nftx::pools_from_block(&blk));
+
+    Ok(pools_pb::Pools { pools })
+}
+
+#[substreams::handlers::store]
+fn store_pools(new_pools: pools_pb::Pools, store: StoreSetProto<pools_pb::Pool>) {
+    pools::store_pools(&new_pools.pools, &store);
+}
+
+#[substreams::handlers::store]
+fn store_pool_inventory(blk: ethpb::eth::v2::Block, pools: StoreGetProto<pools_pb::Pool>, store: StoreAddBigInt) {
+    let (_timestamp, transfers) = transform_block_to_transfers(blk);
+
+    pools::store_inventory(&transfers, &pools, &store);
+}
+
+#[substreams::handlers::map]
+fn map_sales(
+    blk: ethpb::eth::v2::Block,
+    nftcom_fees: StoreGetBigInt,
+    pools: StoreGetProto<pools_pb::Pool>,
+) -> Result<sales::Sales, substreams::errors::Error> {
+    let mut sales = nftcom::sales_from_block(&blk, &nftcom_fees);
+    let value_flows = inferred_sales::value_flows(&blk);
+    let marketplace_txs = inferred_sales::marketplace_txs(&blk);
+    let sudoswap_swaps = sudoswap::swaps_from_block(&blk, &pools);
+    let nftx_swaps = nftx::swaps_from_block(&blk, &pools);
+    let (_timestamp, transfers) = transform_block_to_transfers(blk);
+    sales.extend(sudoswap::sales_from_swaps(&sudoswap_swaps, &transfers, &value_flows));
+    sales.extend(nftx::sales_from_swaps(&nftx_swaps, &transfers));
+    let inferred_sales = inferred_sales::sales_from_transfers(&transfers, &value_flows, &sales, &marketplace_txs);
+    sales.extend(inferred_sales);
+
+    Ok(sales::Sales { sales })
+}
+
+#[substreams::handlers::store]
+fn store_collection_floors(sales: sales::Sales, store: StoreSetBigInt) {
+    bundles::store_floors(&sales.sales, &store);
+}
+
+#[substreams::handlers::store]
+fn store_eth_usd_price(blk: ethpb::eth::v2::Block, store: StoreSetBigInt) {
+    prices::store_eth_usd(&blk, &store);
+}
+
+#[substreams::handlers::map]
+fn map_allocated_sales(
+    sales: sales::Sales,
+    collection_floors: StoreGetBigInt,
+    eth_usd_price: StoreGetBigInt,
+) -> Result<sales::Sales, substreams::errors::Error> {
+    let mut sales = bundles::allocate(sales.sales, &collection_floors);
+    prices::stamp_sales(&mut sales, &eth_usd_price);
+
+    Ok(sales::Sales { sales })
+}
+
+#[substreams::handlers::store]
+fn store_wallet_funding(blk: ethpb::eth::v2::Block, store: StoreMinInt64) {
+    wash_trading::store_funding(&blk, &store);
+}
+
+#[substreams::handlers::store]
+fn store_nft_sold_at(blk: ethpb::eth::v2::Block, store: StoreSetInt64) {
+    let (_timestamp, transfers) = transform_block_to_transfers(blk);
+
+    wash_trading::store_sold_at(&transfers, &store);
+}
+
+#[substreams::handlers::store]
+fn store_nft_costs(blk: ethpb::eth::v2::Block, sales: sales::Sales, store: StoreSetBigInt) {
+    let (_timestamp, transfers) = transform_block_to_transfers(blk);
+
+    wash_trading::store_costs(&transfers, &sales.sales, &store);
+}
+
+#[substreams::handlers::map]
+fn map_scored_sales(
+    sales: sales::Sales,
+    wallet_funding: StoreGetInt64,
+    nft_sold_at: StoreGetInt64,
+    nft_costs: StoreGetBigInt,
+) -> Result<sales::Sales, substreams::errors::Error> {
+    let sales = wash_trading::score(sales.sales, &wallet_funding, &nft_sold_at, &nft_costs);
+
+    Ok(sales::Sales { sales })
+}
+
+#[substreams::handlers::store]
+fn store_collection_daily_sale_counts(blk: ethpb::eth::v2::Block, sales: sales::Sales, store: StoreAddInt64) {
+    let timestamp = BlockTimestamp::from_block(&blk);
+
+    market::store_sale_counts(&timestamp, &sales.sales, &store);
+}
+
+#[substreams::handlers::store]
+fn store_collection_daily_volumes(blk: ethpb::eth::v2::Block, sales: sales::Sales, store: StoreAddBigInt) {
+    let timestamp = BlockTimestamp::from_block(&blk);
+
+    market::store_volumes(&timestamp, &sales.sales, &store);
+}
+
+#[substreams::handlers::store]
+fn store_collection_daily_min_prices(blk: ethpb::eth::v2::Block, sales: sales::Sales, store: StoreMinBigInt) {
+    let timestamp = BlockTimestamp::from_block(&blk);
+
+    market::store_min_prices(&timestamp, &sales.sales, &store);
+}
+
+#[substreams::handlers::store]
+fn store_collection_daily_max_prices(blk: ethpb::eth::v2::Block, sales: sales::Sales, store: StoreMaxBigInt) {
+    let timestamp = BlockTimestamp::from_block(&blk);
+
+    market::store_max_prices(&timestamp, &sales.sales, &store);
+}
+
+#[substreams::handlers::store]
+fn store_collection_daily_prices(blk: ethpb::eth::v2::Block, sales: sales::Sales, store: StoreAppend<String>) {
+    let timestamp = BlockTimestamp::from_block(&blk);
+
+    market::store_prices(&timestamp, &sales.sales, &store);
+}
+
+#[substreams::handlers::store]
+fn store_collection_daily_volume_usd(blk: ethpb::eth::v2::Block, sales: sales::Sales, store: StoreAddBigDecimal) {
+    let timestamp = BlockTimestamp::from_block(&blk);
+
+    market::store_volume_usd(&timestamp, &sales.sales, &store);
+}
+
+#[substreams::handlers::map]
+fn map_mints(
+    blk: ethpb::eth::v2::Block,
+    eth_usd_price: StoreGetBigInt,
+) -> Result<sales::Mints, substreams::errors::Error> {
+    let erc20_payments = mints::erc20_payments(&blk);
+    let (_timestamp, transfers) = transform_block_to_transfers(blk);
+    let mut mints = mints::mints_from_transfers(&transfers, &erc20_payments);
+    prices::stamp_mints(&mut mints, &eth_usd_price);
+
+    Ok(sales::Mints { mints })
+}
+
 #[substreams::handlers::map]
+fn map_order_invalidations(
+    blk: ethpb::eth::v2::Block,
+) -> Result<orders::OrderInvalidations, substreams::errors::Error> {
+    let mut order_invalidations = seaport::invalidations_from_block(&blk);
+    order_invalidations.extend(looksrare::invalidations_from_block(&blk));
+    order_invalidations.extend(nftcom::invalidations_from_block(&blk));
+
+    Ok(orders::OrderInvalidations { order_invalidations })
+}
+
+#[substreams::handlers::map]
+#[allow(clippy::too_many_arguments)]
 fn db_out(
-    blk: ethpb::eth::v2::Block
+    blk: ethpb::eth::v2::Block,
+    sales: sales::Sales,
+    mints: sales::Mints,
+    order_invalidations: orders::OrderInvalidations,
+    profile_associations: Deltas<DeltaString>,
+    nft_owners: Deltas<DeltaProto<ownership_pb::Ownership>>,
+    nft_users: Deltas<DeltaProto<ownership_pb::NftUser>>,
+    nft_locks: Deltas<DeltaInt64>,
+    new_token_bound_accounts: ownership_pb::TokenBoundAccounts,
+    token_bound_accounts: StoreGetProto<ownership_pb::TokenBoundAccount>,
+    new_contracts: contracts_pb::Contracts,
+    new_nfts: contracts_pb::Nfts,
+    contract_supply: Deltas<DeltaBigInt>,
+    contract_roles: Deltas<DeltaString>,
+    first_seen: StoreGetInt64,
+    collection_daily_sale_counts: Deltas<DeltaInt64>,
+    collection_daily_volumes: StoreGetBigInt,
+    collection_daily_min_prices: StoreGetBigInt,
+    collection_daily_max_prices: StoreGetBigInt,
+    collection_daily_prices: StoreGetArray<String>,
+    collection_daily_volume_usd: StoreGetBigDecimal,
+    contract_spam_counts: Deltas<DeltaInt64>,
+    contract_spam_signals: Deltas<DeltaInt64>,
+    new_pools: pools_pb::Pools,
+    pool_inventory: Deltas<DeltaBigInt>,
 ) -> Result<DatabaseChanges, substreams::errors::Error> {
+    let block_number = blk.number;
+    let refresh_requests = metadata::refresh_requests_from_block(&blk);
     let (_timestamp, transfers) = transform_block_to_transfers(blk);
 
     let mut database_changes: DatabaseChanges = Default::default();
 
+    for contract in new_contracts.contracts {
+        contracts::push_contract_create(&mut database_changes, contract);
+    }
+
+    for nft in new_nfts.nfts {
+        contracts::push_nft_create(&mut database_changes, nft);
+    }
+
+    contracts::push_supply_changes(&mut database_changes, contract_supply);
+    contract_roles::push_role_changes(&mut database_changes, block_number, contract_roles, &first_seen);
+    spam::push_spam_signal_changes(&mut database_changes, contract_spam_counts, contract_spam_signals);
+
+    for pool in new_pools.pools {
+        pools::push_pool_create(&mut database_changes, pool);
+    }
+    pools::push_inventory_changes(&mut database_changes, pool_inventory);
+
     // for loop over transfers
     for transfer in transfers {
-        transform_transfers_to_database_changes(&mut database_changes, transfer);
+        transform_transfers_to_database_changes(&mut database_changes, transfer, &token_bound_accounts);
+    }
+
+    for account in new_token_bound_accounts.token_bound_accounts {
+        token_bound_accounts::push_account_create(&mut database_changes, account);
+    }
+
+    for sale in sales.sales {
+        push_sale_create(&mut database_changes, sale);
     }
 
+    market::push_daily_market_changes(
+        &mut database_changes,
+        collection_daily_sale_counts,
+        &collection_daily_volumes,
+        &collection_daily_min_prices,
+        &collection_daily_max_prices,
+        &collection_daily_prices,
+        &collection_daily_volume_usd,
+    );
+
+    for mint in mints.mints {
+        mints::push_mint_create(&mut database_changes, mint);
+    }
+
+    for order_invalidation in order_invalidations.order_invalidations {
+        push_order_invalidation_create(&mut database_changes, order_invalidation);
+    }
+
+    for refresh_request in refresh_requests {
+        metadata::push_refresh_request_create(&mut database_changes, refresh_request);
+    }
+
+    nftcom_resolver::push_association_changes(&mut database_changes, profile_associations);
+    ownership::push_interval_changes(&mut database_changes, &nft_owners);
+    nft_users::push_user_changes(&mut database_changes, nft_users);
+    nft_locks::push_lock_changes(&mut database_changes, block_number, nft_locks);
+
     Ok(database_changes)
 }
 
@@ -46,7 +449,8 @@ fn transform_block_to_transfers(blk: ethpb::eth::v2::Block) -> (BlockTimestamp,
     let timestamp = BlockTimestamp::from_block(&blk);
 
     let transfers: Vec<transfers::Transfer> = blk.receipts().flat_map(|receipt| {
-        let hash = &receipt.transaction.hash;
+        let trx = receipt.transaction;
+        let hash = &trx.hash;
         let timestamp = Some(header.timestamp.as_ref().unwrap().clone());
 
         receipt.receipt.logs.iter().flat_map(move |log| {
@@ -94,12 +498,73 @@ fn transform_block_to_transfers(blk: ethpb::eth::v2::Block) -> (BlockTimestamp,
                 .chain(erc721_transfers.into_iter())
                 .chain(erc1155_single_transfers.into_iter())
                 .chain(erc1155_batch_transfers)
+                .map(move |transfer| with_transaction_context(transfer, trx))
+                .map(move |transfer| with_call_context(transfer, trx))
         })
     }).collect();
 
     (timestamp, transfers)
 }
 
+fn with_transaction_context(
+    transfer: transfers::Transfer,
+    trx: &ethpb::eth::v2::TransactionTrace,
+) -> transfers::Transfer {
+    let to_string = |value: &Option<ethpb::eth::v2::BigInt>| {
+        BigInt::from_unsigned_bytes_be(value.as_ref().map(|value| value.bytes.as_slice()).unwrap_or_default()).to_string()
+    };
+
+    transfers::Transfer {
+        tx_from: trx.from.clone(),
+        tx_to: trx.to.clone(),
+        tx_value: to_string(&trx.value),
+        tx_gas_used: trx.gas_used,
+        tx_gas_price: to_string(&trx.gas_price),
+        tx_selector: trx.input.get(..4).map(|selector| selector.to_vec()).unwrap_or_default(),
+        ..transfer
+    }
+}
+
+// Transfers are keyed by the block index of their log, which is found back among the logs of the
+// call that emitted it.
+fn with_call_context(
+    transfer: transfers::Transfer,
+    trx: &ethpb::eth::v2::TransactionTrace,
+) -> transfers::Transfer {
+    let call = trx
+        .calls
+        .iter()
+        .find(|call| call.logs.iter().any(|log| log.block_index as u64 == transfer.ordinal));
+
+    match call {
+        Some(call) => transfers::Transfer {
+            call_index: call.index,
+            call_depth: call.depth,
+            call_caller: call.caller.clone(),
+            call_selector: call.input.get(..4).map(|selector| selector.to_vec()).unwrap_or_default(),
+            call_method: transfer_method(call).to_string(),
+            ..transfer
+        },
+        None => transfer,
+    }
+}
+
+fn transfer_method(call: &ethpb::eth::v2::Call) -> &'static str {
+    if ERC721TransferFromFunction::match_call(call) {
+        ERC721TransferFromFunction::NAME
+    } else if ERC721SafeTransferFrom1Function::match_call(call) {
+        ERC721SafeTransferFrom1Function::NAME
+    } else if ERC721SafeTransferFrom2Function::match_call(call) {
+        ERC721SafeTransferFrom2Function::NAME
+    } else if ERC1155SafeTransferFromFunction::match_call(call) {
+        ERC1155SafeTransferFromFunction::NAME
+    } else if ERC1155SafeBatchTransferFromFunction::match_call(call) {
+        ERC1155SafeBatchTransferFromFunction::NAME
+    } else {
+        ""
+    }
+}
+
 #[allow(dead_code)]
 fn new_erc20_transfer(
     hash: &[u8],
@@ -122,6 +587,7 @@ fn new_erc20_transfer(
 
         operator: Vec::new(),
         token_id: Vec::new(),
+        ..Default::default()
     }
 }
 
@@ -146,6 +612,7 @@ fn new_erc721_transfer(
         timestamp: timestamp,
 
         operator: Vec::new(),
+        ..Default::default()
     }
 }
 
@@ -253,6 +720,7 @@ fn new_erc1155_transfer(
         contract_address: contract_address,
         block_number: block_number,
         timestamp: timestamp,
+        ..Default::default()
     }
 }
 
@@ -265,9 +733,37 @@ fn schema_to_string(schema: Schema) -> String {
     .to_string()
 }
 
+fn allocation_to_string(allocation: Allocation) -> String {
+    match allocation {
+        Allocation::Single => "single",
+        Allocation::Even => "even",
+        Allocation::Floor => "floor",
+    }
+    .to_string()
+}
+
+fn wash_reason_to_string(reason: WashReason) -> String {
+    match reason {
+        WashReason::FundedByCounterparty => "funded_by_counterparty",
+        WashReason::RoundTrip => "round_trip",
+        WashReason::ZeroCost => "zero_cost",
+    }
+    .to_string()
+}
+
+fn invalidation_kind_to_string(kind: InvalidationKind) -> String {
+    match kind {
+        InvalidationKind::CancelledOrder => "cancelled_order",
+        InvalidationKind::CancelledNonce => "cancelled_nonce",
+        InvalidationKind::MinNonce => "min_nonce",
+    }
+    .to_string()
+}
+
 fn transform_transfers_to_database_changes(
     changes: &mut DatabaseChanges,
     transfer: transfers::Transfer,
+    token_bound_accounts: &StoreGetProto<ownership_pb::TokenBoundAccount>,
 ) {
     let unique_key = |transfer: &transfers::Transfer| {
         format!(
@@ -294,11 +790,15 @@ fn transform_transfers_to_database_changes(
 
     log::info!("Found a transfer {}", unique_key(&transfer));
 
+    // A transfer to a token-bound account is held by the NFT owning the account.
+    let held_by = token_bound_accounts.get_last(token_bound_accounts::account_key(&transfer.to_address));
+
     push_create(
         changes,
         &unique_key(&transfer),
         transfer.ordinal,
         transfer,
+        held_by,
     )
 }
 
@@ -307,7 +807,10 @@ fn push_create(
     key: &str,
     ordinal: u64,
     value: transfers::Transfer,
+    held_by: Option<ownership_pb::TokenBoundAccount>,
 ) {
+    let held_by = held_by.unwrap_or_default();
+
     changes
         .push_change("transfers", key, ordinal, Operation::Create)
         .change("schema", (None, value.schema))
@@ -320,5 +823,88 @@ fn push_create(
         .change("contract_address", (None, Hex(value.contract_address)))
         .change("tx_hash", (None, Hex(value.tx_hash)))
         .change("ordinal", (None, value.ordinal))
+        .change("timestamp", (None, value.timestamp.unwrap()))
+        .change("held_by_contract", (None, Hex(held_by.token_contract)))
+        .change("held_by_token_id", (None, Hex(held_by.token_id)))
+        .change("tx_from", (None, Hex(value.tx_from)))
+        .change("tx_to", (None, Hex(value.tx_to)))
+        .change("tx_value", (None, value.tx_value))
+        .change("tx_gas_used", (None, value.tx_gas_used))
+        .change("tx_gas_price", (None, value.tx_gas_price))
+        .change("tx_selector", (None, Hex(value.tx_selector)))
+        .change("call_index", (None, value.call_index))
+        .change("call_depth", (None, value.call_depth))
+        .change("call_caller", (None, Hex(value.call_caller)))
+        .change("call_selector", (None, Hex(value.call_selector)))
+        .change("call_method", (None, value.call_method));
+}
+
+fn push_sale_create(
+    changes: &mut DatabaseChanges,
+    value: sales::Sale,
+) {
+    let key = format!(
+        "{}-{}-{}-{}-{}",
+        value.marketplace,
+        Hex(&value.tx_hash),
+        value.ordinal,
+        Hex(&value.contract_address),
+        Hex(&value.token_id),
+    );
+
+    changes
+        .push_change("sales", &key, value.ordinal, Operation::Create)
+        .change("marketplace", (None, value.marketplace))
+        .change("order_hash", (None, Hex(value.order_hash)))
+        .change("maker", (None, Hex(value.maker)))
+        .change("taker", (None, Hex(value.taker)))
+        .change("seller", (None, Hex(value.seller)))
+        .change("buyer", (None, Hex(value.buyer)))
+        .change("schema", (None, value.schema))
+        .change("contract_address", (None, Hex(value.contract_address)))
+        .change("token_id", (None, Hex(value.token_id)))
+        .change("quantity", (None, value.quantity))
+        .change("payment_token", (None, Hex(value.payment_token)))
+        .change("price", (None, value.price))
+        .change("protocol_fee", (None, value.protocol_fee))
+        .change("royalty_fee", (None, value.royalty_fee))
+        .change("tx_hash", (None, Hex(value.tx_hash)))
+        .change("block_number", (None, value.block_number))
+        .change("ordinal", (None, value.ordinal))
+        .change("timestamp", (None, value.timestamp.unwrap()))
+        .change("confidence", (None, value.confidence))
+        .change("bundle_id", (None, value.bundle_id))
+        .change("bundle_size", (None, value.bundle_size))
+        .change("bundle_price", (None, value.bundle_price))
+        .change("allocation", (None, value.allocation))
+        .change("eth_usd_price", (None, value.eth_usd_price))
+        .change("price_usd", (None, value.price_usd))
+        .change("wash_score", (None, value.wash_score))
+        .change("wash_reasons", (None, value.wash_reasons.join(",")));
+}
+
+fn push_order_invalidation_create(
+    changes: &mut DatabaseChanges,
+    value: orders::OrderInvalidation,
+) {
+    let key = format!(
+        "{}-{}-{}-{}-{}",
+        value.marketplace,
+        Hex(&value.tx_hash),
+        value.ordinal,
+        Hex(&value.order_hash),
+        value.nonce,
+    );
+
+    changes
+        .push_change("order_invalidations", &key, value.ordinal, Operation::Create)
+        .change("marketplace", (None, value.marketplace))
+        .change("kind", (None, value.kind))
+        .change("maker", (None, Hex(value.maker)))
+        .change("order_hash", (None, Hex(value.order_hash)))
+        .change("nonce", (None, value.nonce))
+        .change("tx_hash", (None, Hex(value.tx_hash)))
+        .change("block_number", (None, value.block_number))
+        .change("ordinal", (None, value.ordinal))
         .change("timestamp", (None, value.timestamp.unwrap()));
 }
\ No newline at end of file
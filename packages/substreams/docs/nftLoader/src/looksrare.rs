@@ -0,0 +1,58 @@
+use hex_literal::hex;
+use substreams_ethereum::pb::eth::v2 as eth;
+use substreams_ethereum::Event;
+
+use crate::abi::looksrare_exchange::events::CancelAllOrders as CancelAllOrdersEvent;
+use crate::abi::looksrare_exchange::events::CancelMultipleOrders as CancelMultipleOrdersEvent;
+use crate::invalidation_kind_to_string;
+use crate::pb::orders;
+use crate::pb::orders::order_invalidation::Kind;
+
+pub const MARKETPLACE_NAME: &str = "looksrare";
+
+pub const EXCHANGE_ADDRESS: [u8; 20] = hex!("59728544b08ab483533076417fbbb2fd0b17ce3a");
+
+/// Decodes the order cancellations of the block. LooksRare orders are cancelled through their
+/// nonce, either one by one or by raising the minimum nonce accepted for the maker.
+pub fn invalidations_from_block(blk: &eth::Block) -> Vec<orders::OrderInvalidation> {
+    let header = blk.header.as_ref().unwrap();
+
+    blk.receipts()
+        .flat_map(|receipt| {
+            let hash = &receipt.transaction.hash;
+            let timestamp = Some(header.timestamp.as_ref().unwrap().clone());
+
+            receipt
+                .receipt
+                .logs
+                .iter()
+                .filter(|log| log.address == EXCHANGE_ADDRESS)
+                .flat_map(move |log| {
+                    let nonces = if let Some(event) = CancelAllOrdersEvent::match_and_decode(log) {
+                        vec![(Kind::MinNonce, event.user, event.new_min_nonce)]
+                    } else if let Some(event) = CancelMultipleOrdersEvent::match_and_decode(log) {
+                        event
+                            .order_nonces
+                            .into_iter()
+                            .map(|nonce| (Kind::CancelledNonce, event.user.clone(), nonce))
+                            .collect()
+                    } else {
+                        vec![]
+                    };
+
+                    let timestamp = timestamp.clone();
+                    nonces.into_iter().map(move |(kind, maker, nonce)| orders::OrderInvalidation {
+                        marketplace: MARKETPLACE_NAME.to_string(),
+                        kind: invalidation_kind_to_string(kind),
+                        maker,
+                        order_hash: Vec::new(),
+                        nonce: nonce.to_string(),
+                        tx_hash: hash.to_vec(),
+                        ordinal: log.block_index as u64,
+                        block_number: blk.number,
+                        timestamp: timestamp.clone(),
+                    })
+                })
+        })
+        .collect()
+}
@@ -0,0 +1,193 @@
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use substreams::pb::substreams::store_delta::Operation as DeltaOperation;
+use substreams::scalar::{BigDecimal, BigInt};
+use substreams::store::{
+    Appender, DeltaInt64, Deltas, StoreAdd, StoreAddBigDecimal, StoreAddBigInt, StoreAddInt64, StoreAppend, StoreGet,
+    StoreGetArray, StoreGetBigDecimal, StoreGetBigInt, StoreMax, StoreMaxBigInt, StoreMin, StoreMinBigInt,
+};
+use substreams::Hex;
+use substreams_database_change::pb::database::{table_change::Operation, DatabaseChanges};
+
+use crate::block_timestamp::BlockTimestamp;
+use crate::pb::sales;
+use crate::prices;
+use crate::wash_trading::WASH_SCORE_THRESHOLD;
+
+// Inferred sales below this confidence are left out of the market stats.
+const MIN_CONFIDENCE: u32 = 75;
+
+// Keys are `day:first:<yyyymmdd>:<contract>`.
+pub fn market_key(timestamp: &BlockTimestamp, contract_address: &[u8]) -> String {
+    format!("{}:{}", timestamp.start_of_day_key(), Hex(contract_address))
+}
+
+/// The price of a sale in wei, for sales paid in ETH or WETH with enough confidence and that are
+/// not likely wash trades.
+pub fn eth_price(sale: &sales::Sale) -> Option<BigInt> {
+    if sale.confidence < MIN_CONFIDENCE || sale.wash_score >= WASH_SCORE_THRESHOLD {
+        return None;
+    }
+    if !prices::is_eth(&sale.payment_token) {
+        return None;
+    }
+
+    BigInt::try_from(sale.price.clone()).ok()
+}
+
+/// Counts the sales of each collection per day.
+pub fn store_sale_counts(timestamp: &BlockTimestamp, sales: &[sales::Sale], store: &StoreAddInt64) {
+    for sale in sales.iter().filter(|sale| eth_price(sale).is_some()) {
+        store.add(sale.ordinal, market_key(timestamp, &sale.contract_address), 1);
+    }
+}
+
+fn priced_sales<'a>(
+    timestamp: &'a BlockTimestamp,
+    sales: &'a [sales::Sale],
+) -> impl Iterator<Item = (&'a sales::Sale, String, BigInt)> + 'a {
+    sales.iter().filter_map(move |sale| {
+        eth_price(sale).map(|price| (sale, market_key(timestamp, &sale.contract_address), price))
+    })
+}
+
+/// Adds up the price of the sales of each collection per day.
+pub fn store_volumes(timestamp: &BlockTimestamp, sales: &[sales::Sale], store: &StoreAddBigInt) {
+    for (sale, key, price) in priced_sales(timestamp, sales) {
+        store.add(sale.ordinal, key, price);
+    }
+}
+
+/// Keeps the lowest price of the sales of each collection per day.
+pub fn store_min_prices(timestamp: &BlockTimestamp, sales: &[sales::Sale], store: &StoreMinBigInt) {
+    for (sale, key, price) in priced_sales(timestamp, sales) {
+        store.min(sale.ordinal, key, price);
+    }
+}
+
+/// Keeps the highest price of the sales of each collection per day.
+pub fn store_max_prices(timestamp: &BlockTimestamp, sales: &[sales::Sale], store: &StoreMaxBigInt) {
+    for (sale, key, price) in priced_sales(timestamp, sales) {
+        store.max(sale.ordinal, key, price);
+    }
+}
+
+/// Appends the price of the sales of each collection per day, from which the median is computed.
+pub fn store_prices(timestamp: &BlockTimestamp, sales: &[sales::Sale], store: &StoreAppend<String>) {
+    for (sale, key, price) in priced_sales(timestamp, sales) {
+        store.append(sale.ordinal, key, price.to_string());
+    }
+}
+
+/// The median of the prices, being the mean of the two middle ones for an even number of prices.
+pub fn median_price(prices: &[String]) -> Option<BigInt> {
+    let mut prices: Vec<BigInt> = prices
+        .iter()
+        .filter_map(|price| BigInt::try_from(price.clone()).ok())
+        .collect();
+    if prices.is_empty() {
+        return None;
+    }
+    prices.sort();
+
+    let count = prices.len();
+    Some((prices[(count - 1) / 2].clone() + prices[count / 2].clone()) / BigInt::from(2))
+}
+
+/// Adds up the USD price of the sales of each collection per day, for the sales that have one.
+pub fn store_volume_usd(timestamp: &BlockTimestamp, sales: &[sales::Sale], store: &StoreAddBigDecimal) {
+    for sale in sales.iter().filter(|sale| eth_price(sale).is_some()) {
+        if let Ok(price_usd) = BigDecimal::from_str(&sale.price_usd) {
+            store.add(sale.ordinal, market_key(timestamp, &sale.contract_address), price_usd);
+        }
+    }
+}
+
+/// Updates the daily market row of every collection that had sales in the block from the running
+/// count, volume, minimum, maximum and median of its prices of the day. Each sale gives a count delta, only
+/// the last one of each key is used.
+pub fn push_daily_market_changes(
+    changes: &mut DatabaseChanges,
+    sale_counts: Deltas<DeltaInt64>,
+    volumes: &StoreGetBigInt,
+    min_prices: &StoreGetBigInt,
+    max_prices: &StoreGetBigInt,
+    prices: &StoreGetArray<String>,
+    volume_usd: &StoreGetBigDecimal,
+) {
+    // key => (created in this block, ordinal of the last delta, last count)
+    let mut markets: BTreeMap<String, (bool, u64, i64)> = BTreeMap::new();
+    for delta in sale_counts.deltas {
+        let market = markets.entry(delta.key).or_insert((false, 0, 0));
+        market.0 |= delta.operation == DeltaOperation::Create;
+        market.1 = delta.ordinal;
+        market.2 = delta.new_value;
+    }
+
+    for (market_key, (created, ordinal, count)) in markets {
+        let (volume, min_price, max_price) = match (
+            volumes.get_last(&market_key),
+            min_prices.get_last(&market_key),
+            max_prices.get_last(&market_key),
+        ) {
+            (Some(volume), Some(min_price), Some(max_price)) if count > 0 => (volume, min_price, max_price),
+            _ => continue,
+        };
+        let average = volume.clone() / BigInt::from(count);
+        let median = prices
+            .get_last(&market_key)
+            .and_then(|prices| median_price(&prices))
+            .map(|median| median.to_string())
+            .unwrap_or_default();
+
+        let volume_usd = volume_usd.get_last(&market_key).map(|volume| volume.to_string()).unwrap_or_default();
+
+        let mut parts = market_key.rsplitn(2, ':');
+        let contract = parts.next().unwrap_or_default().to_string();
+        let day = BlockTimestamp::from_key(parts.next().unwrap_or_default())
+            .start_of_day()
+            .format("%Y-%m-%d")
+            .to_string();
+        let key = format!("{}-{}", contract, day);
+
+        let operation = if created { Operation::Create } else { Operation::Update };
+        let row = changes.push_change("collection_daily_market", &key, ordinal, operation);
+        if created {
+            row.change("contract", (None, contract)).change("day", (None, day));
+        }
+        row.change("sale_count", (None, count))
+            .change("volume", (None, volume.to_string()))
+            .change("volume_usd", (None, volume_usd))
+            .change("min_price", (None, min_price.to_string()))
+            .change("max_price", (None, max_price.to_string()))
+            .change("median_price", (None, median))
+            .change("average_price", (None, average.to_string()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn prices(prices: &[&str]) -> Vec<String> {
+        prices.iter().map(|price| price.to_string()).collect()
+    }
+
+    #[test]
+    fn it_median_price_of_odd_count() {
+        assert_eq!(median_price(&prices(&["300", "100", "200"])), Some(BigInt::from(200)));
+        assert_eq!(median_price(&prices(&["700"])), Some(BigInt::from(700)));
+    }
+
+    #[test]
+    fn it_median_price_of_even_count() {
+        assert_eq!(median_price(&prices(&["400", "100", "1000", "200"])), Some(BigInt::from(300)));
+        assert_eq!(median_price(&prices(&["5", "2"])), Some(BigInt::from(3)));
+    }
+
+    #[test]
+    fn it_median_price_of_no_sales() {
+        assert_eq!(median_price(&[]), None);
+    }
+}
@@ -0,0 +1,132 @@
+use prost_types::Timestamp;
+use substreams::Hex;
+use substreams_database_change::pb::database::{table_change::Operation, DatabaseChanges};
+use substreams_ethereum::pb::eth::v2 as eth;
+use substreams_ethereum::Event;
+
+use crate::abi::erc1155::events::Uri as ERC1155UriEvent;
+use crate::abi::erc4906::events::BatchMetadataUpdate as BatchMetadataUpdateEvent;
+use crate::abi::erc4906::events::MetadataUpdate as MetadataUpdateEvent;
+
+/// A request to refresh the metadata of the tokens `from_token_id` to `to_token_id` (inclusive)
+/// of a contract, emitted by ERC-4906 contracts on reveal or update and by ERC1155 on `URI`.
+pub struct RefreshRequest {
+    pub kind: &'static str,
+    pub contract_address: Vec<u8>,
+    pub from_token_id: Vec<u8>,
+    pub to_token_id: Vec<u8>,
+    // The new URI of the token, only set for the ERC1155 `URI` event.
+    pub uri: String,
+    pub tx_hash: Vec<u8>,
+    pub ordinal: u64,
+    pub block_number: u64,
+    pub timestamp: Timestamp,
+}
+
+pub fn refresh_requests_from_block(blk: &eth::Block) -> Vec<RefreshRequest> {
+    let header = blk.header.as_ref().unwrap();
+
+    blk.receipts()
+        .flat_map(|receipt| {
+            let hash = &receipt.transaction.hash;
+            let timestamp = header.timestamp.as_ref().unwrap().clone();
+
+            receipt.receipt.logs.iter().filter_map(move |log| {
+                let (kind, from_token_id, to_token_id, uri) =
+                    if let Some(event) = MetadataUpdateEvent::match_and_decode(log) {
+                        let token_id = event.token_id.to_bytes_be().1;
+                        ("metadata_update", token_id.clone(), token_id, String::new())
+                    } else if let Some(event) = BatchMetadataUpdateEvent::match_and_decode(log) {
+                        (
+                            "batch_metadata_update",
+                            event.from_token_id.to_bytes_be().1,
+                            event.to_token_id.to_bytes_be().1,
+                            String::new(),
+                        )
+                    } else if let Some(event) = ERC1155UriEvent::match_and_decode(log) {
+                        let token_id = event.id.to_bytes_be().1;
+                        ("uri", token_id.clone(), token_id, event.value)
+                    } else {
+                        return None;
+                    };
+
+                Some(RefreshRequest {
+                    kind,
+                    contract_address: log.address.to_vec(),
+                    from_token_id,
+                    to_token_id,
+                    uri,
+                    tx_hash: hash.to_vec(),
+                    ordinal: log.block_index as u64,
+                    block_number: blk.number,
+                    timestamp: timestamp.clone(),
+                })
+            })
+        })
+        .collect()
+}
+
+pub fn push_refresh_request_create(changes: &mut DatabaseChanges, value: RefreshRequest) {
+    let key = format!("{}-{}", Hex(&value.tx_hash), value.ordinal);
+
+    changes
+        .push_change("metadata_refresh_requests", &key, value.ordinal, Operation::Create)
+        .change("kind", (None, value.kind.to_string()))
+        .change("contract", (None, Hex(value.contract_address)))
+        .change("from_token_id", (None, Hex(value.from_token_id)))
+        .change("to_token_id", (None, Hex(value.to_token_id)))
+        .change("uri", (None, value.uri))
+        .change("tx_hash", (None, Hex(value.tx_hash)))
+        .change("block_number", (None, value.block_number))
+        .change("ordinal", (None, value.ordinal))
+        .change("timestamp", (None, value.timestamp));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::refresh_requests_from_block;
+    use crate::test_utils::{block, log};
+    use ethabi::{ParamType, Token, Uint};
+
+    const CONTRACT: [u8; 20] = [0x11; 20];
+
+    #[test]
+    fn it_refresh_requests_from_block() {
+        let blk = block(
+            100,
+            vec![
+                log(
+                    &CONTRACT,
+                    "BatchMetadataUpdate",
+                    &[ParamType::Uint(256), ParamType::Uint(256)],
+                    &[],
+                    &[Token::Uint(Uint::from(1)), Token::Uint(Uint::from(300))],
+                    3,
+                ),
+                log(
+                    &CONTRACT,
+                    "MetadataUpdate",
+                    &[ParamType::Uint(256)],
+                    &[],
+                    &[Token::Uint(Uint::from(7))],
+                    4,
+                ),
+            ],
+        );
+
+        let requests = refresh_requests_from_block(&blk);
+        assert_eq!(requests.len(), 2);
+
+        // A batch covers the range of its event, both ends included.
+        assert_eq!(requests[0].kind, "batch_metadata_update");
+        assert_eq!(requests[0].contract_address, CONTRACT.to_vec());
+        assert_eq!(requests[0].from_token_id, vec![1]);
+        assert_eq!(requests[0].to_token_id, vec![0x01, 0x2c]);
+        assert_eq!(requests[0].ordinal, 3);
+
+        assert_eq!(requests[1].kind, "metadata_update");
+        assert_eq!(requests[1].from_token_id, vec![7]);
+        assert_eq!(requests[1].to_token_id, vec![7]);
+        assert_eq!(requests[1].block_number, 100);
+    }
+}
@@ -0,0 +1,193 @@
+use std::collections::HashMap;
+
+use substreams::scalar::BigInt;
+use substreams::Hex;
+use substreams_database_change::pb::database::{table_change::Operation, DatabaseChanges};
+use substreams_ethereum::pb::eth::v2 as eth;
+use substreams_ethereum::Event;
+
+use crate::abi::erc20::events::Transfer as ERC20TransferEvent;
+use crate::pb::sales;
+use crate::pb::transfers;
+
+const ZERO_ADDRESS: [u8; 20] = [0u8; 20];
+
+/// The ERC20 paid by the signer of each transaction, keyed by transaction hash. When the signer
+/// paid with several tokens, only the first one is kept, like sales do.
+pub fn erc20_payments(blk: &eth::Block) -> HashMap<Vec<u8>, (Vec<u8>, BigInt)> {
+    let mut payments: HashMap<Vec<u8>, (Vec<u8>, BigInt)> = HashMap::new();
+
+    for receipt in blk.receipts() {
+        for log in receipt.receipt.logs.iter() {
+            let event = match ERC20TransferEvent::match_and_decode(log) {
+                Some(event) if event.from == receipt.transaction.from => event,
+                _ => continue,
+            };
+
+            let (token, amount) = payments
+                .entry(receipt.transaction.hash.clone())
+                .or_insert_with(|| (log.address.clone(), BigInt::zero()));
+            if *token == log.address {
+                *amount = amount.clone() + event.value;
+            }
+        }
+    }
+
+    payments
+}
+
+/// Builds the mints of the block from its transfers. The ETH value of the transaction, or else
+/// its ERC20 payment, is spread over all the units minted by the transaction.
+pub fn mints_from_transfers(
+    transfers: &[transfers::Transfer],
+    erc20_payments: &HashMap<Vec<u8>, (Vec<u8>, BigInt)>,
+) -> Vec<sales::Mint> {
+    let mints: Vec<(&transfers::Transfer, BigInt)> = transfers
+        .iter()
+        .filter(|transfer| transfer.from_address == ZERO_ADDRESS)
+        .filter_map(|transfer| {
+            BigInt::try_from(transfer.quantity.clone())
+                .ok()
+                .map(|quantity| (transfer, quantity))
+        })
+        .collect();
+
+    let mut tx_quantities: HashMap<&[u8], BigInt> = HashMap::new();
+    for (transfer, quantity) in &mints {
+        let tx_quantity = tx_quantities.entry(&transfer.tx_hash).or_insert_with(BigInt::zero);
+        *tx_quantity = tx_quantity.clone() + quantity.clone();
+    }
+
+    mints
+        .iter()
+        .map(|(transfer, quantity)| {
+            let tx_value = BigInt::try_from(transfer.tx_value.clone()).unwrap_or_else(|_| BigInt::zero());
+            let (payment_token, tx_price) = if tx_value != BigInt::zero() {
+                (Vec::new(), tx_value)
+            } else {
+                erc20_payments
+                    .get(&transfer.tx_hash)
+                    .cloned()
+                    .unwrap_or_else(|| (Vec::new(), BigInt::zero()))
+            };
+            let tx_quantity = tx_quantities[transfer.tx_hash.as_slice()].clone();
+            let price_per_token = if tx_quantity == BigInt::zero() {
+                BigInt::zero()
+            } else {
+                tx_price.clone() / tx_quantity.clone()
+            };
+
+            sales::Mint {
+                schema: transfer.schema.clone(),
+                contract_address: transfer.contract_address.clone(),
+                token_id: transfer.token_id.clone(),
+                quantity: quantity.to_string(),
+                minter: transfer.to_address.clone(),
+                payer: transfer.tx_from.clone(),
+                payment_token,
+                free: tx_price == BigInt::zero(),
+                tx_price: tx_price.to_string(),
+                tx_quantity: tx_quantity.to_string(),
+                price_per_token: price_per_token.to_string(),
+                tx_gas_used: transfer.tx_gas_used,
+                tx_gas_price: transfer.tx_gas_price.clone(),
+                tx_hash: transfer.tx_hash.clone(),
+                ordinal: transfer.ordinal,
+                block_number: transfer.block_number,
+                timestamp: transfer.timestamp.clone(),
+                ..Default::default()
+            }
+        })
+        .collect()
+}
+
+pub fn push_mint_create(changes: &mut DatabaseChanges, value: sales::Mint) {
+    let key = format!(
+        "{}-{}-{}-{}",
+        Hex(&value.tx_hash),
+        value.ordinal,
+        Hex(&value.contract_address),
+        Hex(&value.token_id),
+    );
+
+    changes
+        .push_change("mints", &key, value.ordinal, Operation::Create)
+        .change("schema", (None, value.schema))
+        .change("contract_address", (None, Hex(value.contract_address)))
+        .change("token_id", (None, Hex(value.token_id)))
+        .change("quantity", (None, value.quantity))
+        .change("minter", (None, Hex(value.minter)))
+        .change("payer", (None, Hex(value.payer)))
+        .change("payment_token", (None, Hex(value.payment_token)))
+        .change("tx_price", (None, value.tx_price))
+        .change("tx_quantity", (None, value.tx_quantity))
+        .change("price_per_token", (None, value.price_per_token))
+        .change("eth_usd_price", (None, value.eth_usd_price))
+        .change("price_per_token_usd", (None, value.price_per_token_usd))
+        .change("free", (None, value.free.to_string()))
+        .change("tx_gas_used", (None, value.tx_gas_used))
+        .change("tx_gas_price", (None, value.tx_gas_price))
+        .change("tx_hash", (None, Hex(value.tx_hash)))
+        .change("block_number", (None, value.block_number))
+        .change("ordinal", (None, value.ordinal))
+        .change("timestamp", (None, value.timestamp.unwrap()));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mint(tx_hash: u8, quantity: &str, tx_value: &str) -> transfers::Transfer {
+        transfers::Transfer {
+            schema: "erc1155".to_string(),
+            contract_address: vec![0x11; 20],
+            token_id: vec![1],
+            from_address: ZERO_ADDRESS.to_vec(),
+            to_address: vec![0x02; 20],
+            quantity: quantity.to_string(),
+            tx_hash: vec![tx_hash; 32],
+            tx_from: vec![0x02; 20],
+            tx_value: tx_value.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn it_spreads_tx_value_over_minted_units() {
+        let mut sold = mint(0xaa, "1", "300");
+        sold.from_address = vec![0x03; 20];
+        let transfers = vec![mint(0xaa, "1", "300"), mint(0xaa, "2", "300"), sold];
+
+        let mints = mints_from_transfers(&transfers, &HashMap::new());
+        assert_eq!(mints.len(), 2);
+        for (mint, quantity) in mints.iter().zip(["1", "2"]) {
+            assert_eq!(mint.quantity, quantity);
+            assert_eq!(mint.tx_price, "300");
+            assert_eq!(mint.tx_quantity, "3");
+            assert_eq!(mint.price_per_token, "100");
+            assert_eq!(mint.payment_token, Vec::<u8>::new());
+            assert!(!mint.free);
+        }
+    }
+
+    #[test]
+    fn it_spreads_erc20_payment_without_tx_value() {
+        let usdc = vec![0x44; 20];
+        let payments = HashMap::from([(vec![0xaa; 32], (usdc.clone(), BigInt::from(500)))]);
+
+        let mints = mints_from_transfers(&[mint(0xaa, "5", "0")], &payments);
+        assert_eq!(mints[0].payment_token, usdc);
+        assert_eq!(mints[0].price_per_token, "100");
+        assert!(!mints[0].free);
+    }
+
+    #[test]
+    fn it_detects_free_mints() {
+        let payments = HashMap::from([(vec![0xbb; 32], (vec![0x44; 20], BigInt::from(500)))]);
+
+        let mints = mints_from_transfers(&[mint(0xaa, "2", "0")], &payments);
+        assert!(mints[0].free);
+        assert_eq!(mints[0].tx_price, "0");
+        assert_eq!(mints[0].price_per_token, "0");
+    }
+}
@@ -0,0 +1,124 @@
+use substreams::pb::substreams::store_delta::Operation as DeltaOperation;
+use substreams::store::{DeltaInt64, Deltas, StoreSet, StoreSetInt64};
+use substreams::Hex;
+use substreams_database_change::pb::database::{table_change::Operation, DatabaseChanges};
+use substreams_ethereum::pb::eth::v2 as eth;
+use substreams_ethereum::Event;
+
+use crate::abi::erc5192::events::Locked as LockedEvent;
+use crate::abi::erc5192::events::Unlocked as UnlockedEvent;
+
+pub fn lock_key(contract_address: &[u8], token_id: &[u8]) -> String {
+    format!("{}:{}", Hex(contract_address), Hex(token_id))
+}
+
+/// Tracks the ERC-5192 lock state of soulbound tokens, `1` when locked and `0` when unlocked.
+pub fn store_locks(blk: &eth::Block, store: &StoreSetInt64) {
+    for (ordinal, key, locked) in lock_events(blk) {
+        store.set(ordinal, key, &locked);
+    }
+}
+
+// The `Locked` and `Unlocked` events of the block, as (ordinal, lock key, lock state).
+fn lock_events(blk: &eth::Block) -> Vec<(u64, String, i64)> {
+    blk.receipts()
+        .flat_map(|receipt| receipt.receipt.logs.iter())
+        .filter_map(|log| {
+            let (token_id, locked) = if let Some(event) = LockedEvent::match_and_decode(log) {
+                (event.token_id, 1)
+            } else if let Some(event) = UnlockedEvent::match_and_decode(log) {
+                (event.token_id, 0)
+            } else {
+                return None;
+            };
+
+            Some((log.ordinal, lock_key(&log.address, &token_id.to_bytes_be().1), locked))
+        })
+        .collect()
+}
+
+/// Mirrors the lock store into the `nft_locks` table, one row per token that ever emitted a
+/// lock event.
+pub fn push_lock_changes(changes: &mut DatabaseChanges, block_number: u64, deltas: Deltas<DeltaInt64>) {
+    for delta in deltas.deltas {
+        let locked = (delta.new_value == 1).to_string();
+
+        match delta.operation {
+            DeltaOperation::Create => {
+                let mut parts = delta.key.split(':');
+                let contract = parts.next().unwrap_or_default().to_string();
+                let token_id = parts.next().unwrap_or_default().to_string();
+
+                changes
+                    .push_change("nft_locks", &delta.key, delta.ordinal, Operation::Create)
+                    .change("contract", (None, contract))
+                    .change("token_id", (None, token_id))
+                    .change("locked", (None, locked))
+                    .change("block_number", (None, block_number));
+            }
+            DeltaOperation::Update => {
+                changes
+                    .push_change("nft_locks", &delta.key, delta.ordinal, Operation::Update)
+                    .change("locked", (Some((delta.old_value == 1).to_string()), locked))
+                    .change("block_number", (None, block_number));
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{block, log};
+    use ethabi::{ParamType, Token, Uint};
+
+    const CONTRACT: [u8; 20] = [0x11; 20];
+
+    fn lock_log(name: &str, index: u32) -> eth::Log {
+        log(&CONTRACT, name, &[ParamType::Uint(256)], &[], &[Token::Uint(Uint::from(42))], index)
+    }
+
+    #[test]
+    fn it_lock_then_unlock() {
+        let blk = block(100, vec![lock_log("Locked", 1), lock_log("Unlocked", 2)]);
+        let key = lock_key(&CONTRACT, &[42]);
+        assert_eq!(lock_events(&blk), vec![(1, key.clone(), 1), (2, key.clone(), 0)]);
+
+        let delta = |operation, ordinal, old_value, new_value| DeltaInt64 {
+            operation,
+            ordinal,
+            key: key.clone(),
+            old_value,
+            new_value,
+        };
+        let mut changes = DatabaseChanges::default();
+        push_lock_changes(
+            &mut changes,
+            100,
+            Deltas {
+                deltas: vec![
+                    delta(DeltaOperation::Create, 1, 0, 1),
+                    delta(DeltaOperation::Update, 2, 1, 0),
+                ],
+            },
+        );
+
+        let locked: Vec<(i32, String, String)> = changes
+            .table_changes
+            .iter()
+            .map(|change| {
+                let field = change.fields.iter().find(|field| field.name == "locked").unwrap();
+
+                (change.operation, field.old_value.clone(), field.new_value.clone())
+            })
+            .collect();
+        assert_eq!(
+            locked,
+            vec![
+                (Operation::Create as i32, String::new(), "true".to_string()),
+                (Operation::Update as i32, "true".to_string(), "false".to_string()),
+            ]
+        );
+    }
+}
@@ -0,0 +1,202 @@
+use substreams::pb::substreams::store_delta::Operation as DeltaOperation;
+use substreams::store::{DeltaProto, Deltas, StoreDelete, StoreSet, StoreSetProto};
+use substreams::Hex;
+use substreams_database_change::pb::database::{table_change::Operation, DatabaseChanges};
+use substreams_ethereum::pb::eth::v2 as eth;
+use substreams_ethereum::Event;
+
+use crate::abi::erc4907::events::UpdateUser as UpdateUserEvent;
+use crate::pb::ownership;
+use crate::pb::transfers;
+
+// The token id is padded to 32 bytes so that a key is never the prefix of another one and
+// `delete_prefix` removes exactly one token.
+pub fn user_key(contract_address: &[u8], token_id: &[u8]) -> String {
+    format!("{}:{:0>64}", Hex(contract_address), Hex(token_id).to_string())
+}
+
+/// A change of user of a token, `None` clearing the user.
+pub struct UserUpdate {
+    ordinal: u64,
+    key: String,
+    user: Option<ownership::NftUser>,
+}
+
+/// Decodes the ERC-4907 `UpdateUser` events of the block. Setting the zero address as user
+/// clears it.
+pub fn user_updates_from_block(blk: &eth::Block) -> Vec<UserUpdate> {
+    blk.receipts()
+        .flat_map(|receipt| receipt.receipt.logs.iter())
+        .filter_map(|log| {
+            UpdateUserEvent::match_and_decode(log).map(|event| {
+                let token_id = event.token_id.to_bytes_be().1;
+                let key = user_key(&log.address, &token_id);
+
+                let user = if event.user == [0u8; 20] {
+                    None
+                } else {
+                    Some(ownership::NftUser {
+                        contract_address: log.address.to_vec(),
+                        token_id,
+                        user: event.user,
+                        expires: event.expires.to_u64(),
+                        block_number: blk.number,
+                    })
+                };
+
+                UserUpdate {
+                    ordinal: log.block_index as u64,
+                    key,
+                    user,
+                }
+            })
+        })
+        .collect()
+}
+
+/// Tracks the ERC-4907 user of the rentable tokens. Per the standard, the user is cleared when
+/// the token is transferred, whether or not the contract emits `UpdateUser` for it.
+pub fn store_users(
+    updates: Vec<UserUpdate>,
+    transfers: &[transfers::Transfer],
+    store: &StoreSetProto<ownership::NftUser>,
+) {
+    for update in with_transfers(updates, transfers) {
+        match update.user {
+            Some(user) => store.set(update.ordinal, update.key, &user),
+            None => store.delete_prefix(update.ordinal as i64, &update.key),
+        }
+    }
+}
+
+// Adds the clearing of the user of every ERC721 transferred, in ordinal order.
+fn with_transfers(mut updates: Vec<UserUpdate>, transfers: &[transfers::Transfer]) -> Vec<UserUpdate> {
+    updates.extend(
+        transfers
+            .iter()
+            .filter(|transfer| transfer.schema == "erc721")
+            .map(|transfer| UserUpdate {
+                ordinal: transfer.ordinal,
+                key: user_key(&transfer.contract_address, &transfer.token_id),
+                user: None,
+            }),
+    );
+
+    updates.sort_by_key(|update| update.ordinal);
+
+    updates
+}
+
+/// Mirrors the user store into the `nft_users` table, one row per rented token.
+pub fn push_user_changes(changes: &mut DatabaseChanges, deltas: Deltas<DeltaProto<ownership::NftUser>>) {
+    for delta in deltas.deltas {
+        let value = delta.new_value;
+
+        match delta.operation {
+            DeltaOperation::Create => {
+                changes
+                    .push_change("nft_users", &delta.key, delta.ordinal, Operation::Create)
+                    .change("contract", (None, Hex(value.contract_address)))
+                    .change("token_id", (None, Hex(value.token_id)))
+                    .change("user_address", (None, Hex(value.user)))
+                    .change("expires", (None, value.expires))
+                    .change("block_number", (None, value.block_number));
+            }
+            DeltaOperation::Update => {
+                let old_value = delta.old_value;
+
+                changes
+                    .push_change("nft_users", &delta.key, delta.ordinal, Operation::Update)
+                    .change("user_address", (Some(Hex(old_value.user)), Hex(value.user)))
+                    .change("expires", (Some(old_value.expires), value.expires))
+                    .change("block_number", (Some(old_value.block_number), value.block_number));
+            }
+            DeltaOperation::Delete => {
+                changes.push_change("nft_users", &delta.key, delta.ordinal, Operation::Delete);
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{block, log};
+    use ethabi::{Address, ParamType, Token, Uint};
+
+    const CONTRACT: [u8; 20] = [0x11; 20];
+
+    fn update_user(user: u8, index: u32) -> eth::Log {
+        log(
+            &CONTRACT,
+            "UpdateUser",
+            &[ParamType::Uint(256), ParamType::Address, ParamType::Uint(64)],
+            &[Token::Uint(Uint::from(42)), Token::Address(Address::repeat_byte(user))],
+            &[Token::Uint(Uint::from(1_700_000_000u64))],
+            index,
+        )
+    }
+
+    fn transfer(index: u64) -> transfers::Transfer {
+        transfers::Transfer {
+            schema: "erc721".to_string(),
+            contract_address: CONTRACT.to_vec(),
+            token_id: vec![42],
+            ordinal: index,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn it_clears_user_on_transfer() {
+        // The user is set, the token transferred, then the new owner rents it to another user.
+        let blk = block(100, vec![update_user(0x01, 1), update_user(0x02, 5)]);
+        let updates = with_transfers(user_updates_from_block(&blk), &[transfer(3)]);
+
+        let key = user_key(&CONTRACT, &[42]);
+        let users: Vec<(u64, &str, Option<Vec<u8>>)> = updates
+            .iter()
+            .map(|update| (update.ordinal, update.key.as_str(), update.user.as_ref().map(|user| user.user.clone())))
+            .collect();
+        assert_eq!(
+            users,
+            vec![
+                (1, key.as_str(), Some(vec![0x01; 20])),
+                (3, key.as_str(), None),
+                (5, key.as_str(), Some(vec![0x02; 20])),
+            ]
+        );
+        assert_eq!(updates[0].user.as_ref().unwrap().expires, 1_700_000_000);
+    }
+
+    #[test]
+    fn it_clears_user_on_zero_address() {
+        let blk = block(100, vec![update_user(0x00, 1)]);
+        let updates = user_updates_from_block(&blk);
+
+        assert_eq!(updates.len(), 1);
+        assert!(updates[0].user.is_none());
+    }
+
+    #[test]
+    fn it_push_user_changes_deletes_cleared_user() {
+        let mut changes = DatabaseChanges::default();
+        push_user_changes(
+            &mut changes,
+            Deltas {
+                deltas: vec![DeltaProto {
+                    operation: DeltaOperation::Delete,
+                    ordinal: 3,
+                    key: user_key(&CONTRACT, &[42]),
+                    old_value: ownership::NftUser::default(),
+                    new_value: ownership::NftUser::default(),
+                }],
+            },
+        );
+
+        assert_eq!(changes.table_changes.len(), 1);
+        assert_eq!(changes.table_changes[0].table, "nft_users");
+        assert_eq!(changes.table_changes[0].operation, Operation::Delete as i32);
+    }
+}
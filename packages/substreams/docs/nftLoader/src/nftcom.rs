@@ -0,0 +1,544 @@
+use std::collections::HashMap;
+
+use hex_literal::hex;
+use prost_types::Timestamp;
+use substreams::scalar::BigInt;
+use substreams::store::{StoreGet, StoreGetBigInt, StoreSet, StoreSetBigInt};
+use substreams::{log, Hex};
+use substreams_ethereum::pb::eth::v2 as eth;
+use substreams_ethereum::Event;
+
+use crate::abi::nftcom_marketplace::events::Cancel as CancelEvent;
+use crate::abi::nftcom_marketplace::events::NonceIncremented as NonceIncrementedEvent;
+use crate::abi::nftcom_marketplace::events::ProtocolFeeChange as ProtocolFeeChangeEvent;
+use crate::abi::nftcom_marketplace::events::RoyaltyInfoChange as RoyaltyInfoChangeEvent;
+use crate::abi::nftcom_marketplace_event::events::BuyNowInfo as BuyNowInfoEvent;
+use crate::abi::nftcom_marketplace_event::events::Match as MatchEvent;
+use crate::abi::nftcom_marketplace_event::events::Match2A as Match2AEvent;
+use crate::abi::nftcom_marketplace_event::events::Match2B as Match2BEvent;
+use crate::abi::nftcom_marketplace_event::events::Match3A as Match3AEvent;
+use crate::abi::nftcom_marketplace_event::events::Match3B as Match3BEvent;
+use crate::invalidation_kind_to_string;
+use crate::pb::orders;
+use crate::pb::orders::order_invalidation::Kind;
+use crate::pb::sales;
+
+pub const MARKETPLACE_NAME: &str = "nftcom";
+
+// NftMarketplace settles the orders and emits `Cancel`, `NonceIncremented` and fee changes.
+pub const MARKETPLACE_ADDRESS: [u8; 20] = hex!("1fd9bc58c7ffdb18d19a8a8d791b6ae9fa2bd097");
+
+// MarketplaceEvent only emits the `Match*` and `BuyNowInfo` events describing a settlement.
+pub const MARKETPLACE_EVENT_ADDRESS: [u8; 20] = hex!("2e42652b9b539c849ece944329c0ba0bc4f70968");
+
+// Asset classes are the first 4 bytes of the keccak256 of their name, e.g. `keccak256("ETH")`.
+const ETH_ASSET_CLASS: [u8; 4] = hex!("aaaebeba");
+const ERC20_ASSET_CLASS: [u8; 4] = hex!("8ae85d84");
+const ERC721_ASSET_CLASS: [u8; 4] = hex!("73ad2146");
+const ERC1155_ASSET_CLASS: [u8; 4] = hex!("973bb640");
+
+const FEE_DENOMINATOR: u64 = 10_000;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Asset {
+    Eth {
+        value: ethabi::Uint,
+    },
+    Erc20 {
+        token: Vec<u8>,
+        value: ethabi::Uint,
+    },
+    Erc721 {
+        contract: Vec<u8>,
+        token_id: ethabi::Uint,
+    },
+    Erc1155 {
+        contract: Vec<u8>,
+        token_id: ethabi::Uint,
+        value: ethabi::Uint,
+    },
+}
+
+/// One side of a settlement, rebuilt from the `Match2A`/`Match2B` (maker order) or
+/// `Match3A`/`Match3B` (taker order) events sharing the same struct hash.
+#[derive(Debug, Default)]
+struct Order {
+    maker: Vec<u8>,
+    taker: Vec<u8>,
+    make_assets: Vec<Asset>,
+    take_assets: Vec<Asset>,
+}
+
+pub fn protocol_fee_key() -> String {
+    "protocol_fee:public".to_string()
+}
+
+pub fn royalty_fee_key(contract: &[u8]) -> String {
+    format!("royalty_fee:{}", Hex(contract))
+}
+
+/// Records the fee percentages configured on the NftMarketplace contract, in basis points.
+pub fn store_fees(blk: &eth::Block, store: &StoreSetBigInt) {
+    for log in blk.receipts().flat_map(|receipt| receipt.receipt.logs.iter()) {
+        if log.address != MARKETPLACE_ADDRESS {
+            continue;
+        }
+
+        if let Some(event) = ProtocolFeeChangeEvent::match_and_decode(log) {
+            store.set(log.ordinal, protocol_fee_key(), &event.public_fee);
+        }
+
+        if let Some(event) = RoyaltyInfoChangeEvent::match_and_decode(log) {
+            store.set(log.ordinal, royalty_fee_key(&event.token), &event.percent);
+        }
+    }
+}
+
+/// Reassembles the NFT.com settlements of the block into sales. The marketplace splits a
+/// settlement across several events of the same transaction, joined by the maker and taker
+/// struct hashes announced in `Match`.
+pub fn sales_from_block(blk: &eth::Block, fees: &StoreGetBigInt) -> Vec<sales::Sale> {
+    let header = blk.header.as_ref().unwrap();
+
+    blk.receipts()
+        .flat_map(|receipt| {
+            let timestamp = Some(header.timestamp.as_ref().unwrap().clone());
+
+            sales_from_transaction(
+                &receipt.transaction.hash,
+                &receipt.receipt.logs,
+                blk.number,
+                timestamp,
+                fees,
+            )
+        })
+        .collect()
+}
+
+fn sales_from_transaction(
+    hash: &[u8],
+    logs: &[eth::Log],
+    block_number: u64,
+    timestamp: Option<Timestamp>,
+    fees: &StoreGetBigInt,
+) -> Vec<sales::Sale> {
+    let mut makers: HashMap<[u8; 32], Order> = HashMap::new();
+    let mut takers: HashMap<[u8; 32], Order> = HashMap::new();
+    let mut buy_now_takers: HashMap<[u8; 32], Vec<u8>> = HashMap::new();
+    let mut matches: Vec<(u32, MatchEvent)> = Vec::new();
+
+    for log in logs.iter().filter(|log| log.address == MARKETPLACE_EVENT_ADDRESS) {
+        if let Some(event) = Match2AEvent::match_and_decode(log) {
+            let order = makers.entry(event.maker_struct_hash).or_default();
+            order.maker = event.maker_address;
+            order.taker = event.taker_address;
+        } else if let Some(event) = Match2BEvent::match_and_decode(log) {
+            let order = makers.entry(event.maker_struct_hash).or_default();
+            order.make_assets = decode_assets(
+                &event.seller_maker_order_asset_data,
+                &event.seller_maker_order_asset_type_data,
+                &event.seller_maker_order_asset_class,
+            );
+            order.take_assets = decode_assets(
+                &event.seller_taker_order_asset_data,
+                &event.seller_taker_order_asset_type_data,
+                &event.seller_taker_order_asset_class,
+            );
+        } else if let Some(event) = Match3AEvent::match_and_decode(log) {
+            let order = takers.entry(event.taker_struct_hash).or_default();
+            order.maker = event.maker_address;
+            order.taker = event.taker_address;
+        } else if let Some(event) = Match3BEvent::match_and_decode(log) {
+            let order = takers.entry(event.taker_struct_hash).or_default();
+            order.make_assets = decode_assets(
+                &event.buyer_maker_order_asset_data,
+                &event.buyer_maker_order_asset_type_data,
+                &event.buyer_maker_order_asset_class,
+            );
+            order.take_assets = decode_assets(
+                &event.buyer_taker_order_asset_data,
+                &event.buyer_taker_order_asset_type_data,
+                &event.buyer_taker_order_asset_class,
+            );
+        } else if let Some(event) = BuyNowInfoEvent::match_and_decode(log) {
+            buy_now_takers.insert(event.maker_struct_hash, event.taker_address);
+        } else if let Some(event) = MatchEvent::match_and_decode(log) {
+            matches.push((log.block_index, event));
+        }
+    }
+
+    let mut sales = Vec::new();
+    for (ordinal, event) in matches {
+        let maker_order = match makers.get(&event.maker_struct_hash) {
+            Some(order) => order,
+            None => {
+                log::info!(
+                    "No Match2A/Match2B found for maker order {} in transaction {}, ignoring the match",
+                    Hex(&event.maker_struct_hash),
+                    Hex(hash),
+                );
+                continue;
+            }
+        };
+
+        // A zero taker struct hash means the listing was bought directly, in which case the buyer is
+        // announced through `BuyNowInfo` or is the taker designated by the listing itself.
+        let taker_order = takers.get(&event.taker_struct_hash);
+        let taker = match taker_order {
+            Some(order) => order.maker.clone(),
+            None => buy_now_takers
+                .get(&event.maker_struct_hash)
+                .cloned()
+                .unwrap_or_else(|| maker_order.taker.clone()),
+        };
+
+        let side = settlement_side(maker_order, taker_order, &taker);
+        let (payment_token, price) = payment(side.payment_assets);
+
+        for asset in side.nft_assets {
+            let (schema, contract, token_id, quantity) = match asset {
+                Asset::Erc721 { contract, token_id } => ("erc721", contract, token_id, ethabi::Uint::one()),
+                Asset::Erc1155 {
+                    contract,
+                    token_id,
+                    value,
+                } => ("erc1155", contract, token_id, *value),
+                _ => continue,
+            };
+
+            sales.push(sales::Sale {
+                marketplace: MARKETPLACE_NAME.to_string(),
+                order_hash: event.maker_struct_hash.to_vec(),
+                maker: maker_order.maker.clone(),
+                taker: taker.clone(),
+                seller: side.seller.to_vec(),
+                buyer: side.buyer.to_vec(),
+                schema: schema.to_string(),
+                contract_address: contract.clone(),
+                token_id: uint_to_bytes(token_id),
+                quantity: quantity.to_string(),
+                payment_token: payment_token.clone(),
+                price: price.to_string(),
+                protocol_fee: fee(&price, fees.get_last(protocol_fee_key())).to_string(),
+                royalty_fee: fee(&price, fees.get_last(royalty_fee_key(contract))).to_string(),
+                tx_hash: hash.to_vec(),
+                ordinal: ordinal as u64,
+                block_number,
+                timestamp: timestamp.clone(),
+                confidence: 100,
+                // The assets of an order are sold for the order price, split by `bundles::allocate`.
+                bundle_id: format!("{}-{}", Hex(hash), Hex(&event.maker_struct_hash)),
+                ..Default::default()
+            });
+        }
+    }
+
+    sales
+}
+
+// The NFTs and the payment of a settlement, and who sold and bought the NFTs. The maker order is a
+// listing when it gives NFTs, and a bid when it gives the payment, in which case the taker accepting
+// it gives the NFTs.
+#[derive(Debug, PartialEq)]
+struct Side<'a> {
+    seller: &'a [u8],
+    buyer: &'a [u8],
+    nft_assets: &'a [Asset],
+    payment_assets: &'a [Asset],
+}
+
+fn settlement_side<'a>(maker_order: &'a Order, taker_order: Option<&'a Order>, taker: &'a [u8]) -> Side<'a> {
+    // Without a taker order, the taker gives what the maker order asks for.
+    let taker_assets = match taker_order {
+        Some(order) => &order.make_assets,
+        None => &maker_order.take_assets,
+    };
+
+    if maker_order.make_assets.iter().any(is_nft) || !taker_assets.iter().any(is_nft) {
+        Side {
+            seller: &maker_order.maker,
+            buyer: taker,
+            nft_assets: &maker_order.make_assets,
+            payment_assets: taker_assets,
+        }
+    } else {
+        Side {
+            seller: taker,
+            buyer: &maker_order.maker,
+            nft_assets: taker_assets,
+            payment_assets: &maker_order.make_assets,
+        }
+    }
+}
+
+fn is_nft(asset: &Asset) -> bool {
+    matches!(asset, Asset::Erc721 { .. } | Asset::Erc1155 { .. })
+}
+
+/// Decodes the order cancellations of the block. Incrementing the nonce of a maker invalidates
+/// every order it signed with the previous nonce.
+pub fn invalidations_from_block(blk: &eth::Block) -> Vec<orders::OrderInvalidation> {
+    let header = blk.header.as_ref().unwrap();
+
+    blk.receipts()
+        .flat_map(|receipt| {
+            let hash = &receipt.transaction.hash;
+            let timestamp = Some(header.timestamp.as_ref().unwrap().clone());
+
+            receipt
+                .receipt
+                .logs
+                .iter()
+                .filter(|log| log.address == MARKETPLACE_ADDRESS)
+                .filter_map(move |log| {
+                    let (kind, maker, order_hash, nonce) = if let Some(event) = CancelEvent::match_and_decode(log) {
+                        (Kind::CancelledOrder, event.maker, event.struct_hash.to_vec(), String::new())
+                    } else if let Some(event) = NonceIncrementedEvent::match_and_decode(log) {
+                        (Kind::MinNonce, event.maker, Vec::new(), event.new_nonce.to_string())
+                    } else {
+                        return None;
+                    };
+
+                    Some(orders::OrderInvalidation {
+                        marketplace: MARKETPLACE_NAME.to_string(),
+                        kind: invalidation_kind_to_string(kind),
+                        maker,
+                        order_hash,
+                        nonce,
+                        tx_hash: hash.to_vec(),
+                        ordinal: log.block_index as u64,
+                        block_number: blk.number,
+                        timestamp: timestamp.clone(),
+                    })
+                })
+        })
+        .collect()
+}
+
+pub fn decode_assets(data: &[Vec<u8>], type_data: &[Vec<u8>], class: &[[u8; 4]]) -> Vec<Asset> {
+    data.iter()
+        .zip(type_data.iter())
+        .zip(class.iter())
+        .filter_map(|((data, type_data), class)| match decode_asset(data, type_data, class) {
+            Ok(asset) => Some(asset),
+            Err(err) => {
+                log::info!("Unable to decode NFT.com asset of class {}: {}", Hex(class), err);
+                None
+            }
+        })
+        .collect()
+}
+
+fn decode_asset(data: &[u8], type_data: &[u8], class: &[u8; 4]) -> Result<Asset, String> {
+    // Asset data is `(uint256 value, uint256 minimumBid)`, only the value matters once settled.
+    let value = ethabi::decode(&[ethabi::ParamType::Uint(256), ethabi::ParamType::Uint(256)], data)
+        .map_err(|e| format!("unable to decode asset data: {:?}", e))?
+        .remove(0)
+        .into_uint()
+        .unwrap();
+
+    match *class {
+        ETH_ASSET_CLASS => Ok(Asset::Eth { value }),
+        ERC20_ASSET_CLASS => {
+            let mut values = ethabi::decode(&[ethabi::ParamType::Address], type_data)
+                .map_err(|e| format!("unable to decode ERC20 asset type: {:?}", e))?;
+
+            Ok(Asset::Erc20 {
+                token: values.remove(0).into_address().unwrap().as_bytes().to_vec(),
+                value,
+            })
+        }
+        ERC721_ASSET_CLASS | ERC1155_ASSET_CLASS => {
+            // NFT asset types are `(address contract, uint256 tokenId, bool allowAll)`.
+            let mut values = ethabi::decode(
+                &[
+                    ethabi::ParamType::Address,
+                    ethabi::ParamType::Uint(256),
+                    ethabi::ParamType::Bool,
+                ],
+                type_data,
+            )
+            .map_err(|e| format!("unable to decode NFT asset type: {:?}", e))?;
+
+            let contract = values.remove(0).into_address().unwrap().as_bytes().to_vec();
+            let token_id = values.remove(0).into_uint().unwrap();
+
+            if *class == ERC721_ASSET_CLASS {
+                Ok(Asset::Erc721 { contract, token_id })
+            } else {
+                Ok(Asset::Erc1155 {
+                    contract,
+                    token_id,
+                    value,
+                })
+            }
+        }
+        _ => Err("unknown asset class".to_string()),
+    }
+}
+
+/// Returns the payment token (empty for ETH) and the total amount paid in it. Orders are expected
+/// to be paid in a single currency, assets in any other currency are ignored.
+fn payment(assets: &[Asset]) -> (Vec<u8>, ethabi::Uint) {
+    let mut payment_token: Option<Vec<u8>> = None;
+    let mut price = ethabi::Uint::zero();
+
+    for asset in assets {
+        let (token, value) = match asset {
+            Asset::Eth { value } => (Vec::new(), value),
+            Asset::Erc20 { token, value } => (token.clone(), value),
+            _ => continue,
+        };
+
+        if payment_token.get_or_insert_with(|| token.clone()) == &token {
+            price = price.saturating_add(*value);
+        }
+    }
+
+    (payment_token.unwrap_or_default(), price)
+}
+
+fn fee(price: &ethabi::Uint, basis_points: Option<BigInt>) -> ethabi::Uint {
+    let basis_points = match basis_points {
+        Some(value) => ethabi::Uint::from_big_endian(&value.to_bytes_be().1),
+        None => return ethabi::Uint::zero(),
+    };
+
+    price
+        .checked_mul(basis_points)
+        .map(|value| value / FEE_DENOMINATOR)
+        .unwrap_or_default()
+}
+
+pub fn uint_to_bytes(value: &ethabi::Uint) -> Vec<u8> {
+    let mut bytes = [0u8; 32];
+    value.to_big_endian(&mut bytes);
+
+    BigInt::from_unsigned_bytes_be(&bytes).to_bytes_be().1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_assets, payment, settlement_side, Asset, Order, Side, ERC721_ASSET_CLASS, ETH_ASSET_CLASS};
+    use ethabi::{Address, Token, Uint};
+
+    fn asset_data(value: u64) -> Vec<u8> {
+        ethabi::encode(&[Token::Uint(Uint::from(value)), Token::Uint(Uint::zero())])
+    }
+
+    #[test]
+    fn it_decode_assets() {
+        let contract = Address::repeat_byte(0x11);
+        let nft_type = ethabi::encode(&[
+            Token::Address(contract),
+            Token::Uint(Uint::from(42)),
+            Token::Bool(false),
+        ]);
+
+        assert_eq!(
+            decode_assets(
+                &[asset_data(1), asset_data(5)],
+                &[nft_type, Vec::new()],
+                &[ERC721_ASSET_CLASS, ETH_ASSET_CLASS],
+            ),
+            vec![
+                Asset::Erc721 {
+                    contract: contract.as_bytes().to_vec(),
+                    token_id: Uint::from(42),
+                },
+                Asset::Eth {
+                    value: Uint::from(5)
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn it_decode_assets_skips_unknown_class() {
+        assert_eq!(decode_assets(&[asset_data(1)], &[Vec::new()], &[[0u8; 4]]), vec![]);
+    }
+
+    #[test]
+    fn it_payment_sums_single_currency() {
+        let weth = vec![0x22u8; 20];
+
+        assert_eq!(
+            payment(&[
+                Asset::Erc20 {
+                    token: weth.clone(),
+                    value: Uint::from(7)
+                },
+                Asset::Eth {
+                    value: Uint::from(100)
+                },
+                Asset::Erc20 {
+                    token: weth.clone(),
+                    value: Uint::from(3)
+                },
+            ]),
+            (weth, Uint::from(10))
+        );
+    }
+
+    #[test]
+    fn it_settlement_side_listing() {
+        let nft = Asset::Erc721 {
+            contract: vec![0x11u8; 20],
+            token_id: Uint::from(42),
+        };
+        let eth = Asset::Eth {
+            value: Uint::from(100),
+        };
+        let listing = Order {
+            maker: vec![0x01u8; 20],
+            make_assets: vec![nft.clone()],
+            take_assets: vec![eth.clone()],
+            ..Default::default()
+        };
+        let buyer = vec![0x02u8; 20];
+
+        assert_eq!(
+            settlement_side(&listing, None, &buyer),
+            Side {
+                seller: &listing.maker,
+                buyer: &buyer,
+                nft_assets: &[nft],
+                payment_assets: &[eth],
+            }
+        );
+    }
+
+    #[test]
+    fn it_settlement_side_accepted_bid() {
+        let nft = Asset::Erc721 {
+            contract: vec![0x11u8; 20],
+            token_id: Uint::from(42),
+        };
+        let weth = Asset::Erc20 {
+            token: vec![0x22u8; 20],
+            value: Uint::from(100),
+        };
+        let bid = Order {
+            maker: vec![0x01u8; 20],
+            make_assets: vec![weth.clone()],
+            take_assets: vec![nft.clone()],
+            ..Default::default()
+        };
+        let acceptance = Order {
+            maker: vec![0x02u8; 20],
+            make_assets: vec![nft.clone()],
+            take_assets: vec![weth.clone()],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            settlement_side(&bid, Some(&acceptance), &acceptance.maker),
+            Side {
+                seller: &acceptance.maker,
+                buyer: &bid.maker,
+                nft_assets: &[nft],
+                payment_assets: &[weth],
+            }
+        );
+    }
+}
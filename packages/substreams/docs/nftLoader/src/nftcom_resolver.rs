@@ -0,0 +1,126 @@
+use hex_literal::hex;
+use substreams::pb::substreams::store_delta::Operation as DeltaOperation;
+use substreams::store::{DeltaString, Deltas, StoreDelete, StoreSet, StoreSetString};
+use substreams::Hex;
+use substreams_database_change::pb::database::{table_change::Operation, DatabaseChanges};
+use substreams_ethereum::pb::eth::v2 as eth;
+use substreams_ethereum::Event;
+
+use crate::abi::nftcom_resolver::events::AssociateEvmUser as AssociateEvmUserEvent;
+use crate::abi::nftcom_resolver::events::AssociateSelfWithUser as AssociateSelfWithUserEvent;
+use crate::abi::nftcom_resolver::events::CancelledEvmAssociation as CancelledEvmAssociationEvent;
+use crate::abi::nftcom_resolver::events::ClearAllAssociatedAddresses as ClearAllAssociatedAddressesEvent;
+use crate::abi::nftcom_resolver::events::ClearAssociatedContract as ClearAssociatedContractEvent;
+use crate::abi::nftcom_resolver::events::RemovedAssociateProfile as RemovedAssociateProfileEvent;
+use crate::abi::nftcom_resolver::events::SetAssociatedContract as SetAssociatedContractEvent;
+
+pub const RESOLVER_ADDRESS: [u8; 20] = hex!("a657c988e8ac39d3268d390eb7c522a535b10453");
+
+// Keys are `<profile_url>:<kind>[:<address>]`. Profile urls are restricted to `[a-z0-9_]` and
+// addresses have a fixed length, so a key is never the prefix of another one and `delete_prefix`
+// removes exactly the association (or, for a bulk clear, exactly the profile's associations).
+const ADDRESS_KIND: &str = "address";
+const APPROVAL_KIND: &str = "approval";
+const CONTRACT_KIND: &str = "contract";
+
+pub fn address_key(profile_url: &str, address: &[u8]) -> String {
+    format!("{}:{}:{}", profile_url, ADDRESS_KIND, Hex(address))
+}
+
+pub fn approval_key(profile_url: &str, address: &[u8]) -> String {
+    format!("{}:{}:{}", profile_url, APPROVAL_KIND, Hex(address))
+}
+
+pub fn contract_key(profile_url: &str) -> String {
+    format!("{}:{}", profile_url, CONTRACT_KIND)
+}
+
+/// Tracks the live associations of the NftResolver. An `address` entry is the profile owner
+/// adding a wallet, an `approval` entry is the wallet accepting the profile and a `contract`
+/// entry is the contract the profile points to. Values are the profile owner, or the contract.
+pub fn store_associations(blk: &eth::Block, store: &StoreSetString) {
+    for log in blk.receipts().flat_map(|receipt| receipt.receipt.logs.iter()) {
+        if log.address != RESOLVER_ADDRESS {
+            continue;
+        }
+
+        if let Some(event) = AssociateEvmUserEvent::match_and_decode(log) {
+            store.set(
+                log.ordinal,
+                address_key(&event.profile_url, &event.associated_address),
+                &Hex(&event.owner).to_string(),
+            );
+        } else if let Some(event) = CancelledEvmAssociationEvent::match_and_decode(log) {
+            store.delete_prefix(
+                log.ordinal as i64,
+                &address_key(&event.profile_url, &event.associated_addresses),
+            );
+        } else if let Some(event) = ClearAllAssociatedAddressesEvent::match_and_decode(log) {
+            // Only the owner side is cleared, approvals given by the wallets are kept on chain.
+            store.delete_prefix(
+                log.ordinal as i64,
+                &format!("{}:{}:", event.profile_url, ADDRESS_KIND),
+            );
+        } else if let Some(event) = AssociateSelfWithUserEvent::match_and_decode(log) {
+            store.set(
+                log.ordinal,
+                approval_key(&event.profile_url, &event.receiver),
+                &Hex(&event.profile_owner).to_string(),
+            );
+        } else if let Some(event) = RemovedAssociateProfileEvent::match_and_decode(log) {
+            store.delete_prefix(
+                log.ordinal as i64,
+                &approval_key(&event.profile_url, &event.receiver),
+            );
+        } else if let Some(event) = SetAssociatedContractEvent::match_and_decode(log) {
+            store.set(
+                log.ordinal,
+                contract_key(&event.profile_url),
+                &event.associated_contract,
+            );
+        } else if let Some(event) = ClearAssociatedContractEvent::match_and_decode(log) {
+            store.delete_prefix(log.ordinal as i64, &contract_key(&event.profile_url));
+        }
+    }
+}
+
+/// Mirrors the association store into the `profile_associations` table, one row per key. The
+/// wallets of a profile are the `address` rows having a matching `approval` row.
+pub fn push_association_changes(changes: &mut DatabaseChanges, deltas: Deltas<DeltaString>) {
+    for delta in deltas.deltas {
+        let mut parts = delta.key.splitn(3, ':');
+        let profile_url = parts.next().unwrap_or_default().to_string();
+        let kind = parts.next().unwrap_or_default().to_string();
+        let key_address = parts.next().map(|address| address.to_string());
+
+        // Contract associations carry the contract as value, the others the profile owner.
+        let columns = |value: String| match &key_address {
+            Some(address) => (address.clone(), value),
+            None => (value, String::new()),
+        };
+        let (address, profile_owner) = columns(delta.new_value);
+
+        match delta.operation {
+            DeltaOperation::Create => {
+                changes
+                    .push_change("profile_associations", &delta.key, delta.ordinal, Operation::Create)
+                    .change("profile_url", (None, profile_url))
+                    .change("kind", (None, kind))
+                    .change("address", (None, address))
+                    .change("profile_owner", (None, profile_owner));
+            }
+            DeltaOperation::Update => {
+                let (old_address, old_profile_owner) = columns(delta.old_value);
+
+                changes
+                    .push_change("profile_associations", &delta.key, delta.ordinal, Operation::Update)
+                    .change("address", (Some(old_address), address))
+                    .change("profile_owner", (Some(old_profile_owner), profile_owner));
+            }
+            DeltaOperation::Delete => {
+                changes.push_change("profile_associations", &delta.key, delta.ordinal, Operation::Delete);
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use std::collections::HashSet;
+
+use hex_literal::hex;
+use substreams::scalar::BigInt;
+use substreams::store::{StoreGet, StoreGetProto};
+use substreams_ethereum::pb::eth::v2 as eth;
+use substreams_ethereum::Event;
+
+use crate::abi::nftx_vault::events::Minted as MintedEvent;
+use crate::abi::nftx_vault::events::Redeemed as RedeemedEvent;
+use crate::abi::nftx_vault::events::Swapped as SwappedEvent;
+use crate::abi::nftx_vault_factory::events::NewVault as NewVaultEvent;
+use crate::pb::pools;
+use crate::pb::sales;
+use crate::pb::transfers;
+use crate::pools::{pool_key, pool_sale, swap_transfers, Direction, Swap};
+
+pub const MARKETPLACE_NAME: &str = "nftx";
+
+pub const VAULT_FACTORY_ADDRESS: [u8; 20] = hex!("be86f647b167567525ccaafcd6f881f1ee558216");
+
+// A vault mints one vToken, with 18 decimals, per NFT deposited and burns one per NFT redeemed.
+const VTOKEN_UNIT: u64 = 1_000_000_000_000_000_000;
+
+/// Decodes the vaults created by the factory of the block. The vault is also the ERC20 of its
+/// vTokens, which is what its NFTs trade for.
+pub fn pools_from_block(blk: &eth::Block) -> Vec<pools::Pool> {
+    let header = blk.header.as_ref().unwrap();
+
+    blk.receipts()
+        .flat_map(|receipt| {
+            receipt
+                .receipt
+                .logs
+                .iter()
+                .filter(|log| log.address == VAULT_FACTORY_ADDRESS)
+                .filter_map(move |log| {
+                    let event = NewVaultEvent::match_and_decode(log)?;
+
+                    Some(pools::Pool {
+                        amm: MARKETPLACE_NAME.to_string(),
+                        address: event.vault_address.clone(),
+                        nft_contract: event.asset_address,
+                        payment_token: event.vault_address,
+                        tx_hash: receipt.transaction.hash.clone(),
+                        ordinal: log.block_index as u64,
+                        block_number: blk.number,
+                        timestamp: Some(header.timestamp.as_ref().unwrap().clone()),
+                    })
+                })
+        })
+        .collect()
+}
+
+/// Decodes the vault operations of the block. `Minted` sells NFTs to the vault, `Redeemed` buys
+/// NFTs from the vault and `Swapped` does both. Several operations of the same vault in a
+/// transaction are a single swap per direction.
+pub fn swaps_from_block(blk: &eth::Block, pools: &StoreGetProto<pools::Pool>) -> Vec<Swap> {
+    let mut seen: HashSet<(Vec<u8>, Vec<u8>, Direction)> = HashSet::new();
+    let mut swaps = Vec::new();
+
+    for receipt in blk.receipts() {
+        for log in receipt.receipt.logs.iter() {
+            let directions = if MintedEvent::match_log(log) {
+                vec![Direction::In]
+            } else if RedeemedEvent::match_log(log) {
+                vec![Direction::Out]
+            } else if SwappedEvent::match_log(log) {
+                vec![Direction::In, Direction::Out]
+            } else {
+                continue;
+            };
+
+            let pool = match pools.get_last(pool_key(&log.address)) {
+                Some(pool) if pool.amm == MARKETPLACE_NAME => pool,
+                _ => continue,
+            };
+            for direction in directions {
+                if seen.insert((receipt.transaction.hash.clone(), log.address.clone(), direction)) {
+                    swaps.push(Swap {
+                        tx_hash: receipt.transaction.hash.clone(),
+                        pool: pool.clone(),
+                        direction,
+                    });
+                }
+            }
+        }
+    }
+
+    swaps
+}
+
+/// Builds the sales of the vault operations, each NFT trading for one vToken before fees. The NFTs
+/// traded are the transfers into or out of the vault in the same transaction.
+pub fn sales_from_swaps(swaps: &[Swap], transfers: &[transfers::Transfer]) -> Vec<sales::Sale> {
+    let unit = BigInt::from(VTOKEN_UNIT);
+
+    swaps
+        .iter()
+        .flat_map(|swap| {
+            swap_transfers(transfers, swap).map(|transfer| {
+                let quantity = BigInt::try_from(transfer.quantity.clone()).unwrap_or_else(|_| BigInt::from(1));
+
+                pool_sale(MARKETPLACE_NAME, &swap.pool, transfer, &(quantity * unit.clone()))
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,200 @@
+use substreams::pb::substreams::store_delta::Operation as DeltaOperation;
+use substreams::store::{DeltaProto, Deltas, StoreSet, StoreSetProto};
+use substreams::Hex;
+use substreams_database_change::pb::database::{table_change::Operation, DatabaseChanges};
+
+use crate::pb::ownership;
+use crate::pb::transfers;
+
+pub fn owner_key(contract_address: &[u8], token_id: &[u8]) -> String {
+    format!("{}:{}", Hex(contract_address), Hex(token_id))
+}
+
+/// Records the current owner of every ERC721 token transferred in the block. ERC1155 tokens
+/// can have many holders and are left out.
+pub fn store_owners(transfers: &[transfers::Transfer], store: &StoreSetProto<ownership::Ownership>) {
+    for transfer in transfers.iter().filter(|transfer| transfer.schema == "erc721") {
+        store.set(
+            transfer.ordinal,
+            owner_key(&transfer.contract_address, &transfer.token_id),
+            &ownership::Ownership {
+                contract_address: transfer.contract_address.clone(),
+                token_id: transfer.token_id.clone(),
+                owner: transfer.to_address.clone(),
+                tx_hash: transfer.tx_hash.clone(),
+                ordinal: transfer.ordinal,
+                block_number: transfer.block_number,
+                timestamp: transfer.timestamp.clone(),
+                previous_owner: transfer.from_address.clone(),
+            },
+        );
+    }
+}
+
+/// Turns the owner store deltas of the block into ownership changes, in transfer order. A
+/// transfer to the current owner changes nothing and is skipped.
+pub fn changes_from_deltas(deltas: Deltas<DeltaProto<ownership::Ownership>>) -> Vec<ownership::OwnershipChange> {
+    deltas
+        .deltas
+        .into_iter()
+        .filter(|delta| delta.operation == DeltaOperation::Create || delta.operation == DeltaOperation::Update)
+        .filter(|delta| delta.operation == DeltaOperation::Create || delta.old_value.owner != delta.new_value.owner)
+        .map(|delta| {
+            let value = delta.new_value;
+
+            ownership::OwnershipChange {
+                contract_address: value.contract_address,
+                token_id: value.token_id,
+                previous_owner: value.previous_owner,
+                new_owner: value.owner,
+                tx_hash: value.tx_hash,
+                ordinal: value.ordinal,
+                block_number: value.block_number,
+                timestamp: value.timestamp,
+            }
+        })
+        .collect()
+}
+
+fn interval_key(ownership: &ownership::Ownership) -> String {
+    format!(
+        "{}-{}-{}-{}",
+        Hex(&ownership.contract_address),
+        Hex(&ownership.token_id),
+        ownership.block_number,
+        ownership.ordinal,
+    )
+}
+
+/// Maintains the `ownership_intervals` table from the owner store deltas. Every transfer closes
+/// the interval opened by the previous one and opens a new one, keyed by the transfer starting
+/// it. Closing is an update carrying the previous (empty) end, so the sink can revert it on undo.
+pub fn push_interval_changes(changes: &mut DatabaseChanges, deltas: &Deltas<DeltaProto<ownership::Ownership>>) {
+    for delta in deltas.deltas.iter() {
+        let value = &delta.new_value;
+
+        match delta.operation {
+            DeltaOperation::Create => {}
+            DeltaOperation::Update => {
+                let key = interval_key(&delta.old_value);
+                changes
+                    .push_change("ownership_intervals", &key, delta.ordinal, Operation::Update)
+                    .change("end_block", (None, value.block_number))
+                    .change("end_time", (None, value.timestamp.clone().unwrap()));
+            }
+            _ => continue,
+        }
+
+        let key = interval_key(value);
+        changes
+            .push_change("ownership_intervals", &key, delta.ordinal, Operation::Create)
+            .change("contract", (None, Hex(&value.contract_address)))
+            .change("token_id", (None, Hex(&value.token_id)))
+            .change("owner", (None, Hex(&value.owner)))
+            .change("start_block", (None, value.block_number))
+            .change("start_time", (None, value.timestamp.clone().unwrap()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{changes_from_deltas, push_interval_changes};
+    use crate::pb::ownership::Ownership;
+    use prost_types::Timestamp;
+    use substreams::pb::substreams::store_delta::Operation;
+    use substreams::store::{DeltaProto, Deltas};
+    use substreams_database_change::pb::database::{table_change, DatabaseChanges};
+
+    fn owned_by(owner: u8, previous_owner: u8) -> Ownership {
+        Ownership {
+            contract_address: vec![0x11; 20],
+            token_id: vec![0x2a],
+            owner: vec![owner; 20],
+            previous_owner: vec![previous_owner; 20],
+            ..Default::default()
+        }
+    }
+
+    fn owned_at(owner: u8, block_number: u64, ordinal: u64) -> Ownership {
+        Ownership {
+            ordinal,
+            block_number,
+            timestamp: Some(Timestamp {
+                seconds: block_number as i64 * 12,
+                nanos: 0,
+            }),
+            ..owned_by(owner, 0)
+        }
+    }
+
+    fn delta(operation: Operation, old_value: Ownership, new_value: Ownership) -> DeltaProto<Ownership> {
+        DeltaProto {
+            operation,
+            ordinal: 0,
+            key: "key".to_string(),
+            old_value,
+            new_value,
+        }
+    }
+
+    #[test]
+    fn it_changes_from_deltas() {
+        let changes = changes_from_deltas(Deltas {
+            deltas: vec![
+                delta(Operation::Create, Ownership::default(), owned_by(1, 0)),
+                delta(Operation::Update, owned_by(1, 0), owned_by(1, 1)),
+                delta(Operation::Update, owned_by(1, 1), owned_by(2, 1)),
+            ],
+        });
+
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].previous_owner, vec![0; 20]);
+        assert_eq!(changes[0].new_owner, vec![1; 20]);
+        assert_eq!(changes[1].previous_owner, vec![1; 20]);
+        assert_eq!(changes[1].new_owner, vec![2; 20]);
+    }
+
+    #[test]
+    fn it_push_interval_changes() {
+        let mut changes = DatabaseChanges::default();
+        push_interval_changes(
+            &mut changes,
+            &Deltas {
+                deltas: vec![
+                    delta(Operation::Create, Ownership::default(), owned_at(1, 10, 4)),
+                    delta(Operation::Update, owned_at(1, 10, 4), owned_at(2, 12, 7)),
+                ],
+            },
+        );
+
+        let rows: Vec<(&str, i32)> = changes
+            .table_changes
+            .iter()
+            .map(|change| (change.pk.as_str(), change.operation))
+            .collect();
+        let first = "1111111111111111111111111111111111111111-2a-10-4";
+        let second = "1111111111111111111111111111111111111111-2a-12-7";
+        assert_eq!(
+            rows,
+            vec![
+                (first, table_change::Operation::Create as i32),
+                (first, table_change::Operation::Update as i32),
+                (second, table_change::Operation::Create as i32),
+            ]
+        );
+
+        let field = |row: usize, name: &str| {
+            changes.table_changes[row]
+                .fields
+                .iter()
+                .find(|field| field.name == name)
+                .map(|field| field.new_value.clone())
+        };
+        assert_eq!(field(0, "owner"), Some("01".repeat(20)));
+        assert_eq!(field(0, "start_block"), Some("10".to_string()));
+        assert_eq!(field(1, "end_block"), Some("12".to_string()));
+        assert_eq!(field(1, "owner"), None);
+        assert_eq!(field(2, "owner"), Some("02".repeat(20)));
+        assert_eq!(field(2, "start_block"), Some("12".to_string()));
+    }
+}
@@ -0,0 +1,453 @@
+// @generated
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Contracts {
+    #[prost(message, repeated, tag="1")]
+    pub contracts: ::prost::alloc::vec::Vec<Contract>,
+}
+/// Contract is an NFT contract, captured the first time one of its tokens is transferred.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Contract {
+    /// Address is the address of the smart contract that is the token contract.
+    #[prost(bytes="vec", tag="1")]
+    pub address: ::prost::alloc::vec::Vec<u8>,
+    /// Schema is the token standard of the first transfer seen, either `erc721` or `erc1155`.
+    #[prost(string, tag="2")]
+    pub schema: ::prost::alloc::string::String,
+    /// SupportsRoyalties is true when the contract reports support of EIP-2981 (`0x2a55205a`).
+    #[prost(bool, tag="3")]
+    pub supports_royalties: bool,
+    /// RoyaltyReceiver is the EIP-2981 royalty receiver of the first token seen, only set when
+    /// `supports_royalties` is true.
+    #[prost(bytes="vec", tag="4")]
+    pub royalty_receiver: ::prost::alloc::vec::Vec<u8>,
+    /// RoyaltyBps is the EIP-2981 royalty of the first token seen, in basis points.
+    #[prost(string, tag="5")]
+    pub royalty_bps: ::prost::alloc::string::String,
+    /// Ordinal is the order within the block of the first transfer seen.
+    #[prost(uint64, tag="6")]
+    pub ordinal: u64,
+    #[prost(uint64, tag="7")]
+    pub block_number: u64,
+    /// Timestamp is the timestamp of the block.
+    #[prost(message, optional, tag="8")]
+    pub timestamp: ::core::option::Option<::prost_types::Timestamp>,
+    /// Name is the result of `name()` when the contract was first seen, empty if not implemented.
+    #[prost(string, tag="9")]
+    pub name: ::prost::alloc::string::String,
+    /// Symbol is the result of `symbol()` when the contract was first seen, empty if not implemented.
+    #[prost(string, tag="10")]
+    pub symbol: ::prost::alloc::string::String,
+    /// Interfaces is a bitset of the interfaces the contract reports through `supportsInterface`, bit
+    /// `i` being set when it supports the i-th interface of: ERC721, ERC721Metadata, ERC721Enumerable,
+    /// ERC1155, ERC1155MetadataURI, ERC2981, ERC4907 and ERC5192.
+    #[prost(uint64, tag="11")]
+    pub interfaces: u64,
+    /// Owner is the last `OwnershipTransferred` owner when the contract was first seen, or else the
+    /// result of `owner()`, empty if not Ownable.
+    #[prost(bytes="vec", tag="12")]
+    pub owner: ::prost::alloc::vec::Vec<u8>,
+    /// Admin is the ERC-1967 proxy admin when the contract was first seen, empty if not a proxy.
+    #[prost(bytes="vec", tag="13")]
+    pub admin: ::prost::alloc::vec::Vec<u8>,
+    /// Implementation is the ERC-1967 implementation when the contract was first seen.
+    #[prost(bytes="vec", tag="14")]
+    pub implementation: ::prost::alloc::vec::Vec<u8>,
+    /// Beacon is the ERC-1967 beacon when the contract was first seen.
+    #[prost(bytes="vec", tag="15")]
+    pub beacon: ::prost::alloc::vec::Vec<u8>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Nfts {
+    #[prost(message, repeated, tag="1")]
+    pub nfts: ::prost::alloc::vec::Vec<Nft>,
+}
+/// Nft is a token of an NFT contract, captured the first time it is transferred, usually at mint.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Nft {
+    /// ContractAddress is the address of the smart contract that is the token contract.
+    #[prost(bytes="vec", tag="1")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+    /// TokenID the identifier of the token within `contract_address`.
+    #[prost(bytes="vec", tag="2")]
+    pub token_id: ::prost::alloc::vec::Vec<u8>,
+    /// Schema is the token standard of the token, either `erc721` or `erc1155`.
+    #[prost(string, tag="3")]
+    pub schema: ::prost::alloc::string::String,
+    /// RoyaltyReceiver is the EIP-2981 royalty receiver of the token, only set when the contract
+    /// supports royalties.
+    #[prost(bytes="vec", tag="4")]
+    pub royalty_receiver: ::prost::alloc::vec::Vec<u8>,
+    /// RoyaltyBps is the EIP-2981 royalty of the token, in basis points.
+    #[prost(string, tag="5")]
+    pub royalty_bps: ::prost::alloc::string::String,
+    /// Ordinal is the order within the block of the first transfer seen.
+    #[prost(uint64, tag="6")]
+    pub ordinal: u64,
+    #[prost(uint64, tag="7")]
+    pub block_number: u64,
+    /// Timestamp is the timestamp of the block.
+    #[prost(message, optional, tag="8")]
+    pub timestamp: ::core::option::Option<::prost_types::Timestamp>,
+    /// TokenURI is the result of `tokenURI(id)` for ERC721 or `uri(id)` for ERC1155 when the token
+    /// was first seen, empty if not implemented.
+    #[prost(string, tag="9")]
+    pub token_uri: ::prost::alloc::string::String,
+    /// Metadata is the JSON embedded in `token_uri` when it is a `data:` URI, empty otherwise or when
+    /// larger than the size cap.
+    #[prost(string, tag="10")]
+    pub metadata: ::prost::alloc::string::String,
+    /// Name is the `name` field of the embedded metadata.
+    #[prost(string, tag="11")]
+    pub name: ::prost::alloc::string::String,
+    /// Image is the `image` field of the embedded metadata, empty when larger than the size cap.
+    #[prost(string, tag="12")]
+    pub image: ::prost::alloc::string::String,
+    /// Attributes is the `attributes` field of the embedded metadata, as JSON.
+    #[prost(string, tag="13")]
+    pub attributes: ::prost::alloc::string::String,
+    /// CanonicalURI is `token_uri` normalized into a resolvable form: `ipfs://<cid>/<path>` for IPFS
+    /// URIs and gateway URLs, `<https://arweave.net/<id>`> for Arweave and with the ERC1155 `{id}`
+    /// template substituted.
+    #[prost(string, tag="14")]
+    pub canonical_uri: ::prost::alloc::string::String,
+}
+/// Encoded file descriptor set for the `eth.contracts.v1` package
+pub const FILE_DESCRIPTOR_SET: &[u8] = &[
+    0x0a, 0xef, 0x29, 0x0a, 0x0f, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x73, 0x2e, 0x70,
+    0x72, 0x6f, 0x74, 0x6f, 0x12, 0x10, 0x65, 0x74, 0x68, 0x2e, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61,
+    0x63, 0x74, 0x73, 0x2e, 0x76, 0x31, 0x1a, 0x1f, 0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2f, 0x70,
+    0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x2f, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d,
+    0x70, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x22, 0x45, 0x0a, 0x09, 0x43, 0x6f, 0x6e, 0x74, 0x72,
+    0x61, 0x63, 0x74, 0x73, 0x12, 0x38, 0x0a, 0x09, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74,
+    0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1a, 0x2e, 0x65, 0x74, 0x68, 0x2e, 0x63, 0x6f,
+    0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x43, 0x6f, 0x6e, 0x74, 0x72,
+    0x61, 0x63, 0x74, 0x52, 0x09, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x73, 0x22, 0x90,
+    0x03, 0x0a, 0x08, 0x43, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x12, 0x18, 0x0a, 0x07, 0x61,
+    0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x61, 0x64,
+    0x64, 0x72, 0x65, 0x73, 0x73, 0x12, 0x16, 0x0a, 0x06, 0x73, 0x63, 0x68, 0x65, 0x6d, 0x61, 0x18,
+    0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06, 0x73, 0x63, 0x68, 0x65, 0x6d, 0x61, 0x12, 0x2d, 0x0a,
+    0x12, 0x73, 0x75, 0x70, 0x70, 0x6f, 0x72, 0x74, 0x73, 0x5f, 0x72, 0x6f, 0x79, 0x61, 0x6c, 0x74,
+    0x69, 0x65, 0x73, 0x18, 0x03, 0x20, 0x01, 0x28, 0x08, 0x52, 0x11, 0x73, 0x75, 0x70, 0x70, 0x6f,
+    0x72, 0x74, 0x73, 0x52, 0x6f, 0x79, 0x61, 0x6c, 0x74, 0x69, 0x65, 0x73, 0x12, 0x29, 0x0a, 0x10,
+    0x72, 0x6f, 0x79, 0x61, 0x6c, 0x74, 0x79, 0x5f, 0x72, 0x65, 0x63, 0x65, 0x69, 0x76, 0x65, 0x72,
+    0x18, 0x04, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x0f, 0x72, 0x6f, 0x79, 0x61, 0x6c, 0x74, 0x79, 0x52,
+    0x65, 0x63, 0x65, 0x69, 0x76, 0x65, 0x72, 0x12, 0x1f, 0x0a, 0x0b, 0x72, 0x6f, 0x79, 0x61, 0x6c,
+    0x74, 0x79, 0x5f, 0x62, 0x70, 0x73, 0x18, 0x05, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0a, 0x72, 0x6f,
+    0x79, 0x61, 0x6c, 0x74, 0x79, 0x42, 0x70, 0x73, 0x12, 0x18, 0x0a, 0x07, 0x6f, 0x72, 0x64, 0x69,
+    0x6e, 0x61, 0x6c, 0x18, 0x06, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x6f, 0x72, 0x64, 0x69, 0x6e,
+    0x61, 0x6c, 0x12, 0x21, 0x0a, 0x0c, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x5f, 0x6e, 0x75, 0x6d, 0x62,
+    0x65, 0x72, 0x18, 0x07, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x4e,
+    0x75, 0x6d, 0x62, 0x65, 0x72, 0x12, 0x38, 0x0a, 0x09, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61,
+    0x6d, 0x70, 0x18, 0x08, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1a, 0x2e, 0x67, 0x6f, 0x6f, 0x67, 0x6c,
+    0x65, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x2e, 0x54, 0x69, 0x6d, 0x65, 0x73,
+    0x74, 0x61, 0x6d, 0x70, 0x52, 0x09, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x12,
+    0x12, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x09, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x6e,
+    0x61, 0x6d, 0x65, 0x12, 0x16, 0x0a, 0x06, 0x73, 0x79, 0x6d, 0x62, 0x6f, 0x6c, 0x18, 0x0a, 0x20,
+    0x01, 0x28, 0x09, 0x52, 0x06, 0x73, 0x79, 0x6d, 0x62, 0x6f, 0x6c, 0x12, 0x1e, 0x0a, 0x0a, 0x69,
+    0x6e, 0x74, 0x65, 0x72, 0x66, 0x61, 0x63, 0x65, 0x73, 0x18, 0x0b, 0x20, 0x01, 0x28, 0x04, 0x52,
+    0x0a, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x66, 0x61, 0x63, 0x65, 0x73, 0x12, 0x14, 0x0a, 0x05, 0x6f,
+    0x77, 0x6e, 0x65, 0x72, 0x18, 0x0c, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x05, 0x6f, 0x77, 0x6e, 0x65,
+    0x72, 0x22, 0x31, 0x0a, 0x04, 0x4e, 0x66, 0x74, 0x73, 0x12, 0x29, 0x0a, 0x04, 0x6e, 0x66, 0x74,
+    0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x15, 0x2e, 0x65, 0x74, 0x68, 0x2e, 0x63, 0x6f,
+    0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x4e, 0x66, 0x74, 0x52, 0x04,
+    0x6e, 0x66, 0x74, 0x73, 0x22, 0xce, 0x03, 0x0a, 0x03, 0x4e, 0x66, 0x74, 0x12, 0x29, 0x0a, 0x10,
+    0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x5f, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73,
+    0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x0f, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74,
+    0x41, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x12, 0x19, 0x0a, 0x08, 0x74, 0x6f, 0x6b, 0x65, 0x6e,
+    0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x74, 0x6f, 0x6b, 0x65, 0x6e,
+    0x49, 0x64, 0x12, 0x16, 0x0a, 0x06, 0x73, 0x63, 0x68, 0x65, 0x6d, 0x61, 0x18, 0x03, 0x20, 0x01,
+    0x28, 0x09, 0x52, 0x06, 0x73, 0x63, 0x68, 0x65, 0x6d, 0x61, 0x12, 0x29, 0x0a, 0x10, 0x72, 0x6f,
+    0x79, 0x61, 0x6c, 0x74, 0x79, 0x5f, 0x72, 0x65, 0x63, 0x65, 0x69, 0x76, 0x65, 0x72, 0x18, 0x04,
+    0x20, 0x01, 0x28, 0x0c, 0x52, 0x0f, 0x72, 0x6f, 0x79, 0x61, 0x6c, 0x74, 0x79, 0x52, 0x65, 0x63,
+    0x65, 0x69, 0x76, 0x65, 0x72, 0x12, 0x1f, 0x0a, 0x0b, 0x72, 0x6f, 0x79, 0x61, 0x6c, 0x74, 0x79,
+    0x5f, 0x62, 0x70, 0x73, 0x18, 0x05, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0a, 0x72, 0x6f, 0x79, 0x61,
+    0x6c, 0x74, 0x79, 0x42, 0x70, 0x73, 0x12, 0x18, 0x0a, 0x07, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61,
+    0x6c, 0x18, 0x06, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c,
+    0x12, 0x21, 0x0a, 0x0c, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x5f, 0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72,
+    0x18, 0x07, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x4e, 0x75, 0x6d,
+    0x62, 0x65, 0x72, 0x12, 0x38, 0x0a, 0x09, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70,
+    0x18, 0x08, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1a, 0x2e, 0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2e,
+    0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x2e, 0x54, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61,
+    0x6d, 0x70, 0x52, 0x09, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x12, 0x1b, 0x0a,
+    0x09, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x5f, 0x75, 0x72, 0x69, 0x18, 0x09, 0x20, 0x01, 0x28, 0x09,
+    0x52, 0x08, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x55, 0x72, 0x69, 0x12, 0x1a, 0x0a, 0x08, 0x6d, 0x65,
+    0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x18, 0x0a, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x6d, 0x65,
+    0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x12, 0x12, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x0b,
+    0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x12, 0x14, 0x0a, 0x05, 0x69, 0x6d,
+    0x61, 0x67, 0x65, 0x18, 0x0c, 0x20, 0x01, 0x28, 0x09, 0x52, 0x05, 0x69, 0x6d, 0x61, 0x67, 0x65,
+    0x12, 0x1e, 0x0a, 0x0a, 0x61, 0x74, 0x74, 0x72, 0x69, 0x62, 0x75, 0x74, 0x65, 0x73, 0x18, 0x0d,
+    0x20, 0x01, 0x28, 0x09, 0x52, 0x0a, 0x61, 0x74, 0x74, 0x72, 0x69, 0x62, 0x75, 0x74, 0x65, 0x73,
+    0x12, 0x23, 0x0a, 0x0d, 0x63, 0x61, 0x6e, 0x6f, 0x6e, 0x69, 0x63, 0x61, 0x6c, 0x5f, 0x75, 0x72,
+    0x69, 0x18, 0x0e, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0c, 0x63, 0x61, 0x6e, 0x6f, 0x6e, 0x69, 0x63,
+    0x61, 0x6c, 0x55, 0x72, 0x69, 0x4a, 0xc2, 0x21, 0x0a, 0x06, 0x12, 0x04, 0x00, 0x00, 0x67, 0x01,
+    0x0a, 0x08, 0x0a, 0x01, 0x0c, 0x12, 0x03, 0x00, 0x00, 0x12, 0x0a, 0x08, 0x0a, 0x01, 0x02, 0x12,
+    0x03, 0x02, 0x00, 0x19, 0x0a, 0x09, 0x0a, 0x02, 0x03, 0x00, 0x12, 0x03, 0x04, 0x00, 0x29, 0x0a,
+    0x0a, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x06, 0x00, 0x08, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04,
+    0x00, 0x01, 0x12, 0x03, 0x06, 0x08, 0x11, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12,
+    0x03, 0x07, 0x02, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x04, 0x12, 0x03, 0x07,
+    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x06, 0x12, 0x03, 0x07, 0x0b, 0x13,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x07, 0x14, 0x1d, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x07, 0x20, 0x21, 0x0a, 0x64, 0x0a, 0x02,
+    0x04, 0x01, 0x12, 0x04, 0x0b, 0x00, 0x32, 0x01, 0x1a, 0x58, 0x20, 0x43, 0x6f, 0x6e, 0x74, 0x72,
+    0x61, 0x63, 0x74, 0x20, 0x69, 0x73, 0x20, 0x61, 0x6e, 0x20, 0x4e, 0x46, 0x54, 0x20, 0x63, 0x6f,
+    0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x2c, 0x20, 0x63, 0x61, 0x70, 0x74, 0x75, 0x72, 0x65, 0x64,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x66, 0x69, 0x72, 0x73, 0x74, 0x20, 0x74, 0x69, 0x6d, 0x65, 0x20,
+    0x6f, 0x6e, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x69, 0x74, 0x73, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e,
+    0x73, 0x20, 0x69, 0x73, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x72, 0x65, 0x64,
+    0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01, 0x12, 0x03, 0x0b, 0x08, 0x10, 0x0a, 0x57,
+    0x0a, 0x04, 0x04, 0x01, 0x02, 0x00, 0x12, 0x03, 0x0d, 0x02, 0x14, 0x1a, 0x4a, 0x20, 0x41, 0x64,
+    0x64, 0x72, 0x65, 0x73, 0x73, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x64, 0x64,
+    0x72, 0x65, 0x73, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x6d, 0x61, 0x72,
+    0x74, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20,
+    0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x63, 0x6f, 0x6e,
+    0x74, 0x72, 0x61, 0x63, 0x74, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x05,
+    0x12, 0x03, 0x0d, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03,
+    0x0d, 0x08, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x0d, 0x12,
+    0x13, 0x0a, 0x65, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x01, 0x12, 0x03, 0x10, 0x02, 0x14, 0x1a, 0x58,
+    0x20, 0x53, 0x63, 0x68, 0x65, 0x6d, 0x61, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74,
+    0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x73, 0x74, 0x61, 0x6e, 0x64, 0x61, 0x72, 0x64, 0x20, 0x6f, 0x66,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x66, 0x69, 0x72, 0x73, 0x74, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73,
+    0x66, 0x65, 0x72, 0x20, 0x73, 0x65, 0x65, 0x6e, 0x2c, 0x20, 0x65, 0x69, 0x74, 0x68, 0x65, 0x72,
+    0x20, 0x60, 0x65, 0x72, 0x63, 0x37, 0x32, 0x31, 0x60, 0x20, 0x6f, 0x72, 0x20, 0x60, 0x65, 0x72,
+    0x63, 0x31, 0x31, 0x35, 0x35, 0x60, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01,
+    0x05, 0x12, 0x03, 0x10, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x01, 0x12,
+    0x03, 0x10, 0x09, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x03, 0x12, 0x03, 0x10,
+    0x12, 0x13, 0x0a, 0x66, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x02, 0x12, 0x03, 0x13, 0x02, 0x1e, 0x1a,
+    0x59, 0x20, 0x53, 0x75, 0x70, 0x70, 0x6f, 0x72, 0x74, 0x73, 0x52, 0x6f, 0x79, 0x61, 0x6c, 0x74,
+    0x69, 0x65, 0x73, 0x20, 0x69, 0x73, 0x20, 0x74, 0x72, 0x75, 0x65, 0x20, 0x77, 0x68, 0x65, 0x6e,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x20, 0x72, 0x65,
+    0x70, 0x6f, 0x72, 0x74, 0x73, 0x20, 0x73, 0x75, 0x70, 0x70, 0x6f, 0x72, 0x74, 0x20, 0x6f, 0x66,
+    0x20, 0x45, 0x49, 0x50, 0x2d, 0x32, 0x39, 0x38, 0x31, 0x20, 0x28, 0x60, 0x30, 0x78, 0x32, 0x61,
+    0x35, 0x35, 0x32, 0x30, 0x35, 0x61, 0x60, 0x29, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
+    0x02, 0x02, 0x05, 0x12, 0x03, 0x13, 0x02, 0x06, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02,
+    0x01, 0x12, 0x03, 0x13, 0x07, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x03, 0x12,
+    0x03, 0x13, 0x1c, 0x1d, 0x0a, 0x85, 0x01, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x03, 0x12, 0x03, 0x17,
+    0x02, 0x1d, 0x1a, 0x78, 0x20, 0x52, 0x6f, 0x79, 0x61, 0x6c, 0x74, 0x79, 0x52, 0x65, 0x63, 0x65,
+    0x69, 0x76, 0x65, 0x72, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x45, 0x49, 0x50, 0x2d,
+    0x32, 0x39, 0x38, 0x31, 0x20, 0x72, 0x6f, 0x79, 0x61, 0x6c, 0x74, 0x79, 0x20, 0x72, 0x65, 0x63,
+    0x65, 0x69, 0x76, 0x65, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x66, 0x69, 0x72,
+    0x73, 0x74, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x73, 0x65, 0x65, 0x6e, 0x2c, 0x20, 0x6f,
+    0x6e, 0x6c, 0x79, 0x20, 0x73, 0x65, 0x74, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x0a, 0x20, 0x60, 0x73,
+    0x75, 0x70, 0x70, 0x6f, 0x72, 0x74, 0x73, 0x5f, 0x72, 0x6f, 0x79, 0x61, 0x6c, 0x74, 0x69, 0x65,
+    0x73, 0x60, 0x20, 0x69, 0x73, 0x20, 0x74, 0x72, 0x75, 0x65, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x01, 0x02, 0x03, 0x05, 0x12, 0x03, 0x17, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
+    0x02, 0x03, 0x01, 0x12, 0x03, 0x17, 0x08, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x03,
+    0x03, 0x12, 0x03, 0x17, 0x1b, 0x1c, 0x0a, 0x5b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x04, 0x12, 0x03,
+    0x1a, 0x02, 0x19, 0x1a, 0x4e, 0x20, 0x52, 0x6f, 0x79, 0x61, 0x6c, 0x74, 0x79, 0x42, 0x70, 0x73,
+    0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x45, 0x49, 0x50, 0x2d, 0x32, 0x39, 0x38, 0x31,
+    0x20, 0x72, 0x6f, 0x79, 0x61, 0x6c, 0x74, 0x79, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x66, 0x69, 0x72, 0x73, 0x74, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x73, 0x65, 0x65, 0x6e,
+    0x2c, 0x20, 0x69, 0x6e, 0x20, 0x62, 0x61, 0x73, 0x69, 0x73, 0x20, 0x70, 0x6f, 0x69, 0x6e, 0x74,
+    0x73, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x04, 0x05, 0x12, 0x03, 0x1a, 0x02,
+    0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x04, 0x01, 0x12, 0x03, 0x1a, 0x09, 0x14, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x04, 0x03, 0x12, 0x03, 0x1a, 0x17, 0x18, 0x0a, 0x50, 0x0a,
+    0x04, 0x04, 0x01, 0x02, 0x05, 0x12, 0x03, 0x1d, 0x02, 0x15, 0x1a, 0x43, 0x20, 0x4f, 0x72, 0x64,
+    0x69, 0x6e, 0x61, 0x6c, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x64, 0x65,
+    0x72, 0x20, 0x77, 0x69, 0x74, 0x68, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f,
+    0x63, 0x6b, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x66, 0x69, 0x72, 0x73, 0x74, 0x20,
+    0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x20, 0x73, 0x65, 0x65, 0x6e, 0x2e, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x05, 0x05, 0x12, 0x03, 0x1d, 0x02, 0x08, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x01, 0x02, 0x05, 0x01, 0x12, 0x03, 0x1d, 0x09, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x01, 0x02, 0x05, 0x03, 0x12, 0x03, 0x1d, 0x13, 0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02,
+    0x06, 0x12, 0x03, 0x1f, 0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x06, 0x05, 0x12,
+    0x03, 0x1f, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x06, 0x01, 0x12, 0x03, 0x1f,
+    0x09, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x06, 0x03, 0x12, 0x03, 0x1f, 0x18, 0x19,
+    0x0a, 0x37, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x07, 0x12, 0x03, 0x22, 0x02, 0x2a, 0x1a, 0x2a, 0x20,
+    0x54, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
+    0x07, 0x06, 0x12, 0x03, 0x22, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x07, 0x01,
+    0x12, 0x03, 0x22, 0x1c, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x07, 0x03, 0x12, 0x03,
+    0x22, 0x28, 0x29, 0x0a, 0x69, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x08, 0x12, 0x03, 0x25, 0x02, 0x12,
+    0x1a, 0x5c, 0x20, 0x4e, 0x61, 0x6d, 0x65, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x72,
+    0x65, 0x73, 0x75, 0x6c, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x60, 0x6e, 0x61, 0x6d, 0x65, 0x28, 0x29,
+    0x60, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72,
+    0x61, 0x63, 0x74, 0x20, 0x77, 0x61, 0x73, 0x20, 0x66, 0x69, 0x72, 0x73, 0x74, 0x20, 0x73, 0x65,
+    0x65, 0x6e, 0x2c, 0x20, 0x65, 0x6d, 0x70, 0x74, 0x79, 0x20, 0x69, 0x66, 0x20, 0x6e, 0x6f, 0x74,
+    0x20, 0x69, 0x6d, 0x70, 0x6c, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x01, 0x02, 0x08, 0x05, 0x12, 0x03, 0x25, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x01, 0x02, 0x08, 0x01, 0x12, 0x03, 0x25, 0x09, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
+    0x02, 0x08, 0x03, 0x12, 0x03, 0x25, 0x10, 0x11, 0x0a, 0x6d, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x09,
+    0x12, 0x03, 0x28, 0x02, 0x15, 0x1a, 0x60, 0x20, 0x53, 0x79, 0x6d, 0x62, 0x6f, 0x6c, 0x20, 0x69,
+    0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x20, 0x6f, 0x66, 0x20,
+    0x60, 0x73, 0x79, 0x6d, 0x62, 0x6f, 0x6c, 0x28, 0x29, 0x60, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x20, 0x77, 0x61, 0x73,
+    0x20, 0x66, 0x69, 0x72, 0x73, 0x74, 0x20, 0x73, 0x65, 0x65, 0x6e, 0x2c, 0x20, 0x65, 0x6d, 0x70,
+    0x74, 0x79, 0x20, 0x69, 0x66, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x69, 0x6d, 0x70, 0x6c, 0x65, 0x6d,
+    0x65, 0x6e, 0x74, 0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x09, 0x05,
+    0x12, 0x03, 0x28, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x09, 0x01, 0x12, 0x03,
+    0x28, 0x09, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x09, 0x03, 0x12, 0x03, 0x28, 0x12,
+    0x14, 0x0a, 0x8b, 0x02, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x0a, 0x12, 0x03, 0x2d, 0x02, 0x19, 0x1a,
+    0xfd, 0x01, 0x20, 0x49, 0x6e, 0x74, 0x65, 0x72, 0x66, 0x61, 0x63, 0x65, 0x73, 0x20, 0x69, 0x73,
+    0x20, 0x61, 0x20, 0x62, 0x69, 0x74, 0x73, 0x65, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x66, 0x61, 0x63, 0x65, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x20, 0x72, 0x65, 0x70, 0x6f, 0x72, 0x74, 0x73,
+    0x20, 0x74, 0x68, 0x72, 0x6f, 0x75, 0x67, 0x68, 0x20, 0x60, 0x73, 0x75, 0x70, 0x70, 0x6f, 0x72,
+    0x74, 0x73, 0x49, 0x6e, 0x74, 0x65, 0x72, 0x66, 0x61, 0x63, 0x65, 0x60, 0x2c, 0x20, 0x62, 0x69,
+    0x74, 0x0a, 0x20, 0x60, 0x69, 0x60, 0x20, 0x62, 0x65, 0x69, 0x6e, 0x67, 0x20, 0x73, 0x65, 0x74,
+    0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x69, 0x74, 0x20, 0x73, 0x75, 0x70, 0x70, 0x6f, 0x72, 0x74,
+    0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x69, 0x2d, 0x74, 0x68, 0x20, 0x69, 0x6e, 0x74, 0x65, 0x72,
+    0x66, 0x61, 0x63, 0x65, 0x20, 0x6f, 0x66, 0x3a, 0x20, 0x45, 0x52, 0x43, 0x37, 0x32, 0x31, 0x2c,
+    0x20, 0x45, 0x52, 0x43, 0x37, 0x32, 0x31, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x2c,
+    0x20, 0x45, 0x52, 0x43, 0x37, 0x32, 0x31, 0x45, 0x6e, 0x75, 0x6d, 0x65, 0x72, 0x61, 0x62, 0x6c,
+    0x65, 0x2c, 0x0a, 0x20, 0x45, 0x52, 0x43, 0x31, 0x31, 0x35, 0x35, 0x2c, 0x20, 0x45, 0x52, 0x43,
+    0x31, 0x31, 0x35, 0x35, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x55, 0x52, 0x49, 0x2c,
+    0x20, 0x45, 0x52, 0x43, 0x32, 0x39, 0x38, 0x31, 0x2c, 0x20, 0x45, 0x52, 0x43, 0x34, 0x39, 0x30,
+    0x37, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x45, 0x52, 0x43, 0x35, 0x31, 0x39, 0x32, 0x2e, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0a, 0x05, 0x12, 0x03, 0x2d, 0x02, 0x08, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x01, 0x02, 0x0a, 0x01, 0x12, 0x03, 0x2d, 0x09, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x01, 0x02, 0x0a, 0x03, 0x12, 0x03, 0x2d, 0x16, 0x18, 0x0a, 0xa4, 0x01, 0x0a, 0x04, 0x04, 0x01,
+    0x02, 0x0b, 0x12, 0x03, 0x31, 0x02, 0x13, 0x1a, 0x96, 0x01, 0x20, 0x4f, 0x77, 0x6e, 0x65, 0x72,
+    0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x20, 0x6f,
+    0x66, 0x20, 0x60, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x28, 0x29, 0x60, 0x20, 0x77, 0x68, 0x65, 0x6e,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x20, 0x77, 0x61,
+    0x73, 0x20, 0x66, 0x69, 0x72, 0x73, 0x74, 0x20, 0x73, 0x65, 0x65, 0x6e, 0x2c, 0x20, 0x65, 0x6d,
+    0x70, 0x74, 0x79, 0x20, 0x69, 0x66, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x4f, 0x77, 0x6e, 0x61, 0x62,
+    0x6c, 0x65, 0x2e, 0x20, 0x4c, 0x61, 0x74, 0x65, 0x72, 0x0a, 0x20, 0x63, 0x68, 0x61, 0x6e, 0x67,
+    0x65, 0x73, 0x20, 0x61, 0x72, 0x65, 0x20, 0x66, 0x6f, 0x6c, 0x6c, 0x6f, 0x77, 0x65, 0x64, 0x20,
+    0x74, 0x68, 0x72, 0x6f, 0x75, 0x67, 0x68, 0x20, 0x60, 0x4f, 0x77, 0x6e, 0x65, 0x72, 0x73, 0x68,
+    0x69, 0x70, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x72, 0x65, 0x64, 0x60, 0x2e, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0b, 0x05, 0x12, 0x03, 0x31, 0x02, 0x07, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x01, 0x02, 0x0b, 0x01, 0x12, 0x03, 0x31, 0x08, 0x0d, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x01, 0x02, 0x0b, 0x03, 0x12, 0x03, 0x31, 0x10, 0x12, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x02,
+    0x12, 0x04, 0x34, 0x00, 0x36, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x02, 0x01, 0x12, 0x03, 0x34,
+    0x08, 0x0c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x00, 0x12, 0x03, 0x35, 0x02, 0x18, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x04, 0x12, 0x03, 0x35, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x02, 0x02, 0x00, 0x06, 0x12, 0x03, 0x35, 0x0b, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x02, 0x02, 0x00, 0x01, 0x12, 0x03, 0x35, 0x0f, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02,
+    0x00, 0x03, 0x12, 0x03, 0x35, 0x16, 0x17, 0x0a, 0x6c, 0x0a, 0x02, 0x04, 0x03, 0x12, 0x04, 0x39,
+    0x00, 0x67, 0x01, 0x1a, 0x60, 0x20, 0x4e, 0x66, 0x74, 0x20, 0x69, 0x73, 0x20, 0x61, 0x20, 0x74,
+    0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x6f, 0x66, 0x20, 0x61, 0x6e, 0x20, 0x4e, 0x46, 0x54, 0x20, 0x63,
+    0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x2c, 0x20, 0x63, 0x61, 0x70, 0x74, 0x75, 0x72, 0x65,
+    0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x66, 0x69, 0x72, 0x73, 0x74, 0x20, 0x74, 0x69, 0x6d, 0x65,
+    0x20, 0x69, 0x74, 0x20, 0x69, 0x73, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x72,
+    0x65, 0x64, 0x2c, 0x20, 0x75, 0x73, 0x75, 0x61, 0x6c, 0x6c, 0x79, 0x20, 0x61, 0x74, 0x20, 0x6d,
+    0x69, 0x6e, 0x74, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03, 0x01, 0x12, 0x03, 0x39, 0x08,
+    0x0b, 0x0a, 0x5f, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x00, 0x12, 0x03, 0x3b, 0x02, 0x1d, 0x1a, 0x52,
+    0x20, 0x43, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x41, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73,
+    0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x20,
+    0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x6d, 0x61, 0x72, 0x74, 0x20, 0x63, 0x6f, 0x6e,
+    0x74, 0x72, 0x61, 0x63, 0x74, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74,
+    0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x05, 0x12, 0x03, 0x3b, 0x02, 0x07,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x01, 0x12, 0x03, 0x3b, 0x08, 0x18, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x03, 0x12, 0x03, 0x3b, 0x1b, 0x1c, 0x0a, 0x4d, 0x0a, 0x04,
+    0x04, 0x03, 0x02, 0x01, 0x12, 0x03, 0x3e, 0x02, 0x15, 0x1a, 0x40, 0x20, 0x54, 0x6f, 0x6b, 0x65,
+    0x6e, 0x49, 0x44, 0x20, 0x74, 0x68, 0x65, 0x20, 0x69, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x66, 0x69,
+    0x65, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20,
+    0x77, 0x69, 0x74, 0x68, 0x69, 0x6e, 0x20, 0x60, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74,
+    0x5f, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x60, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x03, 0x02, 0x01, 0x05, 0x12, 0x03, 0x3e, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02,
+    0x01, 0x01, 0x12, 0x03, 0x3e, 0x08, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x03,
+    0x12, 0x03, 0x3e, 0x13, 0x14, 0x0a, 0x57, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x02, 0x12, 0x03, 0x41,
+    0x02, 0x14, 0x1a, 0x4a, 0x20, 0x53, 0x63, 0x68, 0x65, 0x6d, 0x61, 0x20, 0x69, 0x73, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x73, 0x74, 0x61, 0x6e, 0x64, 0x61, 0x72,
+    0x64, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x2c, 0x20,
+    0x65, 0x69, 0x74, 0x68, 0x65, 0x72, 0x20, 0x60, 0x65, 0x72, 0x63, 0x37, 0x32, 0x31, 0x60, 0x20,
+    0x6f, 0x72, 0x20, 0x60, 0x65, 0x72, 0x63, 0x31, 0x31, 0x35, 0x35, 0x60, 0x2e, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x03, 0x02, 0x02, 0x05, 0x12, 0x03, 0x41, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x03, 0x02, 0x02, 0x01, 0x12, 0x03, 0x41, 0x09, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03,
+    0x02, 0x02, 0x03, 0x12, 0x03, 0x41, 0x12, 0x13, 0x0a, 0x7d, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x03,
+    0x12, 0x03, 0x45, 0x02, 0x1d, 0x1a, 0x70, 0x20, 0x52, 0x6f, 0x79, 0x61, 0x6c, 0x74, 0x79, 0x52,
+    0x65, 0x63, 0x65, 0x69, 0x76, 0x65, 0x72, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x45,
+    0x49, 0x50, 0x2d, 0x32, 0x39, 0x38, 0x31, 0x20, 0x72, 0x6f, 0x79, 0x61, 0x6c, 0x74, 0x79, 0x20,
+    0x72, 0x65, 0x63, 0x65, 0x69, 0x76, 0x65, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x2c, 0x20, 0x6f, 0x6e, 0x6c, 0x79, 0x20, 0x73, 0x65, 0x74, 0x20,
+    0x77, 0x68, 0x65, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63,
+    0x74, 0x0a, 0x20, 0x73, 0x75, 0x70, 0x70, 0x6f, 0x72, 0x74, 0x73, 0x20, 0x72, 0x6f, 0x79, 0x61,
+    0x6c, 0x74, 0x69, 0x65, 0x73, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x03, 0x05,
+    0x12, 0x03, 0x45, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x03, 0x01, 0x12, 0x03,
+    0x45, 0x08, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x03, 0x03, 0x12, 0x03, 0x45, 0x1b,
+    0x1c, 0x0a, 0x50, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x04, 0x12, 0x03, 0x48, 0x02, 0x19, 0x1a, 0x43,
+    0x20, 0x52, 0x6f, 0x79, 0x61, 0x6c, 0x74, 0x79, 0x42, 0x70, 0x73, 0x20, 0x69, 0x73, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x45, 0x49, 0x50, 0x2d, 0x32, 0x39, 0x38, 0x31, 0x20, 0x72, 0x6f, 0x79, 0x61,
+    0x6c, 0x74, 0x79, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e,
+    0x2c, 0x20, 0x69, 0x6e, 0x20, 0x62, 0x61, 0x73, 0x69, 0x73, 0x20, 0x70, 0x6f, 0x69, 0x6e, 0x74,
+    0x73, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x04, 0x05, 0x12, 0x03, 0x48, 0x02,
+    0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x04, 0x01, 0x12, 0x03, 0x48, 0x09, 0x14, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x04, 0x03, 0x12, 0x03, 0x48, 0x17, 0x18, 0x0a, 0x50, 0x0a,
+    0x04, 0x04, 0x03, 0x02, 0x05, 0x12, 0x03, 0x4b, 0x02, 0x15, 0x1a, 0x43, 0x20, 0x4f, 0x72, 0x64,
+    0x69, 0x6e, 0x61, 0x6c, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x64, 0x65,
+    0x72, 0x20, 0x77, 0x69, 0x74, 0x68, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f,
+    0x63, 0x6b, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x66, 0x69, 0x72, 0x73, 0x74, 0x20,
+    0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x20, 0x73, 0x65, 0x65, 0x6e, 0x2e, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x05, 0x05, 0x12, 0x03, 0x4b, 0x02, 0x08, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x03, 0x02, 0x05, 0x01, 0x12, 0x03, 0x4b, 0x09, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x03, 0x02, 0x05, 0x03, 0x12, 0x03, 0x4b, 0x13, 0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02,
+    0x06, 0x12, 0x03, 0x4d, 0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x06, 0x05, 0x12,
+    0x03, 0x4d, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x06, 0x01, 0x12, 0x03, 0x4d,
+    0x09, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x06, 0x03, 0x12, 0x03, 0x4d, 0x18, 0x19,
+    0x0a, 0x37, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x07, 0x12, 0x03, 0x50, 0x02, 0x2a, 0x1a, 0x2a, 0x20,
+    0x54, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02,
+    0x07, 0x06, 0x12, 0x03, 0x50, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x07, 0x01,
+    0x12, 0x03, 0x50, 0x1c, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x07, 0x03, 0x12, 0x03,
+    0x50, 0x28, 0x29, 0x0a, 0x96, 0x01, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x08, 0x12, 0x03, 0x54, 0x02,
+    0x17, 0x1a, 0x88, 0x01, 0x20, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x55, 0x52, 0x49, 0x20, 0x69, 0x73,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x60,
+    0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x55, 0x52, 0x49, 0x28, 0x69, 0x64, 0x29, 0x60, 0x20, 0x66, 0x6f,
+    0x72, 0x20, 0x45, 0x52, 0x43, 0x37, 0x32, 0x31, 0x20, 0x6f, 0x72, 0x20, 0x60, 0x75, 0x72, 0x69,
+    0x28, 0x69, 0x64, 0x29, 0x60, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x45, 0x52, 0x43, 0x31, 0x31, 0x35,
+    0x35, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e,
+    0x0a, 0x20, 0x77, 0x61, 0x73, 0x20, 0x66, 0x69, 0x72, 0x73, 0x74, 0x20, 0x73, 0x65, 0x65, 0x6e,
+    0x2c, 0x20, 0x65, 0x6d, 0x70, 0x74, 0x79, 0x20, 0x69, 0x66, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x69,
+    0x6d, 0x70, 0x6c, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x03, 0x02, 0x08, 0x05, 0x12, 0x03, 0x54, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03,
+    0x02, 0x08, 0x01, 0x12, 0x03, 0x54, 0x09, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x08,
+    0x03, 0x12, 0x03, 0x54, 0x15, 0x16, 0x0a, 0x88, 0x01, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x09, 0x12,
+    0x03, 0x58, 0x02, 0x17, 0x1a, 0x7b, 0x20, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x20,
+    0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x4a, 0x53, 0x4f, 0x4e, 0x20, 0x65, 0x6d, 0x62, 0x65,
+    0x64, 0x64, 0x65, 0x64, 0x20, 0x69, 0x6e, 0x20, 0x60, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x5f, 0x75,
+    0x72, 0x69, 0x60, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x69, 0x74, 0x20, 0x69, 0x73, 0x20, 0x61,
+    0x20, 0x60, 0x64, 0x61, 0x74, 0x61, 0x3a, 0x60, 0x20, 0x55, 0x52, 0x49, 0x2c, 0x20, 0x65, 0x6d,
+    0x70, 0x74, 0x79, 0x20, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x77, 0x69, 0x73, 0x65, 0x20, 0x6f, 0x72,
+    0x20, 0x77, 0x68, 0x65, 0x6e, 0x0a, 0x20, 0x6c, 0x61, 0x72, 0x67, 0x65, 0x72, 0x20, 0x74, 0x68,
+    0x61, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x69, 0x7a, 0x65, 0x20, 0x63, 0x61, 0x70, 0x2e,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x09, 0x05, 0x12, 0x03, 0x58, 0x02, 0x08, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x09, 0x01, 0x12, 0x03, 0x58, 0x09, 0x11, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x03, 0x02, 0x09, 0x03, 0x12, 0x03, 0x58, 0x14, 0x16, 0x0a, 0x41, 0x0a, 0x04, 0x04,
+    0x03, 0x02, 0x0a, 0x12, 0x03, 0x5b, 0x02, 0x13, 0x1a, 0x34, 0x20, 0x4e, 0x61, 0x6d, 0x65, 0x20,
+    0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x60, 0x6e, 0x61, 0x6d, 0x65, 0x60, 0x20, 0x66, 0x69,
+    0x65, 0x6c, 0x64, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x6d, 0x62, 0x65, 0x64,
+    0x64, 0x65, 0x64, 0x20, 0x6d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x2e, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x03, 0x02, 0x0a, 0x05, 0x12, 0x03, 0x5b, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x03, 0x02, 0x0a, 0x01, 0x12, 0x03, 0x5b, 0x09, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03,
+    0x02, 0x0a, 0x03, 0x12, 0x03, 0x5b, 0x10, 0x12, 0x0a, 0x68, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x0b,
+    0x12, 0x03, 0x5e, 0x02, 0x14, 0x1a, 0x5b, 0x20, 0x49, 0x6d, 0x61, 0x67, 0x65, 0x20, 0x69, 0x73,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x60, 0x69, 0x6d, 0x61, 0x67, 0x65, 0x60, 0x20, 0x66, 0x69, 0x65,
+    0x6c, 0x64, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x6d, 0x62, 0x65, 0x64, 0x64,
+    0x65, 0x64, 0x20, 0x6d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x2c, 0x20, 0x65, 0x6d, 0x70,
+    0x74, 0x79, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x6c, 0x61, 0x72, 0x67, 0x65, 0x72, 0x20, 0x74,
+    0x68, 0x61, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x69, 0x7a, 0x65, 0x20, 0x63, 0x61, 0x70,
+    0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x0b, 0x05, 0x12, 0x03, 0x5e, 0x02, 0x08,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x0b, 0x01, 0x12, 0x03, 0x5e, 0x09, 0x0e, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x03, 0x02, 0x0b, 0x03, 0x12, 0x03, 0x5e, 0x11, 0x13, 0x0a, 0x56, 0x0a, 0x04,
+    0x04, 0x03, 0x02, 0x0c, 0x12, 0x03, 0x61, 0x02, 0x19, 0x1a, 0x49, 0x20, 0x41, 0x74, 0x74, 0x72,
+    0x69, 0x62, 0x75, 0x74, 0x65, 0x73, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x60, 0x61,
+    0x74, 0x74, 0x72, 0x69, 0x62, 0x75, 0x74, 0x65, 0x73, 0x60, 0x20, 0x66, 0x69, 0x65, 0x6c, 0x64,
+    0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x6d, 0x62, 0x65, 0x64, 0x64, 0x65, 0x64,
+    0x20, 0x6d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x2c, 0x20, 0x61, 0x73, 0x20, 0x4a, 0x53,
+    0x4f, 0x4e, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x0c, 0x05, 0x12, 0x03, 0x61,
+    0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x0c, 0x01, 0x12, 0x03, 0x61, 0x09, 0x13,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x0c, 0x03, 0x12, 0x03, 0x61, 0x16, 0x18, 0x0a, 0xdf,
+    0x01, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x0d, 0x12, 0x03, 0x66, 0x02, 0x1c, 0x1a, 0xd1, 0x01, 0x20,
+    0x43, 0x61, 0x6e, 0x6f, 0x6e, 0x69, 0x63, 0x61, 0x6c, 0x55, 0x52, 0x49, 0x20, 0x69, 0x73, 0x20,
+    0x60, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x5f, 0x75, 0x72, 0x69, 0x60, 0x20, 0x6e, 0x6f, 0x72, 0x6d,
+    0x61, 0x6c, 0x69, 0x7a, 0x65, 0x64, 0x20, 0x69, 0x6e, 0x74, 0x6f, 0x20, 0x61, 0x20, 0x72, 0x65,
+    0x73, 0x6f, 0x6c, 0x76, 0x61, 0x62, 0x6c, 0x65, 0x20, 0x66, 0x6f, 0x72, 0x6d, 0x3a, 0x20, 0x60,
+    0x69, 0x70, 0x66, 0x73, 0x3a, 0x2f, 0x2f, 0x3c, 0x63, 0x69, 0x64, 0x3e, 0x2f, 0x3c, 0x70, 0x61,
+    0x74, 0x68, 0x3e, 0x60, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x49, 0x50, 0x46, 0x53, 0x0a, 0x20, 0x55,
+    0x52, 0x49, 0x73, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x67, 0x61, 0x74, 0x65, 0x77, 0x61, 0x79, 0x20,
+    0x55, 0x52, 0x4c, 0x73, 0x2c, 0x20, 0x60, 0x68, 0x74, 0x74, 0x70, 0x73, 0x3a, 0x2f, 0x2f, 0x61,
+    0x72, 0x77, 0x65, 0x61, 0x76, 0x65, 0x2e, 0x6e, 0x65, 0x74, 0x2f, 0x3c, 0x69, 0x64, 0x3e, 0x60,
+    0x20, 0x66, 0x6f, 0x72, 0x20, 0x41, 0x72, 0x77, 0x65, 0x61, 0x76, 0x65, 0x20, 0x61, 0x6e, 0x64,
+    0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x74, 0x68, 0x65, 0x20, 0x45, 0x52, 0x43, 0x31, 0x31, 0x35,
+    0x35, 0x20, 0x60, 0x7b, 0x69, 0x64, 0x7d, 0x60, 0x0a, 0x20, 0x74, 0x65, 0x6d, 0x70, 0x6c, 0x61,
+    0x74, 0x65, 0x20, 0x73, 0x75, 0x62, 0x73, 0x74, 0x69, 0x74, 0x75, 0x74, 0x65, 0x64, 0x2e, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x0d, 0x05, 0x12, 0x03, 0x66, 0x02, 0x08, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x03, 0x02, 0x0d, 0x01, 0x12, 0x03, 0x66, 0x09, 0x16, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x03, 0x02, 0x0d, 0x03, 0x12, 0x03, 0x66, 0x19, 0x1b, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74,
+    0x6f, 0x33,
+];
+// @@protoc_insertion_point(module)
\ No newline at end of file
@@ -0,0 +1,196 @@
+// @generated
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OrderInvalidations {
+    #[prost(message, repeated, tag="1")]
+    pub order_invalidations: ::prost::alloc::vec::Vec<OrderInvalidation>,
+}
+/// OrderInvalidation is an on-chain action of a maker that makes some of its signed orders unfillable.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OrderInvalidation {
+    /// Marketplace is the name of the marketplace the orders belong to, e.g. `seaport`.
+    #[prost(string, tag="1")]
+    pub marketplace: ::prost::alloc::string::String,
+    /// Kind is the string representation of one of the enum defined in Kind, see `Transfer.schema`.
+    #[prost(string, tag="2")]
+    pub kind: ::prost::alloc::string::String,
+    /// Maker is the address that signed the invalidated orders.
+    #[prost(bytes="vec", tag="3")]
+    pub maker: ::prost::alloc::vec::Vec<u8>,
+    /// OrderHash is the hash of the cancelled order, only set when `kind = cancelled_order`.
+    #[prost(bytes="vec", tag="4")]
+    pub order_hash: ::prost::alloc::vec::Vec<u8>,
+    /// Nonce is the cancelled nonce or the new minimum nonce of the maker, empty when `kind = cancelled_order`.
+    #[prost(string, tag="5")]
+    pub nonce: ::prost::alloc::string::String,
+    /// TxHash is the hash of the transaction
+    #[prost(bytes="vec", tag="6")]
+    pub tx_hash: ::prost::alloc::vec::Vec<u8>,
+    /// Ordinal is the order of the invalidation within the block.
+    #[prost(uint64, tag="7")]
+    pub ordinal: u64,
+    #[prost(uint64, tag="8")]
+    pub block_number: u64,
+    /// Timestamp is the timestamp of the block.
+    #[prost(message, optional, tag="9")]
+    pub timestamp: ::core::option::Option<::prost_types::Timestamp>,
+}
+/// Nested message and enum types in `OrderInvalidation`.
+pub mod order_invalidation {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum Kind {
+        /// A single order, identified by `order_hash`, was cancelled.
+        CancelledOrder = 0,
+        /// Every order of the maker signed with exactly `nonce` was cancelled.
+        CancelledNonce = 1,
+        /// Every order of the maker signed with a nonce (or counter) lower than `nonce` was cancelled.
+        MinNonce = 2,
+    }
+}
+/// Encoded file descriptor set for the `eth.orders.v1` package
+pub const FILE_DESCRIPTOR_SET: &[u8] = &[
+    0x0a, 0xe1, 0x11, 0x0a, 0x0c, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x73, 0x2e, 0x70, 0x72, 0x6f, 0x74,
+    0x6f, 0x12, 0x0d, 0x65, 0x74, 0x68, 0x2e, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x73, 0x2e, 0x76, 0x31,
+    0x1a, 0x1f, 0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2f, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75,
+    0x66, 0x2f, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x2e, 0x70, 0x72, 0x6f, 0x74,
+    0x6f, 0x22, 0x67, 0x0a, 0x12, 0x4f, 0x72, 0x64, 0x65, 0x72, 0x49, 0x6e, 0x76, 0x61, 0x6c, 0x69,
+    0x64, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x12, 0x51, 0x0a, 0x13, 0x6f, 0x72, 0x64, 0x65, 0x72,
+    0x5f, 0x69, 0x6e, 0x76, 0x61, 0x6c, 0x69, 0x64, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x18, 0x01,
+    0x20, 0x03, 0x28, 0x0b, 0x32, 0x20, 0x2e, 0x65, 0x74, 0x68, 0x2e, 0x6f, 0x72, 0x64, 0x65, 0x72,
+    0x73, 0x2e, 0x76, 0x31, 0x2e, 0x4f, 0x72, 0x64, 0x65, 0x72, 0x49, 0x6e, 0x76, 0x61, 0x6c, 0x69,
+    0x64, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x52, 0x12, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x49, 0x6e, 0x76,
+    0x61, 0x6c, 0x69, 0x64, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x22, 0xe5, 0x02, 0x0a, 0x11, 0x4f,
+    0x72, 0x64, 0x65, 0x72, 0x49, 0x6e, 0x76, 0x61, 0x6c, 0x69, 0x64, 0x61, 0x74, 0x69, 0x6f, 0x6e,
+    0x12, 0x20, 0x0a, 0x0b, 0x6d, 0x61, 0x72, 0x6b, 0x65, 0x74, 0x70, 0x6c, 0x61, 0x63, 0x65, 0x18,
+    0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x6d, 0x61, 0x72, 0x6b, 0x65, 0x74, 0x70, 0x6c, 0x61,
+    0x63, 0x65, 0x12, 0x12, 0x0a, 0x04, 0x6b, 0x69, 0x6e, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09,
+    0x52, 0x04, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0x14, 0x0a, 0x05, 0x6d, 0x61, 0x6b, 0x65, 0x72, 0x18,
+    0x03, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x05, 0x6d, 0x61, 0x6b, 0x65, 0x72, 0x12, 0x1d, 0x0a, 0x0a,
+    0x6f, 0x72, 0x64, 0x65, 0x72, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0c,
+    0x52, 0x09, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x48, 0x61, 0x73, 0x68, 0x12, 0x14, 0x0a, 0x05, 0x6e,
+    0x6f, 0x6e, 0x63, 0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x09, 0x52, 0x05, 0x6e, 0x6f, 0x6e, 0x63,
+    0x65, 0x12, 0x17, 0x0a, 0x07, 0x74, 0x78, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x18, 0x06, 0x20, 0x01,
+    0x28, 0x0c, 0x52, 0x06, 0x74, 0x78, 0x48, 0x61, 0x73, 0x68, 0x12, 0x18, 0x0a, 0x07, 0x6f, 0x72,
+    0x64, 0x69, 0x6e, 0x61, 0x6c, 0x18, 0x07, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x6f, 0x72, 0x64,
+    0x69, 0x6e, 0x61, 0x6c, 0x12, 0x21, 0x0a, 0x0c, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x5f, 0x6e, 0x75,
+    0x6d, 0x62, 0x65, 0x72, 0x18, 0x08, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x62, 0x6c, 0x6f, 0x63,
+    0x6b, 0x4e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x12, 0x38, 0x0a, 0x09, 0x74, 0x69, 0x6d, 0x65, 0x73,
+    0x74, 0x61, 0x6d, 0x70, 0x18, 0x09, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1a, 0x2e, 0x67, 0x6f, 0x6f,
+    0x67, 0x6c, 0x65, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x2e, 0x54, 0x69, 0x6d,
+    0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x52, 0x09, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d,
+    0x70, 0x22, 0x3f, 0x0a, 0x04, 0x4b, 0x69, 0x6e, 0x64, 0x12, 0x13, 0x0a, 0x0f, 0x63, 0x61, 0x6e,
+    0x63, 0x65, 0x6c, 0x6c, 0x65, 0x64, 0x5f, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x10, 0x00, 0x12, 0x13,
+    0x0a, 0x0f, 0x63, 0x61, 0x6e, 0x63, 0x65, 0x6c, 0x6c, 0x65, 0x64, 0x5f, 0x6e, 0x6f, 0x6e, 0x63,
+    0x65, 0x10, 0x01, 0x12, 0x0d, 0x0a, 0x09, 0x6d, 0x69, 0x6e, 0x5f, 0x6e, 0x6f, 0x6e, 0x63, 0x65,
+    0x10, 0x02, 0x4a, 0xc7, 0x0d, 0x0a, 0x06, 0x12, 0x04, 0x00, 0x00, 0x2e, 0x01, 0x0a, 0x08, 0x0a,
+    0x01, 0x0c, 0x12, 0x03, 0x00, 0x00, 0x12, 0x0a, 0x08, 0x0a, 0x01, 0x02, 0x12, 0x03, 0x02, 0x00,
+    0x16, 0x0a, 0x09, 0x0a, 0x02, 0x03, 0x00, 0x12, 0x03, 0x04, 0x00, 0x29, 0x0a, 0x0a, 0x0a, 0x02,
+    0x04, 0x00, 0x12, 0x04, 0x06, 0x00, 0x08, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12,
+    0x03, 0x06, 0x08, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x07, 0x02,
+    0x35, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x04, 0x12, 0x03, 0x07, 0x02, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x06, 0x12, 0x03, 0x07, 0x0b, 0x1c, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x07, 0x1d, 0x30, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x07, 0x33, 0x34, 0x0a, 0x71, 0x0a, 0x02, 0x04, 0x01, 0x12,
+    0x04, 0x0b, 0x00, 0x2e, 0x01, 0x1a, 0x65, 0x20, 0x4f, 0x72, 0x64, 0x65, 0x72, 0x49, 0x6e, 0x76,
+    0x61, 0x6c, 0x69, 0x64, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x69, 0x73, 0x20, 0x61, 0x6e, 0x20,
+    0x6f, 0x6e, 0x2d, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x20,
+    0x6f, 0x66, 0x20, 0x61, 0x20, 0x6d, 0x61, 0x6b, 0x65, 0x72, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20,
+    0x6d, 0x61, 0x6b, 0x65, 0x73, 0x20, 0x73, 0x6f, 0x6d, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x69, 0x74,
+    0x73, 0x20, 0x73, 0x69, 0x67, 0x6e, 0x65, 0x64, 0x20, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x73, 0x20,
+    0x75, 0x6e, 0x66, 0x69, 0x6c, 0x6c, 0x61, 0x62, 0x6c, 0x65, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03,
+    0x04, 0x01, 0x01, 0x12, 0x03, 0x0b, 0x08, 0x19, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x01, 0x04, 0x00,
+    0x12, 0x04, 0x0c, 0x02, 0x13, 0x03, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x04, 0x00, 0x01, 0x12,
+    0x03, 0x0c, 0x07, 0x0b, 0x0a, 0x4b, 0x0a, 0x06, 0x04, 0x01, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03,
+    0x0e, 0x04, 0x18, 0x1a, 0x3c, 0x20, 0x41, 0x20, 0x73, 0x69, 0x6e, 0x67, 0x6c, 0x65, 0x20, 0x6f,
+    0x72, 0x64, 0x65, 0x72, 0x2c, 0x20, 0x69, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x66, 0x69, 0x65, 0x64,
+    0x20, 0x62, 0x79, 0x20, 0x60, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x60,
+    0x2c, 0x20, 0x77, 0x61, 0x73, 0x20, 0x63, 0x61, 0x6e, 0x63, 0x65, 0x6c, 0x6c, 0x65, 0x64, 0x2e,
+    0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x0e, 0x04,
+    0x13, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x0e, 0x16,
+    0x17, 0x0a, 0x54, 0x0a, 0x06, 0x04, 0x01, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x10, 0x04, 0x18,
+    0x1a, 0x45, 0x20, 0x45, 0x76, 0x65, 0x72, 0x79, 0x20, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x20, 0x6f,
+    0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6d, 0x61, 0x6b, 0x65, 0x72, 0x20, 0x73, 0x69, 0x67, 0x6e,
+    0x65, 0x64, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x65, 0x78, 0x61, 0x63, 0x74, 0x6c, 0x79, 0x20,
+    0x60, 0x6e, 0x6f, 0x6e, 0x63, 0x65, 0x60, 0x20, 0x77, 0x61, 0x73, 0x20, 0x63, 0x61, 0x6e, 0x63,
+    0x65, 0x6c, 0x6c, 0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02,
+    0x01, 0x01, 0x12, 0x03, 0x10, 0x04, 0x13, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02,
+    0x01, 0x02, 0x12, 0x03, 0x10, 0x16, 0x17, 0x0a, 0x6c, 0x0a, 0x06, 0x04, 0x01, 0x04, 0x00, 0x02,
+    0x02, 0x12, 0x03, 0x12, 0x04, 0x12, 0x1a, 0x5d, 0x20, 0x45, 0x76, 0x65, 0x72, 0x79, 0x20, 0x6f,
+    0x72, 0x64, 0x65, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6d, 0x61, 0x6b, 0x65,
+    0x72, 0x20, 0x73, 0x69, 0x67, 0x6e, 0x65, 0x64, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x61, 0x20,
+    0x6e, 0x6f, 0x6e, 0x63, 0x65, 0x20, 0x28, 0x6f, 0x72, 0x20, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x65,
+    0x72, 0x29, 0x20, 0x6c, 0x6f, 0x77, 0x65, 0x72, 0x20, 0x74, 0x68, 0x61, 0x6e, 0x20, 0x60, 0x6e,
+    0x6f, 0x6e, 0x63, 0x65, 0x60, 0x20, 0x77, 0x61, 0x73, 0x20, 0x63, 0x61, 0x6e, 0x63, 0x65, 0x6c,
+    0x6c, 0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x02, 0x01,
+    0x12, 0x03, 0x12, 0x04, 0x0d, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x02, 0x02,
+    0x12, 0x03, 0x12, 0x10, 0x11, 0x0a, 0x5f, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x00, 0x12, 0x03, 0x16,
+    0x02, 0x19, 0x1a, 0x52, 0x20, 0x4d, 0x61, 0x72, 0x6b, 0x65, 0x74, 0x70, 0x6c, 0x61, 0x63, 0x65,
+    0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6e, 0x61, 0x6d, 0x65, 0x20, 0x6f, 0x66, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x6d, 0x61, 0x72, 0x6b, 0x65, 0x74, 0x70, 0x6c, 0x61, 0x63, 0x65, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x73, 0x20, 0x62, 0x65, 0x6c, 0x6f, 0x6e,
+    0x67, 0x20, 0x74, 0x6f, 0x2c, 0x20, 0x65, 0x2e, 0x67, 0x2e, 0x20, 0x60, 0x73, 0x65, 0x61, 0x70,
+    0x6f, 0x72, 0x74, 0x60, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x05, 0x12,
+    0x03, 0x16, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x16,
+    0x09, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x16, 0x17, 0x18,
+    0x0a, 0x6b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x01, 0x12, 0x03, 0x19, 0x02, 0x12, 0x1a, 0x5e, 0x20,
+    0x4b, 0x69, 0x6e, 0x64, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x74, 0x72, 0x69,
+    0x6e, 0x67, 0x20, 0x72, 0x65, 0x70, 0x72, 0x65, 0x73, 0x65, 0x6e, 0x74, 0x61, 0x74, 0x69, 0x6f,
+    0x6e, 0x20, 0x6f, 0x66, 0x20, 0x6f, 0x6e, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x65, 0x6e, 0x75, 0x6d, 0x20, 0x64, 0x65, 0x66, 0x69, 0x6e, 0x65, 0x64, 0x20, 0x69, 0x6e, 0x20,
+    0x4b, 0x69, 0x6e, 0x64, 0x2c, 0x20, 0x73, 0x65, 0x65, 0x20, 0x60, 0x54, 0x72, 0x61, 0x6e, 0x73,
+    0x66, 0x65, 0x72, 0x2e, 0x73, 0x63, 0x68, 0x65, 0x6d, 0x61, 0x60, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x01, 0x02, 0x01, 0x05, 0x12, 0x03, 0x19, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x19, 0x09, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
+    0x01, 0x03, 0x12, 0x03, 0x19, 0x10, 0x11, 0x0a, 0x47, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x02, 0x12,
+    0x03, 0x1c, 0x02, 0x12, 0x1a, 0x3a, 0x20, 0x4d, 0x61, 0x6b, 0x65, 0x72, 0x20, 0x69, 0x73, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x20, 0x74, 0x68, 0x61, 0x74,
+    0x20, 0x73, 0x69, 0x67, 0x6e, 0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x69, 0x6e, 0x76, 0x61,
+    0x6c, 0x69, 0x64, 0x61, 0x74, 0x65, 0x64, 0x20, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x73, 0x2e, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x05, 0x12, 0x03, 0x1c, 0x02, 0x07, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x01, 0x12, 0x03, 0x1c, 0x08, 0x0d, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x01, 0x02, 0x02, 0x03, 0x12, 0x03, 0x1c, 0x10, 0x11, 0x0a, 0x64, 0x0a, 0x04, 0x04, 0x01,
+    0x02, 0x03, 0x12, 0x03, 0x1f, 0x02, 0x17, 0x1a, 0x57, 0x20, 0x4f, 0x72, 0x64, 0x65, 0x72, 0x48,
+    0x61, 0x73, 0x68, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x68, 0x61, 0x73, 0x68, 0x20,
+    0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x61, 0x6e, 0x63, 0x65, 0x6c, 0x6c, 0x65, 0x64,
+    0x20, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x2c, 0x20, 0x6f, 0x6e, 0x6c, 0x79, 0x20, 0x73, 0x65, 0x74,
+    0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x60, 0x6b, 0x69, 0x6e, 0x64, 0x20, 0x3d, 0x20, 0x63, 0x61,
+    0x6e, 0x63, 0x65, 0x6c, 0x6c, 0x65, 0x64, 0x5f, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x60, 0x2e, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x03, 0x05, 0x12, 0x03, 0x1f, 0x02, 0x07, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x01, 0x02, 0x03, 0x01, 0x12, 0x03, 0x1f, 0x08, 0x12, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x01, 0x02, 0x03, 0x03, 0x12, 0x03, 0x1f, 0x15, 0x16, 0x0a, 0x77, 0x0a, 0x04, 0x04, 0x01,
+    0x02, 0x04, 0x12, 0x03, 0x22, 0x02, 0x13, 0x1a, 0x6a, 0x20, 0x4e, 0x6f, 0x6e, 0x63, 0x65, 0x20,
+    0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x61, 0x6e, 0x63, 0x65, 0x6c, 0x6c, 0x65, 0x64,
+    0x20, 0x6e, 0x6f, 0x6e, 0x63, 0x65, 0x20, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6e, 0x65,
+    0x77, 0x20, 0x6d, 0x69, 0x6e, 0x69, 0x6d, 0x75, 0x6d, 0x20, 0x6e, 0x6f, 0x6e, 0x63, 0x65, 0x20,
+    0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6d, 0x61, 0x6b, 0x65, 0x72, 0x2c, 0x20, 0x65, 0x6d,
+    0x70, 0x74, 0x79, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x60, 0x6b, 0x69, 0x6e, 0x64, 0x20, 0x3d,
+    0x20, 0x63, 0x61, 0x6e, 0x63, 0x65, 0x6c, 0x6c, 0x65, 0x64, 0x5f, 0x6f, 0x72, 0x64, 0x65, 0x72,
+    0x60, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x04, 0x05, 0x12, 0x03, 0x22, 0x02,
+    0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x04, 0x01, 0x12, 0x03, 0x22, 0x09, 0x0e, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x04, 0x03, 0x12, 0x03, 0x22, 0x11, 0x12, 0x0a, 0x34, 0x0a,
+    0x04, 0x04, 0x01, 0x02, 0x05, 0x12, 0x03, 0x25, 0x02, 0x14, 0x1a, 0x27, 0x20, 0x54, 0x78, 0x48,
+    0x61, 0x73, 0x68, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x68, 0x61, 0x73, 0x68, 0x20,
+    0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69,
+    0x6f, 0x6e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x05, 0x05, 0x12, 0x03, 0x25, 0x02,
+    0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x05, 0x01, 0x12, 0x03, 0x25, 0x08, 0x0f, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x05, 0x03, 0x12, 0x03, 0x25, 0x12, 0x13, 0x0a, 0x49, 0x0a,
+    0x04, 0x04, 0x01, 0x02, 0x06, 0x12, 0x03, 0x28, 0x02, 0x15, 0x1a, 0x3c, 0x20, 0x4f, 0x72, 0x64,
+    0x69, 0x6e, 0x61, 0x6c, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x64, 0x65,
+    0x72, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x69, 0x6e, 0x76, 0x61, 0x6c, 0x69, 0x64,
+    0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x77, 0x69, 0x74, 0x68, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x06,
+    0x05, 0x12, 0x03, 0x28, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x06, 0x01, 0x12,
+    0x03, 0x28, 0x09, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x06, 0x03, 0x12, 0x03, 0x28,
+    0x13, 0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x07, 0x12, 0x03, 0x2a, 0x02, 0x1a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x07, 0x05, 0x12, 0x03, 0x2a, 0x02, 0x08, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x01, 0x02, 0x07, 0x01, 0x12, 0x03, 0x2a, 0x09, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x01, 0x02, 0x07, 0x03, 0x12, 0x03, 0x2a, 0x18, 0x19, 0x0a, 0x37, 0x0a, 0x04, 0x04, 0x01, 0x02,
+    0x08, 0x12, 0x03, 0x2d, 0x02, 0x2a, 0x1a, 0x2a, 0x20, 0x54, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61,
+    0x6d, 0x70, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74,
+    0x61, 0x6d, 0x70, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b,
+    0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x08, 0x06, 0x12, 0x03, 0x2d, 0x02, 0x1b,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x08, 0x01, 0x12, 0x03, 0x2d, 0x1c, 0x25, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x01, 0x02, 0x08, 0x03, 0x12, 0x03, 0x2d, 0x28, 0x29, 0x62, 0x06, 0x70, 0x72,
+    0x6f, 0x74, 0x6f, 0x33,
+];
+// @@protoc_insertion_point(module)
\ No newline at end of file
@@ -0,0 +1,453 @@
+// @generated
+/// Ownership is the current owner of an ERC721 token along with the transfer that gave it the token.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Ownership {
+    /// ContractAddress is the address of the smart contract that is the token contract.
+    #[prost(bytes="vec", tag="1")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+    /// TokenID the identifier of the token within `contract_address`.
+    #[prost(bytes="vec", tag="2")]
+    pub token_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="3")]
+    pub owner: ::prost::alloc::vec::Vec<u8>,
+    /// TxHash is the hash of the transaction of the transfer to `owner`.
+    #[prost(bytes="vec", tag="4")]
+    pub tx_hash: ::prost::alloc::vec::Vec<u8>,
+    /// Ordinal is the order of the transfer to `owner` within the block.
+    #[prost(uint64, tag="5")]
+    pub ordinal: u64,
+    #[prost(uint64, tag="6")]
+    pub block_number: u64,
+    /// Timestamp is the timestamp of the block.
+    #[prost(message, optional, tag="7")]
+    pub timestamp: ::core::option::Option<::prost_types::Timestamp>,
+    /// PreviousOwner is the sender of the transfer to `owner`, the zero address for a mint.
+    #[prost(bytes="vec", tag="8")]
+    pub previous_owner: ::prost::alloc::vec::Vec<u8>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OwnershipChanges {
+    #[prost(message, repeated, tag="1")]
+    pub ownership_changes: ::prost::alloc::vec::Vec<OwnershipChange>,
+}
+/// OwnershipChange is an ERC721 token leaving the wallet of `previous_owner` for the one of `new_owner`.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OwnershipChange {
+    /// ContractAddress is the address of the smart contract that is the token contract.
+    #[prost(bytes="vec", tag="1")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+    /// TokenID the identifier of the token within `contract_address`.
+    #[prost(bytes="vec", tag="2")]
+    pub token_id: ::prost::alloc::vec::Vec<u8>,
+    /// PreviousOwner is the owner before the transfer, the zero address for a mint.
+    #[prost(bytes="vec", tag="3")]
+    pub previous_owner: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="4")]
+    pub new_owner: ::prost::alloc::vec::Vec<u8>,
+    /// TxHash is the hash of the transaction
+    #[prost(bytes="vec", tag="5")]
+    pub tx_hash: ::prost::alloc::vec::Vec<u8>,
+    /// Ordinal is the order of the transfer within the block.
+    #[prost(uint64, tag="6")]
+    pub ordinal: u64,
+    #[prost(uint64, tag="7")]
+    pub block_number: u64,
+    /// Timestamp is the timestamp of the block.
+    #[prost(message, optional, tag="8")]
+    pub timestamp: ::core::option::Option<::prost_types::Timestamp>,
+}
+/// NftUser is the ERC-4907 user of a rentable ERC721 token, distinct from its owner.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NftUser {
+    /// ContractAddress is the address of the smart contract that is the token contract.
+    #[prost(bytes="vec", tag="1")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+    /// TokenID the identifier of the token within `contract_address`.
+    #[prost(bytes="vec", tag="2")]
+    pub token_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="3")]
+    pub user: ::prost::alloc::vec::Vec<u8>,
+    /// Expires is the unix timestamp, in seconds, at which `user` loses its rights on the token.
+    #[prost(uint64, tag="4")]
+    pub expires: u64,
+    #[prost(uint64, tag="5")]
+    pub block_number: u64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TokenBoundAccounts {
+    #[prost(message, repeated, tag="1")]
+    pub token_bound_accounts: ::prost::alloc::vec::Vec<TokenBoundAccount>,
+}
+/// TokenBoundAccount is an ERC-6551 account owned by an NFT, created through an ERC-6551 registry.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TokenBoundAccount {
+    /// Account is the address of the created account.
+    #[prost(bytes="vec", tag="1")]
+    pub account: ::prost::alloc::vec::Vec<u8>,
+    /// Implementation is the address of the contract the account proxies to.
+    #[prost(bytes="vec", tag="2")]
+    pub implementation: ::prost::alloc::vec::Vec<u8>,
+    /// ChainId is the chain on which the NFT owning the account lives, in decimal. It is any uint256.
+    #[prost(string, tag="3")]
+    pub chain_id: ::prost::alloc::string::String,
+    /// TokenContract is the address of the contract of the NFT owning the account.
+    #[prost(bytes="vec", tag="4")]
+    pub token_contract: ::prost::alloc::vec::Vec<u8>,
+    /// TokenID the identifier of the NFT owning the account within `token_contract`.
+    #[prost(bytes="vec", tag="5")]
+    pub token_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub salt: ::prost::alloc::vec::Vec<u8>,
+    /// Registry is the address of the ERC-6551 registry that created the account.
+    #[prost(bytes="vec", tag="7")]
+    pub registry: ::prost::alloc::vec::Vec<u8>,
+    /// TxHash is the hash of the transaction
+    #[prost(bytes="vec", tag="8")]
+    pub tx_hash: ::prost::alloc::vec::Vec<u8>,
+    /// Ordinal is the order of the creation within the block.
+    #[prost(uint64, tag="9")]
+    pub ordinal: u64,
+    #[prost(uint64, tag="10")]
+    pub block_number: u64,
+    /// Timestamp is the timestamp of the block.
+    #[prost(message, optional, tag="11")]
+    pub timestamp: ::core::option::Option<::prost_types::Timestamp>,
+}
+/// Encoded file descriptor set for the `eth.ownership.v1` package
+pub const FILE_DESCRIPTOR_SET: &[u8] = &[
+    0x0a, 0xce, 0x29, 0x0a, 0x0f, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x73, 0x68, 0x69, 0x70, 0x2e, 0x70,
+    0x72, 0x6f, 0x74, 0x6f, 0x12, 0x10, 0x65, 0x74, 0x68, 0x2e, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x73,
+    0x68, 0x69, 0x70, 0x2e, 0x76, 0x31, 0x1a, 0x1f, 0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2f, 0x70,
+    0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x2f, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d,
+    0x70, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x22, 0xf7, 0x01, 0x0a, 0x09, 0x4f, 0x77, 0x6e, 0x65,
+    0x72, 0x73, 0x68, 0x69, 0x70, 0x12, 0x29, 0x0a, 0x10, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63,
+    0x74, 0x5f, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52,
+    0x0f, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x41, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73,
+    0x12, 0x19, 0x0a, 0x08, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01,
+    0x28, 0x0c, 0x52, 0x07, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x49, 0x64, 0x12, 0x14, 0x0a, 0x05, 0x6f,
+    0x77, 0x6e, 0x65, 0x72, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x05, 0x6f, 0x77, 0x6e, 0x65,
+    0x72, 0x12, 0x17, 0x0a, 0x07, 0x74, 0x78, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x18, 0x04, 0x20, 0x01,
+    0x28, 0x0c, 0x52, 0x06, 0x74, 0x78, 0x48, 0x61, 0x73, 0x68, 0x12, 0x18, 0x0a, 0x07, 0x6f, 0x72,
+    0x64, 0x69, 0x6e, 0x61, 0x6c, 0x18, 0x05, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x6f, 0x72, 0x64,
+    0x69, 0x6e, 0x61, 0x6c, 0x12, 0x21, 0x0a, 0x0c, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x5f, 0x6e, 0x75,
+    0x6d, 0x62, 0x65, 0x72, 0x18, 0x06, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x62, 0x6c, 0x6f, 0x63,
+    0x6b, 0x4e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x12, 0x38, 0x0a, 0x09, 0x74, 0x69, 0x6d, 0x65, 0x73,
+    0x74, 0x61, 0x6d, 0x70, 0x18, 0x07, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1a, 0x2e, 0x67, 0x6f, 0x6f,
+    0x67, 0x6c, 0x65, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x2e, 0x54, 0x69, 0x6d,
+    0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x52, 0x09, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d,
+    0x70, 0x22, 0x62, 0x0a, 0x10, 0x4f, 0x77, 0x6e, 0x65, 0x72, 0x73, 0x68, 0x69, 0x70, 0x43, 0x68,
+    0x61, 0x6e, 0x67, 0x65, 0x73, 0x12, 0x4e, 0x0a, 0x11, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x73, 0x68,
+    0x69, 0x70, 0x5f, 0x63, 0x68, 0x61, 0x6e, 0x67, 0x65, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b,
+    0x32, 0x21, 0x2e, 0x65, 0x74, 0x68, 0x2e, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x73, 0x68, 0x69, 0x70,
+    0x2e, 0x76, 0x31, 0x2e, 0x4f, 0x77, 0x6e, 0x65, 0x72, 0x73, 0x68, 0x69, 0x70, 0x43, 0x68, 0x61,
+    0x6e, 0x67, 0x65, 0x52, 0x10, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x73, 0x68, 0x69, 0x70, 0x43, 0x68,
+    0x61, 0x6e, 0x67, 0x65, 0x73, 0x22, 0xab, 0x02, 0x0a, 0x0f, 0x4f, 0x77, 0x6e, 0x65, 0x72, 0x73,
+    0x68, 0x69, 0x70, 0x43, 0x68, 0x61, 0x6e, 0x67, 0x65, 0x12, 0x29, 0x0a, 0x10, 0x63, 0x6f, 0x6e,
+    0x74, 0x72, 0x61, 0x63, 0x74, 0x5f, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x18, 0x01, 0x20,
+    0x01, 0x28, 0x0c, 0x52, 0x0f, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x41, 0x64, 0x64,
+    0x72, 0x65, 0x73, 0x73, 0x12, 0x19, 0x0a, 0x08, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x5f, 0x69, 0x64,
+    0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x49, 0x64, 0x12,
+    0x25, 0x0a, 0x0e, 0x70, 0x72, 0x65, 0x76, 0x69, 0x6f, 0x75, 0x73, 0x5f, 0x6f, 0x77, 0x6e, 0x65,
+    0x72, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x0d, 0x70, 0x72, 0x65, 0x76, 0x69, 0x6f, 0x75,
+    0x73, 0x4f, 0x77, 0x6e, 0x65, 0x72, 0x12, 0x1b, 0x0a, 0x09, 0x6e, 0x65, 0x77, 0x5f, 0x6f, 0x77,
+    0x6e, 0x65, 0x72, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x08, 0x6e, 0x65, 0x77, 0x4f, 0x77,
+    0x6e, 0x65, 0x72, 0x12, 0x17, 0x0a, 0x07, 0x74, 0x78, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x18, 0x05,
+    0x20, 0x01, 0x28, 0x0c, 0x52, 0x06, 0x74, 0x78, 0x48, 0x61, 0x73, 0x68, 0x12, 0x18, 0x0a, 0x07,
+    0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x18, 0x06, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x6f,
+    0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x12, 0x21, 0x0a, 0x0c, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x5f,
+    0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x18, 0x07, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x62, 0x6c,
+    0x6f, 0x63, 0x6b, 0x4e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x12, 0x38, 0x0a, 0x09, 0x74, 0x69, 0x6d,
+    0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x18, 0x08, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1a, 0x2e, 0x67,
+    0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x2e, 0x54,
+    0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x52, 0x09, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74,
+    0x61, 0x6d, 0x70, 0x22, 0xa0, 0x01, 0x0a, 0x07, 0x4e, 0x66, 0x74, 0x55, 0x73, 0x65, 0x72, 0x12,
+    0x29, 0x0a, 0x10, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x5f, 0x61, 0x64, 0x64, 0x72,
+    0x65, 0x73, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x0f, 0x63, 0x6f, 0x6e, 0x74, 0x72,
+    0x61, 0x63, 0x74, 0x41, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x12, 0x19, 0x0a, 0x08, 0x74, 0x6f,
+    0x6b, 0x65, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x74, 0x6f,
+    0x6b, 0x65, 0x6e, 0x49, 0x64, 0x12, 0x12, 0x0a, 0x04, 0x75, 0x73, 0x65, 0x72, 0x18, 0x03, 0x20,
+    0x01, 0x28, 0x0c, 0x52, 0x04, 0x75, 0x73, 0x65, 0x72, 0x12, 0x18, 0x0a, 0x07, 0x65, 0x78, 0x70,
+    0x69, 0x72, 0x65, 0x73, 0x18, 0x04, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x65, 0x78, 0x70, 0x69,
+    0x72, 0x65, 0x73, 0x12, 0x21, 0x0a, 0x0c, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x5f, 0x6e, 0x75, 0x6d,
+    0x62, 0x65, 0x72, 0x18, 0x05, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x62, 0x6c, 0x6f, 0x63, 0x6b,
+    0x4e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x22, 0x6b, 0x0a, 0x12, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x42,
+    0x6f, 0x75, 0x6e, 0x64, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x73, 0x12, 0x55, 0x0a, 0x14,
+    0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x5f, 0x62, 0x6f, 0x75, 0x6e, 0x64, 0x5f, 0x61, 0x63, 0x63, 0x6f,
+    0x75, 0x6e, 0x74, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x23, 0x2e, 0x65, 0x74, 0x68,
+    0x2e, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x73, 0x68, 0x69, 0x70, 0x2e, 0x76, 0x31, 0x2e, 0x54, 0x6f,
+    0x6b, 0x65, 0x6e, 0x42, 0x6f, 0x75, 0x6e, 0x64, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x52,
+    0x12, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x42, 0x6f, 0x75, 0x6e, 0x64, 0x41, 0x63, 0x63, 0x6f, 0x75,
+    0x6e, 0x74, 0x73, 0x22, 0xf2, 0x02, 0x0a, 0x11, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x42, 0x6f, 0x75,
+    0x6e, 0x64, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x12, 0x18, 0x0a, 0x07, 0x61, 0x63, 0x63,
+    0x6f, 0x75, 0x6e, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x61, 0x63, 0x63, 0x6f,
+    0x75, 0x6e, 0x74, 0x12, 0x26, 0x0a, 0x0e, 0x69, 0x6d, 0x70, 0x6c, 0x65, 0x6d, 0x65, 0x6e, 0x74,
+    0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x0e, 0x69, 0x6d, 0x70,
+    0x6c, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x19, 0x0a, 0x08, 0x63,
+    0x68, 0x61, 0x69, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x63,
+    0x68, 0x61, 0x69, 0x6e, 0x49, 0x64, 0x12, 0x25, 0x0a, 0x0e, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x5f,
+    0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x0d,
+    0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x43, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x12, 0x19, 0x0a,
+    0x08, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0c, 0x52,
+    0x07, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x49, 0x64, 0x12, 0x12, 0x0a, 0x04, 0x73, 0x61, 0x6c, 0x74,
+    0x18, 0x06, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x04, 0x73, 0x61, 0x6c, 0x74, 0x12, 0x1a, 0x0a, 0x08,
+    0x72, 0x65, 0x67, 0x69, 0x73, 0x74, 0x72, 0x79, 0x18, 0x07, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x08,
+    0x72, 0x65, 0x67, 0x69, 0x73, 0x74, 0x72, 0x79, 0x12, 0x17, 0x0a, 0x07, 0x74, 0x78, 0x5f, 0x68,
+    0x61, 0x73, 0x68, 0x18, 0x08, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x06, 0x74, 0x78, 0x48, 0x61, 0x73,
+    0x68, 0x12, 0x18, 0x0a, 0x07, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x18, 0x09, 0x20, 0x01,
+    0x28, 0x04, 0x52, 0x07, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x12, 0x21, 0x0a, 0x0c, 0x62,
+    0x6c, 0x6f, 0x63, 0x6b, 0x5f, 0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x18, 0x0a, 0x20, 0x01, 0x28,
+    0x04, 0x52, 0x0b, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x4e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x12, 0x38,
+    0x0a, 0x09, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x18, 0x0b, 0x20, 0x01, 0x28,
+    0x0b, 0x32, 0x1a, 0x2e, 0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f,
+    0x62, 0x75, 0x66, 0x2e, 0x54, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x52, 0x09, 0x74,
+    0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x4a, 0xee, 0x1e, 0x0a, 0x06, 0x12, 0x04, 0x00,
+    0x00, 0x6d, 0x01, 0x0a, 0x08, 0x0a, 0x01, 0x0c, 0x12, 0x03, 0x00, 0x00, 0x12, 0x0a, 0x08, 0x0a,
+    0x01, 0x02, 0x12, 0x03, 0x02, 0x00, 0x19, 0x0a, 0x09, 0x0a, 0x02, 0x03, 0x00, 0x12, 0x03, 0x04,
+    0x00, 0x29, 0x0a, 0x6f, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x07, 0x00, 0x1a, 0x01, 0x1a, 0x63,
+    0x20, 0x4f, 0x77, 0x6e, 0x65, 0x72, 0x73, 0x68, 0x69, 0x70, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x63, 0x75, 0x72, 0x72, 0x65, 0x6e, 0x74, 0x20, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x20,
+    0x6f, 0x66, 0x20, 0x61, 0x6e, 0x20, 0x45, 0x52, 0x43, 0x37, 0x32, 0x31, 0x20, 0x74, 0x6f, 0x6b,
+    0x65, 0x6e, 0x20, 0x61, 0x6c, 0x6f, 0x6e, 0x67, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20,
+    0x67, 0x61, 0x76, 0x65, 0x20, 0x69, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65,
+    0x6e, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x07, 0x08, 0x11, 0x0a,
+    0x5f, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x09, 0x02, 0x1d, 0x1a, 0x52, 0x20, 0x43,
+    0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x41, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x20, 0x69,
+    0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x20, 0x6f, 0x66,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x6d, 0x61, 0x72, 0x74, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72,
+    0x61, 0x63, 0x74, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x2e, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x05, 0x12, 0x03, 0x09, 0x02, 0x07, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x09, 0x08, 0x18, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x09, 0x1b, 0x1c, 0x0a, 0x4d, 0x0a, 0x04, 0x04, 0x00,
+    0x02, 0x01, 0x12, 0x03, 0x0c, 0x02, 0x15, 0x1a, 0x40, 0x20, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x49,
+    0x44, 0x20, 0x74, 0x68, 0x65, 0x20, 0x69, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72,
+    0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x77, 0x69,
+    0x74, 0x68, 0x69, 0x6e, 0x20, 0x60, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x5f, 0x61,
+    0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x60, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
+    0x01, 0x05, 0x12, 0x03, 0x0c, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x01,
+    0x12, 0x03, 0x0c, 0x08, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x03, 0x12, 0x03,
+    0x0c, 0x13, 0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x0e, 0x02, 0x12,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x05, 0x12, 0x03, 0x0e, 0x02, 0x07, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x0e, 0x08, 0x0d, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x00, 0x02, 0x02, 0x03, 0x12, 0x03, 0x0e, 0x10, 0x11, 0x0a, 0x50, 0x0a, 0x04, 0x04, 0x00,
+    0x02, 0x03, 0x12, 0x03, 0x11, 0x02, 0x14, 0x1a, 0x43, 0x20, 0x54, 0x78, 0x48, 0x61, 0x73, 0x68,
+    0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x68, 0x61, 0x73, 0x68, 0x20, 0x6f, 0x66, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x20,
+    0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x20,
+    0x74, 0x6f, 0x20, 0x60, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x60, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x00, 0x02, 0x03, 0x05, 0x12, 0x03, 0x11, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
+    0x02, 0x03, 0x01, 0x12, 0x03, 0x11, 0x08, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03,
+    0x03, 0x12, 0x03, 0x11, 0x12, 0x13, 0x0a, 0x50, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x04, 0x12, 0x03,
+    0x14, 0x02, 0x15, 0x1a, 0x43, 0x20, 0x4f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x20, 0x69, 0x73,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x20, 0x74, 0x6f, 0x20, 0x60, 0x6f,
+    0x77, 0x6e, 0x65, 0x72, 0x60, 0x20, 0x77, 0x69, 0x74, 0x68, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04,
+    0x05, 0x12, 0x03, 0x14, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x01, 0x12,
+    0x03, 0x14, 0x09, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x03, 0x12, 0x03, 0x14,
+    0x13, 0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x05, 0x12, 0x03, 0x16, 0x02, 0x1a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x05, 0x05, 0x12, 0x03, 0x16, 0x02, 0x08, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x00, 0x02, 0x05, 0x01, 0x12, 0x03, 0x16, 0x09, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x00, 0x02, 0x05, 0x03, 0x12, 0x03, 0x16, 0x18, 0x19, 0x0a, 0x37, 0x0a, 0x04, 0x04, 0x00, 0x02,
+    0x06, 0x12, 0x03, 0x19, 0x02, 0x2a, 0x1a, 0x2a, 0x20, 0x54, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61,
+    0x6d, 0x70, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74,
+    0x61, 0x6d, 0x70, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b,
+    0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x06, 0x06, 0x12, 0x03, 0x19, 0x02, 0x1b,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x06, 0x01, 0x12, 0x03, 0x19, 0x1c, 0x25, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x00, 0x02, 0x06, 0x03, 0x12, 0x03, 0x19, 0x28, 0x29, 0x0a, 0x0a, 0x0a, 0x02,
+    0x04, 0x01, 0x12, 0x04, 0x1c, 0x00, 0x1e, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01, 0x12,
+    0x03, 0x1c, 0x08, 0x18, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x00, 0x12, 0x03, 0x1d, 0x02,
+    0x31, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x04, 0x12, 0x03, 0x1d, 0x02, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x06, 0x12, 0x03, 0x1d, 0x0b, 0x1a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x1d, 0x1b, 0x2c, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x1d, 0x2f, 0x30, 0x0a, 0x73, 0x0a, 0x02, 0x04, 0x02, 0x12,
+    0x04, 0x21, 0x00, 0x37, 0x01, 0x1a, 0x67, 0x20, 0x4f, 0x77, 0x6e, 0x65, 0x72, 0x73, 0x68, 0x69,
+    0x70, 0x43, 0x68, 0x61, 0x6e, 0x67, 0x65, 0x20, 0x69, 0x73, 0x20, 0x61, 0x6e, 0x20, 0x45, 0x52,
+    0x43, 0x37, 0x32, 0x31, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x6c, 0x65, 0x61, 0x76, 0x69,
+    0x6e, 0x67, 0x20, 0x74, 0x68, 0x65, 0x20, 0x77, 0x61, 0x6c, 0x6c, 0x65, 0x74, 0x20, 0x6f, 0x66,
+    0x20, 0x60, 0x70, 0x72, 0x65, 0x76, 0x69, 0x6f, 0x75, 0x73, 0x5f, 0x6f, 0x77, 0x6e, 0x65, 0x72,
+    0x60, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x6e, 0x65, 0x20, 0x6f, 0x66,
+    0x20, 0x60, 0x6e, 0x65, 0x77, 0x5f, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x60, 0x2e, 0x0a, 0x0a, 0x0a,
+    0x0a, 0x03, 0x04, 0x02, 0x01, 0x12, 0x03, 0x21, 0x08, 0x17, 0x0a, 0x5f, 0x0a, 0x04, 0x04, 0x02,
+    0x02, 0x00, 0x12, 0x03, 0x23, 0x02, 0x1d, 0x1a, 0x52, 0x20, 0x43, 0x6f, 0x6e, 0x74, 0x72, 0x61,
+    0x63, 0x74, 0x41, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x73, 0x6d, 0x61, 0x72, 0x74, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x20, 0x74,
+    0x68, 0x61, 0x74, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e,
+    0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x02, 0x02, 0x00, 0x05, 0x12, 0x03, 0x23, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02,
+    0x00, 0x01, 0x12, 0x03, 0x23, 0x08, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x03,
+    0x12, 0x03, 0x23, 0x1b, 0x1c, 0x0a, 0x4d, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x01, 0x12, 0x03, 0x26,
+    0x02, 0x15, 0x1a, 0x40, 0x20, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x49, 0x44, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x69, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x77, 0x69, 0x74, 0x68, 0x69, 0x6e, 0x20,
+    0x60, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x5f, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73,
+    0x73, 0x60, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x05, 0x12, 0x03, 0x26,
+    0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x01, 0x12, 0x03, 0x26, 0x08, 0x10,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x03, 0x12, 0x03, 0x26, 0x13, 0x14, 0x0a, 0x6a,
+    0x0a, 0x04, 0x04, 0x02, 0x02, 0x02, 0x12, 0x03, 0x29, 0x02, 0x1b, 0x1a, 0x5d, 0x20, 0x50, 0x72,
+    0x65, 0x76, 0x69, 0x6f, 0x75, 0x73, 0x4f, 0x77, 0x6e, 0x65, 0x72, 0x20, 0x69, 0x73, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x20, 0x62, 0x65, 0x66, 0x6f, 0x72, 0x65, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x2c, 0x20, 0x65, 0x6d,
+    0x70, 0x74, 0x79, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b,
+    0x65, 0x6e, 0x20, 0x77, 0x61, 0x73, 0x20, 0x6e, 0x65, 0x76, 0x65, 0x72, 0x20, 0x73, 0x65, 0x65,
+    0x6e, 0x20, 0x62, 0x65, 0x66, 0x6f, 0x72, 0x65, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02,
+    0x02, 0x02, 0x05, 0x12, 0x03, 0x29, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x02,
+    0x01, 0x12, 0x03, 0x29, 0x08, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x02, 0x03, 0x12,
+    0x03, 0x29, 0x19, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x03, 0x12, 0x03, 0x2b, 0x02,
+    0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x03, 0x05, 0x12, 0x03, 0x2b, 0x02, 0x07, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x03, 0x01, 0x12, 0x03, 0x2b, 0x08, 0x11, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x02, 0x02, 0x03, 0x03, 0x12, 0x03, 0x2b, 0x14, 0x15, 0x0a, 0x34, 0x0a, 0x04, 0x04,
+    0x02, 0x02, 0x04, 0x12, 0x03, 0x2e, 0x02, 0x14, 0x1a, 0x27, 0x20, 0x54, 0x78, 0x48, 0x61, 0x73,
+    0x68, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x68, 0x61, 0x73, 0x68, 0x20, 0x6f, 0x66,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x04, 0x05, 0x12, 0x03, 0x2e, 0x02, 0x07, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x04, 0x01, 0x12, 0x03, 0x2e, 0x08, 0x0f, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x02, 0x02, 0x04, 0x03, 0x12, 0x03, 0x2e, 0x12, 0x13, 0x0a, 0x45, 0x0a, 0x04, 0x04,
+    0x02, 0x02, 0x05, 0x12, 0x03, 0x31, 0x02, 0x15, 0x1a, 0x38, 0x20, 0x4f, 0x72, 0x64, 0x69, 0x6e,
+    0x61, 0x6c, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x20,
+    0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x20,
+    0x77, 0x69, 0x74, 0x68, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b,
+    0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x05, 0x05, 0x12, 0x03, 0x31, 0x02, 0x08,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x05, 0x01, 0x12, 0x03, 0x31, 0x09, 0x10, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x02, 0x02, 0x05, 0x03, 0x12, 0x03, 0x31, 0x13, 0x14, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x02, 0x02, 0x06, 0x12, 0x03, 0x33, 0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02,
+    0x06, 0x05, 0x12, 0x03, 0x33, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x06, 0x01,
+    0x12, 0x03, 0x33, 0x09, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x06, 0x03, 0x12, 0x03,
+    0x33, 0x18, 0x19, 0x0a, 0x37, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x07, 0x12, 0x03, 0x36, 0x02, 0x2a,
+    0x1a, 0x2a, 0x20, 0x54, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x20, 0x69, 0x73, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x20, 0x6f, 0x66,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x02, 0x02, 0x07, 0x06, 0x12, 0x03, 0x36, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02,
+    0x02, 0x07, 0x01, 0x12, 0x03, 0x36, 0x1c, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x07,
+    0x03, 0x12, 0x03, 0x36, 0x28, 0x29, 0x0a, 0x5f, 0x0a, 0x02, 0x04, 0x03, 0x12, 0x04, 0x3a, 0x00,
+    0x47, 0x01, 0x1a, 0x53, 0x20, 0x4e, 0x66, 0x74, 0x55, 0x73, 0x65, 0x72, 0x20, 0x69, 0x73, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x45, 0x52, 0x43, 0x2d, 0x34, 0x39, 0x30, 0x37, 0x20, 0x75, 0x73, 0x65,
+    0x72, 0x20, 0x6f, 0x66, 0x20, 0x61, 0x20, 0x72, 0x65, 0x6e, 0x74, 0x61, 0x62, 0x6c, 0x65, 0x20,
+    0x45, 0x52, 0x43, 0x37, 0x32, 0x31, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x2c, 0x20, 0x64, 0x69,
+    0x73, 0x74, 0x69, 0x6e, 0x63, 0x74, 0x20, 0x66, 0x72, 0x6f, 0x6d, 0x20, 0x69, 0x74, 0x73, 0x20,
+    0x6f, 0x77, 0x6e, 0x65, 0x72, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03, 0x01, 0x12, 0x03,
+    0x3a, 0x08, 0x0f, 0x0a, 0x5f, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x00, 0x12, 0x03, 0x3c, 0x02, 0x1d,
+    0x1a, 0x52, 0x20, 0x43, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x41, 0x64, 0x64, 0x72, 0x65,
+    0x73, 0x73, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73,
+    0x73, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x6d, 0x61, 0x72, 0x74, 0x20, 0x63,
+    0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x69, 0x73, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61,
+    0x63, 0x74, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x05, 0x12, 0x03, 0x3c,
+    0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x01, 0x12, 0x03, 0x3c, 0x08, 0x18,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x03, 0x12, 0x03, 0x3c, 0x1b, 0x1c, 0x0a, 0x4d,
+    0x0a, 0x04, 0x04, 0x03, 0x02, 0x01, 0x12, 0x03, 0x3f, 0x02, 0x15, 0x1a, 0x40, 0x20, 0x54, 0x6f,
+    0x6b, 0x65, 0x6e, 0x49, 0x44, 0x20, 0x74, 0x68, 0x65, 0x20, 0x69, 0x64, 0x65, 0x6e, 0x74, 0x69,
+    0x66, 0x69, 0x65, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65,
+    0x6e, 0x20, 0x77, 0x69, 0x74, 0x68, 0x69, 0x6e, 0x20, 0x60, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61,
+    0x63, 0x74, 0x5f, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x60, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x03, 0x02, 0x01, 0x05, 0x12, 0x03, 0x3f, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x03, 0x02, 0x01, 0x01, 0x12, 0x03, 0x3f, 0x08, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02,
+    0x01, 0x03, 0x12, 0x03, 0x3f, 0x13, 0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x02, 0x12,
+    0x03, 0x41, 0x02, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x02, 0x05, 0x12, 0x03, 0x41,
+    0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x02, 0x01, 0x12, 0x03, 0x41, 0x08, 0x0c,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x02, 0x03, 0x12, 0x03, 0x41, 0x0f, 0x10, 0x0a, 0x68,
+    0x0a, 0x04, 0x04, 0x03, 0x02, 0x03, 0x12, 0x03, 0x44, 0x02, 0x15, 0x1a, 0x5b, 0x20, 0x45, 0x78,
+    0x70, 0x69, 0x72, 0x65, 0x73, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x75, 0x6e, 0x69,
+    0x78, 0x20, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x2c, 0x20, 0x69, 0x6e, 0x20,
+    0x73, 0x65, 0x63, 0x6f, 0x6e, 0x64, 0x73, 0x2c, 0x20, 0x61, 0x74, 0x20, 0x77, 0x68, 0x69, 0x63,
+    0x68, 0x20, 0x60, 0x75, 0x73, 0x65, 0x72, 0x60, 0x20, 0x6c, 0x6f, 0x73, 0x65, 0x73, 0x20, 0x69,
+    0x74, 0x73, 0x20, 0x72, 0x69, 0x67, 0x68, 0x74, 0x73, 0x20, 0x6f, 0x6e, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x03,
+    0x05, 0x12, 0x03, 0x44, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x03, 0x01, 0x12,
+    0x03, 0x44, 0x09, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x03, 0x03, 0x12, 0x03, 0x44,
+    0x13, 0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x04, 0x12, 0x03, 0x46, 0x02, 0x1a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x04, 0x05, 0x12, 0x03, 0x46, 0x02, 0x08, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x03, 0x02, 0x04, 0x01, 0x12, 0x03, 0x46, 0x09, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x03, 0x02, 0x04, 0x03, 0x12, 0x03, 0x46, 0x18, 0x19, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x04, 0x12,
+    0x04, 0x49, 0x00, 0x4b, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x04, 0x01, 0x12, 0x03, 0x49, 0x08,
+    0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x00, 0x12, 0x03, 0x4a, 0x02, 0x36, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x04, 0x12, 0x03, 0x4a, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x04, 0x02, 0x00, 0x06, 0x12, 0x03, 0x4a, 0x0b, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04,
+    0x02, 0x00, 0x01, 0x12, 0x03, 0x4a, 0x1d, 0x31, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00,
+    0x03, 0x12, 0x03, 0x4a, 0x34, 0x35, 0x0a, 0x6d, 0x0a, 0x02, 0x04, 0x05, 0x12, 0x04, 0x4e, 0x00,
+    0x6d, 0x01, 0x1a, 0x61, 0x20, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x42, 0x6f, 0x75, 0x6e, 0x64, 0x41,
+    0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x69, 0x73, 0x20, 0x61, 0x6e, 0x20, 0x45, 0x52, 0x43,
+    0x2d, 0x36, 0x35, 0x35, 0x31, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x6f, 0x77,
+    0x6e, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x61, 0x6e, 0x20, 0x4e, 0x46, 0x54, 0x2c, 0x20, 0x63,
+    0x72, 0x65, 0x61, 0x74, 0x65, 0x64, 0x20, 0x74, 0x68, 0x72, 0x6f, 0x75, 0x67, 0x68, 0x20, 0x61,
+    0x6e, 0x20, 0x45, 0x52, 0x43, 0x2d, 0x36, 0x35, 0x35, 0x31, 0x20, 0x72, 0x65, 0x67, 0x69, 0x73,
+    0x74, 0x72, 0x79, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x05, 0x01, 0x12, 0x03, 0x4e, 0x08,
+    0x19, 0x0a, 0x3d, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x00, 0x12, 0x03, 0x50, 0x02, 0x14, 0x1a, 0x30,
+    0x20, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63,
+    0x72, 0x65, 0x61, 0x74, 0x65, 0x64, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x2e, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x05, 0x12, 0x03, 0x50, 0x02, 0x07, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x01, 0x12, 0x03, 0x50, 0x08, 0x0f, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x05, 0x02, 0x00, 0x03, 0x12, 0x03, 0x50, 0x12, 0x13, 0x0a, 0x54, 0x0a, 0x04, 0x04, 0x05,
+    0x02, 0x01, 0x12, 0x03, 0x53, 0x02, 0x1b, 0x1a, 0x47, 0x20, 0x49, 0x6d, 0x70, 0x6c, 0x65, 0x6d,
+    0x65, 0x6e, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63,
+    0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x63, 0x63, 0x6f,
+    0x75, 0x6e, 0x74, 0x20, 0x70, 0x72, 0x6f, 0x78, 0x69, 0x65, 0x73, 0x20, 0x74, 0x6f, 0x2e, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x05, 0x12, 0x03, 0x53, 0x02, 0x07, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x01, 0x12, 0x03, 0x53, 0x08, 0x16, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x05, 0x02, 0x01, 0x03, 0x12, 0x03, 0x53, 0x19, 0x1a, 0x0a, 0x4e, 0x0a, 0x04, 0x04, 0x05,
+    0x02, 0x02, 0x12, 0x03, 0x56, 0x02, 0x16, 0x1a, 0x41, 0x20, 0x43, 0x68, 0x61, 0x69, 0x6e, 0x49,
+    0x64, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x6f,
+    0x6e, 0x20, 0x77, 0x68, 0x69, 0x63, 0x68, 0x20, 0x74, 0x68, 0x65, 0x20, 0x4e, 0x46, 0x54, 0x20,
+    0x6f, 0x77, 0x6e, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75,
+    0x6e, 0x74, 0x20, 0x6c, 0x69, 0x76, 0x65, 0x73, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05,
+    0x02, 0x02, 0x05, 0x12, 0x03, 0x56, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02,
+    0x01, 0x12, 0x03, 0x56, 0x09, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x03, 0x12,
+    0x03, 0x56, 0x14, 0x15, 0x0a, 0x5a, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x03, 0x12, 0x03, 0x59, 0x02,
+    0x1b, 0x1a, 0x4d, 0x20, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x43, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63,
+    0x74, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73,
+    0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74,
+    0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x4e, 0x46, 0x54, 0x20, 0x6f, 0x77, 0x6e, 0x69,
+    0x6e, 0x67, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x2e, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x03, 0x05, 0x12, 0x03, 0x59, 0x02, 0x07, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x05, 0x02, 0x03, 0x01, 0x12, 0x03, 0x59, 0x08, 0x16, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x05, 0x02, 0x03, 0x03, 0x12, 0x03, 0x59, 0x19, 0x1a, 0x0a, 0x5c, 0x0a, 0x04, 0x04, 0x05,
+    0x02, 0x04, 0x12, 0x03, 0x5c, 0x02, 0x15, 0x1a, 0x4f, 0x20, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x49,
+    0x44, 0x20, 0x74, 0x68, 0x65, 0x20, 0x69, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72,
+    0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x4e, 0x46, 0x54, 0x20, 0x6f, 0x77, 0x6e, 0x69,
+    0x6e, 0x67, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x77,
+    0x69, 0x74, 0x68, 0x69, 0x6e, 0x20, 0x60, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x5f, 0x63, 0x6f, 0x6e,
+    0x74, 0x72, 0x61, 0x63, 0x74, 0x60, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x04,
+    0x05, 0x12, 0x03, 0x5c, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x04, 0x01, 0x12,
+    0x03, 0x5c, 0x08, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x04, 0x03, 0x12, 0x03, 0x5c,
+    0x13, 0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x05, 0x12, 0x03, 0x5e, 0x02, 0x11, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x05, 0x05, 0x12, 0x03, 0x5e, 0x02, 0x07, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x05, 0x02, 0x05, 0x01, 0x12, 0x03, 0x5e, 0x08, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x05, 0x02, 0x05, 0x03, 0x12, 0x03, 0x5e, 0x0f, 0x10, 0x0a, 0x59, 0x0a, 0x04, 0x04, 0x05, 0x02,
+    0x06, 0x12, 0x03, 0x61, 0x02, 0x15, 0x1a, 0x4c, 0x20, 0x52, 0x65, 0x67, 0x69, 0x73, 0x74, 0x72,
+    0x79, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73,
+    0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x45, 0x52, 0x43, 0x2d, 0x36, 0x35, 0x35, 0x31,
+    0x20, 0x72, 0x65, 0x67, 0x69, 0x73, 0x74, 0x72, 0x79, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x63,
+    0x72, 0x65, 0x61, 0x74, 0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75,
+    0x6e, 0x74, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x06, 0x05, 0x12, 0x03, 0x61,
+    0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x06, 0x01, 0x12, 0x03, 0x61, 0x08, 0x10,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x06, 0x03, 0x12, 0x03, 0x61, 0x13, 0x14, 0x0a, 0x34,
+    0x0a, 0x04, 0x04, 0x05, 0x02, 0x07, 0x12, 0x03, 0x64, 0x02, 0x14, 0x1a, 0x27, 0x20, 0x54, 0x78,
+    0x48, 0x61, 0x73, 0x68, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x68, 0x61, 0x73, 0x68,
+    0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74,
+    0x69, 0x6f, 0x6e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x07, 0x05, 0x12, 0x03, 0x64,
+    0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x07, 0x01, 0x12, 0x03, 0x64, 0x08, 0x0f,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x07, 0x03, 0x12, 0x03, 0x64, 0x12, 0x13, 0x0a, 0x45,
+    0x0a, 0x04, 0x04, 0x05, 0x02, 0x08, 0x12, 0x03, 0x67, 0x02, 0x15, 0x1a, 0x38, 0x20, 0x4f, 0x72,
+    0x64, 0x69, 0x6e, 0x61, 0x6c, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x64,
+    0x65, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x72, 0x65, 0x61, 0x74, 0x69,
+    0x6f, 0x6e, 0x20, 0x77, 0x69, 0x74, 0x68, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x6c,
+    0x6f, 0x63, 0x6b, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x08, 0x05, 0x12, 0x03,
+    0x67, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x08, 0x01, 0x12, 0x03, 0x67, 0x09,
+    0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x08, 0x03, 0x12, 0x03, 0x67, 0x13, 0x14, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x09, 0x12, 0x03, 0x69, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x05, 0x02, 0x09, 0x05, 0x12, 0x03, 0x69, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05,
+    0x02, 0x09, 0x01, 0x12, 0x03, 0x69, 0x09, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x09,
+    0x03, 0x12, 0x03, 0x69, 0x18, 0x1a, 0x0a, 0x37, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x0a, 0x12, 0x03,
+    0x6c, 0x02, 0x2b, 0x1a, 0x2a, 0x20, 0x54, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x20,
+    0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70,
+    0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2e, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x0a, 0x06, 0x12, 0x03, 0x6c, 0x02, 0x1b, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x05, 0x02, 0x0a, 0x01, 0x12, 0x03, 0x6c, 0x1c, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x05, 0x02, 0x0a, 0x03, 0x12, 0x03, 0x6c, 0x28, 0x2a, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f,
+    0x33,
+];
+// @@protoc_insertion_point(module)
\ No newline at end of file
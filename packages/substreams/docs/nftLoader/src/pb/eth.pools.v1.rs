@@ -0,0 +1,133 @@
+// @generated
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Pools {
+    #[prost(message, repeated, tag="1")]
+    pub pools: ::prost::alloc::vec::Vec<Pool>,
+}
+/// Pool is an NFT AMM pool, a Sudoswap pair or an NFTX vault, captured when it is created.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Pool {
+    /// Amm is the name of the AMM the pool belongs to, `sudoswap` or `nftx`.
+    #[prost(string, tag="1")]
+    pub amm: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="2")]
+    pub address: ::prost::alloc::vec::Vec<u8>,
+    /// NftContract is the address of the NFT contract the pool trades.
+    #[prost(bytes="vec", tag="3")]
+    pub nft_contract: ::prost::alloc::vec::Vec<u8>,
+    /// PaymentToken is the ERC20 the pool trades its NFTs for, empty for ETH. For NFTX, this is the
+    /// vault itself, which is the ERC20 of its vTokens.
+    #[prost(bytes="vec", tag="4")]
+    pub payment_token: ::prost::alloc::vec::Vec<u8>,
+    /// TxHash is the hash of the transaction that created the pool.
+    #[prost(bytes="vec", tag="5")]
+    pub tx_hash: ::prost::alloc::vec::Vec<u8>,
+    /// Ordinal is the order of the creation within the block.
+    #[prost(uint64, tag="6")]
+    pub ordinal: u64,
+    #[prost(uint64, tag="7")]
+    pub block_number: u64,
+    /// Timestamp is the timestamp of the block.
+    #[prost(message, optional, tag="8")]
+    pub timestamp: ::core::option::Option<::prost_types::Timestamp>,
+}
+/// Encoded file descriptor set for the `eth.pools.v1` package
+pub const FILE_DESCRIPTOR_SET: &[u8] = &[
+    0x0a, 0xfc, 0x0b, 0x0a, 0x0b, 0x70, 0x6f, 0x6f, 0x6c, 0x73, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f,
+    0x12, 0x0c, 0x65, 0x74, 0x68, 0x2e, 0x70, 0x6f, 0x6f, 0x6c, 0x73, 0x2e, 0x76, 0x31, 0x1a, 0x1f,
+    0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2f, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x2f,
+    0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x22,
+    0x31, 0x0a, 0x05, 0x50, 0x6f, 0x6f, 0x6c, 0x73, 0x12, 0x28, 0x0a, 0x05, 0x70, 0x6f, 0x6f, 0x6c,
+    0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x12, 0x2e, 0x65, 0x74, 0x68, 0x2e, 0x70, 0x6f,
+    0x6f, 0x6c, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x50, 0x6f, 0x6f, 0x6c, 0x52, 0x05, 0x70, 0x6f, 0x6f,
+    0x6c, 0x73, 0x22, 0x8a, 0x02, 0x0a, 0x04, 0x50, 0x6f, 0x6f, 0x6c, 0x12, 0x10, 0x0a, 0x03, 0x61,
+    0x6d, 0x6d, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x03, 0x61, 0x6d, 0x6d, 0x12, 0x18, 0x0a,
+    0x07, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07,
+    0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x12, 0x21, 0x0a, 0x0c, 0x6e, 0x66, 0x74, 0x5f, 0x63,
+    0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x0b, 0x6e,
+    0x66, 0x74, 0x43, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x12, 0x23, 0x0a, 0x0d, 0x70, 0x61,
+    0x79, 0x6d, 0x65, 0x6e, 0x74, 0x5f, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x18, 0x04, 0x20, 0x01, 0x28,
+    0x0c, 0x52, 0x0c, 0x70, 0x61, 0x79, 0x6d, 0x65, 0x6e, 0x74, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x12,
+    0x17, 0x0a, 0x07, 0x74, 0x78, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0c,
+    0x52, 0x06, 0x74, 0x78, 0x48, 0x61, 0x73, 0x68, 0x12, 0x18, 0x0a, 0x07, 0x6f, 0x72, 0x64, 0x69,
+    0x6e, 0x61, 0x6c, 0x18, 0x06, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x6f, 0x72, 0x64, 0x69, 0x6e,
+    0x61, 0x6c, 0x12, 0x21, 0x0a, 0x0c, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x5f, 0x6e, 0x75, 0x6d, 0x62,
+    0x65, 0x72, 0x18, 0x07, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x4e,
+    0x75, 0x6d, 0x62, 0x65, 0x72, 0x12, 0x38, 0x0a, 0x09, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61,
+    0x6d, 0x70, 0x18, 0x08, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1a, 0x2e, 0x67, 0x6f, 0x6f, 0x67, 0x6c,
+    0x65, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x2e, 0x54, 0x69, 0x6d, 0x65, 0x73,
+    0x74, 0x61, 0x6d, 0x70, 0x52, 0x09, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x4a,
+    0xf5, 0x08, 0x0a, 0x06, 0x12, 0x04, 0x00, 0x00, 0x22, 0x01, 0x0a, 0x08, 0x0a, 0x01, 0x0c, 0x12,
+    0x03, 0x00, 0x00, 0x12, 0x0a, 0x08, 0x0a, 0x01, 0x02, 0x12, 0x03, 0x02, 0x00, 0x15, 0x0a, 0x09,
+    0x0a, 0x02, 0x03, 0x00, 0x12, 0x03, 0x04, 0x00, 0x29, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x00, 0x12,
+    0x04, 0x06, 0x00, 0x08, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x06, 0x08,
+    0x0d, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x07, 0x02, 0x1a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x04, 0x12, 0x03, 0x07, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x00, 0x02, 0x00, 0x06, 0x12, 0x03, 0x07, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
+    0x02, 0x00, 0x01, 0x12, 0x03, 0x07, 0x10, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00,
+    0x03, 0x12, 0x03, 0x07, 0x18, 0x19, 0x0a, 0x65, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0x0b, 0x00,
+    0x22, 0x01, 0x1a, 0x59, 0x20, 0x50, 0x6f, 0x6f, 0x6c, 0x20, 0x69, 0x73, 0x20, 0x61, 0x6e, 0x20,
+    0x4e, 0x46, 0x54, 0x20, 0x41, 0x4d, 0x4d, 0x20, 0x70, 0x6f, 0x6f, 0x6c, 0x2c, 0x20, 0x61, 0x20,
+    0x53, 0x75, 0x64, 0x6f, 0x73, 0x77, 0x61, 0x70, 0x20, 0x70, 0x61, 0x69, 0x72, 0x20, 0x6f, 0x72,
+    0x20, 0x61, 0x6e, 0x20, 0x4e, 0x46, 0x54, 0x58, 0x20, 0x76, 0x61, 0x75, 0x6c, 0x74, 0x2c, 0x20,
+    0x63, 0x61, 0x70, 0x74, 0x75, 0x72, 0x65, 0x64, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x69, 0x74,
+    0x20, 0x69, 0x73, 0x20, 0x63, 0x72, 0x65, 0x61, 0x74, 0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a,
+    0x03, 0x04, 0x01, 0x01, 0x12, 0x03, 0x0b, 0x08, 0x0c, 0x0a, 0x54, 0x0a, 0x04, 0x04, 0x01, 0x02,
+    0x00, 0x12, 0x03, 0x0d, 0x02, 0x11, 0x1a, 0x47, 0x20, 0x41, 0x6d, 0x6d, 0x20, 0x69, 0x73, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x6e, 0x61, 0x6d, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x41, 0x4d, 0x4d, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x6f, 0x6f, 0x6c, 0x20, 0x62, 0x65, 0x6c,
+    0x6f, 0x6e, 0x67, 0x73, 0x20, 0x74, 0x6f, 0x2c, 0x20, 0x60, 0x73, 0x75, 0x64, 0x6f, 0x73, 0x77,
+    0x61, 0x70, 0x60, 0x20, 0x6f, 0x72, 0x20, 0x60, 0x6e, 0x66, 0x74, 0x78, 0x60, 0x2e, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x05, 0x12, 0x03, 0x0d, 0x02, 0x08, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x0d, 0x09, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x0d, 0x0f, 0x10, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02,
+    0x01, 0x12, 0x03, 0x0f, 0x02, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x05, 0x12,
+    0x03, 0x0f, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x0f,
+    0x08, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x03, 0x12, 0x03, 0x0f, 0x12, 0x13,
+    0x0a, 0x4e, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x02, 0x12, 0x03, 0x12, 0x02, 0x19, 0x1a, 0x41, 0x20,
+    0x4e, 0x66, 0x74, 0x43, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x20, 0x69, 0x73, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x4e, 0x46, 0x54, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x70, 0x6f, 0x6f, 0x6c, 0x20, 0x74, 0x72, 0x61, 0x64, 0x65, 0x73, 0x2e, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x05, 0x12, 0x03, 0x12, 0x02, 0x07, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x01, 0x12, 0x03, 0x12, 0x08, 0x14, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x01, 0x02, 0x02, 0x03, 0x12, 0x03, 0x12, 0x17, 0x18, 0x0a, 0x9e, 0x01, 0x0a, 0x04, 0x04,
+    0x01, 0x02, 0x03, 0x12, 0x03, 0x16, 0x02, 0x1a, 0x1a, 0x90, 0x01, 0x20, 0x50, 0x61, 0x79, 0x6d,
+    0x65, 0x6e, 0x74, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x45, 0x52, 0x43, 0x32, 0x30, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x6f, 0x6f, 0x6c, 0x20, 0x74,
+    0x72, 0x61, 0x64, 0x65, 0x73, 0x20, 0x69, 0x74, 0x73, 0x20, 0x4e, 0x46, 0x54, 0x73, 0x20, 0x66,
+    0x6f, 0x72, 0x2c, 0x20, 0x65, 0x6d, 0x70, 0x74, 0x79, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x45, 0x54,
+    0x48, 0x2e, 0x20, 0x46, 0x6f, 0x72, 0x20, 0x4e, 0x46, 0x54, 0x58, 0x2c, 0x20, 0x74, 0x68, 0x69,
+    0x73, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x0a, 0x20, 0x76, 0x61, 0x75, 0x6c, 0x74, 0x20,
+    0x69, 0x74, 0x73, 0x65, 0x6c, 0x66, 0x2c, 0x20, 0x77, 0x68, 0x69, 0x63, 0x68, 0x20, 0x69, 0x73,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x45, 0x52, 0x43, 0x32, 0x30, 0x20, 0x6f, 0x66, 0x20, 0x69, 0x74,
+    0x73, 0x20, 0x76, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x01, 0x02, 0x03, 0x05, 0x12, 0x03, 0x16, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
+    0x03, 0x01, 0x12, 0x03, 0x16, 0x08, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x03, 0x03,
+    0x12, 0x03, 0x16, 0x18, 0x19, 0x0a, 0x4b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x04, 0x12, 0x03, 0x19,
+    0x02, 0x14, 0x1a, 0x3e, 0x20, 0x54, 0x78, 0x48, 0x61, 0x73, 0x68, 0x20, 0x69, 0x73, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x68, 0x61, 0x73, 0x68, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74,
+    0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20,
+    0x63, 0x72, 0x65, 0x61, 0x74, 0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x6f, 0x6f, 0x6c,
+    0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x04, 0x05, 0x12, 0x03, 0x19, 0x02, 0x07,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x04, 0x01, 0x12, 0x03, 0x19, 0x08, 0x0f, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x01, 0x02, 0x04, 0x03, 0x12, 0x03, 0x19, 0x12, 0x13, 0x0a, 0x45, 0x0a, 0x04,
+    0x04, 0x01, 0x02, 0x05, 0x12, 0x03, 0x1c, 0x02, 0x15, 0x1a, 0x38, 0x20, 0x4f, 0x72, 0x64, 0x69,
+    0x6e, 0x61, 0x6c, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x64, 0x65, 0x72,
+    0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x72, 0x65, 0x61, 0x74, 0x69, 0x6f, 0x6e,
+    0x20, 0x77, 0x69, 0x74, 0x68, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63,
+    0x6b, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x05, 0x05, 0x12, 0x03, 0x1c, 0x02,
+    0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x05, 0x01, 0x12, 0x03, 0x1c, 0x09, 0x10, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x05, 0x03, 0x12, 0x03, 0x1c, 0x13, 0x14, 0x0a, 0x0b, 0x0a,
+    0x04, 0x04, 0x01, 0x02, 0x06, 0x12, 0x03, 0x1e, 0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
+    0x02, 0x06, 0x05, 0x12, 0x03, 0x1e, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x06,
+    0x01, 0x12, 0x03, 0x1e, 0x09, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x06, 0x03, 0x12,
+    0x03, 0x1e, 0x18, 0x19, 0x0a, 0x37, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x07, 0x12, 0x03, 0x21, 0x02,
+    0x2a, 0x1a, 0x2a, 0x20, 0x54, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x20, 0x69, 0x73,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x20, 0x6f,
+    0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x01, 0x02, 0x07, 0x06, 0x12, 0x03, 0x21, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x01, 0x02, 0x07, 0x01, 0x12, 0x03, 0x21, 0x1c, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
+    0x07, 0x03, 0x12, 0x03, 0x21, 0x28, 0x29, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x33,
+];
+// @@protoc_insertion_point(module)
\ No newline at end of file
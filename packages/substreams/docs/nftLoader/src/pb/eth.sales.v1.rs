@@ -0,0 +1,781 @@
+// @generated
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Sales {
+    #[prost(message, repeated, tag="1")]
+    pub sales: ::prost::alloc::vec::Vec<Sale>,
+}
+/// Sale is a single NFT changing hands through a marketplace settlement. A settlement that moves
+/// several NFTs at once produces one Sale per NFT, all sharing the same `tx_hash`, `order_hash` and
+/// `bundle_id`.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Sale {
+    /// Marketplace is the name of the marketplace that settled the sale, e.g. `nftcom`.
+    #[prost(string, tag="1")]
+    pub marketplace: ::prost::alloc::string::String,
+    /// OrderHash identifies the settled order on the marketplace. For NFT.com, this is the struct hash
+    /// of the maker order.
+    #[prost(bytes="vec", tag="2")]
+    pub order_hash: ::prost::alloc::vec::Vec<u8>,
+    /// Maker is the address that signed the order that was filled.
+    #[prost(bytes="vec", tag="3")]
+    pub maker: ::prost::alloc::vec::Vec<u8>,
+    /// Taker is the address that filled the maker order.
+    #[prost(bytes="vec", tag="4")]
+    pub taker: ::prost::alloc::vec::Vec<u8>,
+    /// Seller is the address that gave away the NFT, equal to either `maker` or `taker` depending on
+    /// which side listed the NFT.
+    #[prost(bytes="vec", tag="5")]
+    pub seller: ::prost::alloc::vec::Vec<u8>,
+    /// Buyer is the address that received the NFT.
+    #[prost(bytes="vec", tag="6")]
+    pub buyer: ::prost::alloc::vec::Vec<u8>,
+    /// Schema is the token standard of the NFT sold, either `erc721` or `erc1155`.
+    #[prost(string, tag="7")]
+    pub schema: ::prost::alloc::string::String,
+    /// ContractAddress is the address of the NFT contract.
+    #[prost(bytes="vec", tag="8")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+    /// TokenID is the identifier of the NFT within `contract_address`.
+    #[prost(bytes="vec", tag="9")]
+    pub token_id: ::prost::alloc::vec::Vec<u8>,
+    /// How many units of the NFT were sold, will always be 1 in the case of ERC721.
+    #[prost(string, tag="10")]
+    pub quantity: ::prost::alloc::string::String,
+    /// PaymentToken is the address of the ERC20 used to pay for the sale, empty when paid in ETH.
+    #[prost(bytes="vec", tag="11")]
+    pub payment_token: ::prost::alloc::vec::Vec<u8>,
+    /// Price is the amount paid by the buyer for this NFT, in the smallest unit of `payment_token`.
+    /// For a bundle, this is the part of `bundle_price` allocated to the NFT.
+    #[prost(string, tag="12")]
+    pub price: ::prost::alloc::string::String,
+    /// ProtocolFee is the part of `price` that went to the marketplace.
+    #[prost(string, tag="13")]
+    pub protocol_fee: ::prost::alloc::string::String,
+    /// RoyaltyFee is the part of `price` that went to the creator of the collection.
+    #[prost(string, tag="14")]
+    pub royalty_fee: ::prost::alloc::string::String,
+    /// TxHash is the hash of the transaction
+    #[prost(bytes="vec", tag="15")]
+    pub tx_hash: ::prost::alloc::vec::Vec<u8>,
+    /// Ordinal is the order of the sale within the block.
+    #[prost(uint64, tag="16")]
+    pub ordinal: u64,
+    #[prost(uint64, tag="17")]
+    pub block_number: u64,
+    /// Timestamp is the timestamp of the block.
+    #[prost(message, optional, tag="18")]
+    pub timestamp: ::core::option::Option<::prost_types::Timestamp>,
+    /// Confidence is how likely the transfer is a sale, from 0 to 100. Sales decoded from a known
+    /// marketplace are always 100, sales of the `unknown` marketplace are inferred from the value
+    /// flows of the transaction.
+    #[prost(uint32, tag="19")]
+    pub confidence: u32,
+    /// BundleID identifies the NFTs sold together for a single price, empty when the NFT was sold
+    /// alone.
+    #[prost(string, tag="20")]
+    pub bundle_id: ::prost::alloc::string::String,
+    /// BundleSize is the number of NFTs sold together, 1 when the NFT was sold alone.
+    #[prost(uint32, tag="21")]
+    pub bundle_size: u32,
+    /// BundlePrice is the total amount paid by the buyer for the bundle, equal to `price` when the NFT
+    /// was sold alone.
+    #[prost(string, tag="22")]
+    pub bundle_price: ::prost::alloc::string::String,
+    /// Allocation is the string representation of one of the enum defined in Allocation, how
+    /// `bundle_price` was split into `price`: `single` for an NFT sold alone, `even` for a split per
+    /// unit and `floor` for a split pro-rata to the floor of the collections.
+    #[prost(string, tag="23")]
+    pub allocation: ::prost::alloc::string::String,
+    /// EthUsdPrice is the price of one ETH in USD at the block, set for sales paid in ETH or WETH.
+    #[prost(string, tag="24")]
+    pub eth_usd_price: ::prost::alloc::string::String,
+    /// PriceUSD is `price` converted to USD with `eth_usd_price`, set for sales paid in ETH or WETH.
+    #[prost(string, tag="25")]
+    pub price_usd: ::prost::alloc::string::String,
+    /// WashScore is how likely the sale is a wash trade, from 0 to 100.
+    #[prost(uint32, tag="26")]
+    pub wash_score: u32,
+    /// WashReasons are the string representations of the enum defined in WashReason that raised
+    /// `wash_score`: `funded_by_counterparty` when the buyer and the seller funded each other before
+    /// the sale, `round_trip` when the NFT returns to a wallet that sold it recently and `zero_cost`
+    /// when the seller got the NFT for free.
+    #[prost(string, repeated, tag="27")]
+    pub wash_reasons: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// Nested message and enum types in `Sale`.
+pub mod sale {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum Allocation {
+        Single = 0,
+        Even = 1,
+        Floor = 2,
+    }
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum WashReason {
+        FundedByCounterparty = 0,
+        RoundTrip = 1,
+        ZeroCost = 2,
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Mints {
+    #[prost(message, repeated, tag="1")]
+    pub mints: ::prost::alloc::vec::Vec<Mint>,
+}
+/// Mint is a token transferred from the zero address, with the payment of its transaction spread
+/// evenly across all the tokens minted by the transaction.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Mint {
+    /// Schema is the token standard of the NFT minted, either `erc721` or `erc1155`.
+    #[prost(string, tag="1")]
+    pub schema: ::prost::alloc::string::String,
+    /// ContractAddress is the address of the NFT contract.
+    #[prost(bytes="vec", tag="2")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+    /// TokenID is the identifier of the NFT within `contract_address`.
+    #[prost(bytes="vec", tag="3")]
+    pub token_id: ::prost::alloc::vec::Vec<u8>,
+    /// How many units of the NFT were minted, will always be 1 in the case of ERC721.
+    #[prost(string, tag="4")]
+    pub quantity: ::prost::alloc::string::String,
+    /// Minter is the address that received the minted NFT.
+    #[prost(bytes="vec", tag="5")]
+    pub minter: ::prost::alloc::vec::Vec<u8>,
+    /// Payer is the account that signed the mint transaction, which may differ from `minter` for
+    /// airdrops and mints on behalf of another address.
+    #[prost(bytes="vec", tag="6")]
+    pub payer: ::prost::alloc::vec::Vec<u8>,
+    /// PaymentToken is the address of the ERC20 paid by `payer` in the transaction, empty when paid
+    /// in ETH or not paid at all.
+    #[prost(bytes="vec", tag="7")]
+    pub payment_token: ::prost::alloc::vec::Vec<u8>,
+    /// TxPrice is the total amount paid by `payer` in the transaction, in the smallest unit of
+    /// `payment_token`.
+    #[prost(string, tag="8")]
+    pub tx_price: ::prost::alloc::string::String,
+    /// TxQuantity is the number of units minted by the transaction, over all contracts.
+    #[prost(string, tag="9")]
+    pub tx_quantity: ::prost::alloc::string::String,
+    /// PricePerToken is `tx_price` divided by `tx_quantity`.
+    #[prost(string, tag="10")]
+    pub price_per_token: ::prost::alloc::string::String,
+    /// Free is true when nothing was paid for the mint, besides gas.
+    #[prost(bool, tag="11")]
+    pub free: bool,
+    #[prost(uint64, tag="12")]
+    pub tx_gas_used: u64,
+    /// TxGasPrice is the gas price paid by the transaction, in wei.
+    #[prost(string, tag="13")]
+    pub tx_gas_price: ::prost::alloc::string::String,
+    /// TxHash is the hash of the transaction
+    #[prost(bytes="vec", tag="14")]
+    pub tx_hash: ::prost::alloc::vec::Vec<u8>,
+    /// Ordinal is the order of the mint within the block.
+    #[prost(uint64, tag="15")]
+    pub ordinal: u64,
+    #[prost(uint64, tag="16")]
+    pub block_number: u64,
+    /// Timestamp is the timestamp of the block.
+    #[prost(message, optional, tag="17")]
+    pub timestamp: ::core::option::Option<::prost_types::Timestamp>,
+    /// EthUsdPrice is the price of one ETH in USD at the block, set for mints paid in ETH or WETH.
+    #[prost(string, tag="18")]
+    pub eth_usd_price: ::prost::alloc::string::String,
+    /// PricePerTokenUSD is `price_per_token` converted to USD with `eth_usd_price`, set for mints
+    /// paid in ETH or WETH.
+    #[prost(string, tag="19")]
+    pub price_per_token_usd: ::prost::alloc::string::String,
+}
+/// Encoded file descriptor set for the `eth.sales.v1` package
+pub const FILE_DESCRIPTOR_SET: &[u8] = &[
+    0x0a, 0xac, 0x49, 0x0a, 0x0b, 0x73, 0x61, 0x6c, 0x65, 0x73, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f,
+    0x12, 0x0c, 0x65, 0x74, 0x68, 0x2e, 0x73, 0x61, 0x6c, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x1a, 0x1f,
+    0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2f, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x2f,
+    0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x22,
+    0x31, 0x0a, 0x05, 0x53, 0x61, 0x6c, 0x65, 0x73, 0x12, 0x28, 0x0a, 0x05, 0x73, 0x61, 0x6c, 0x65,
+    0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x12, 0x2e, 0x65, 0x74, 0x68, 0x2e, 0x73, 0x61,
+    0x6c, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x53, 0x61, 0x6c, 0x65, 0x52, 0x05, 0x73, 0x61, 0x6c,
+    0x65, 0x73, 0x22, 0xc6, 0x07, 0x0a, 0x04, 0x53, 0x61, 0x6c, 0x65, 0x12, 0x20, 0x0a, 0x0b, 0x6d,
+    0x61, 0x72, 0x6b, 0x65, 0x74, 0x70, 0x6c, 0x61, 0x63, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09,
+    0x52, 0x0b, 0x6d, 0x61, 0x72, 0x6b, 0x65, 0x74, 0x70, 0x6c, 0x61, 0x63, 0x65, 0x12, 0x1d, 0x0a,
+    0x0a, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x18, 0x02, 0x20, 0x01, 0x28,
+    0x0c, 0x52, 0x09, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x48, 0x61, 0x73, 0x68, 0x12, 0x14, 0x0a, 0x05,
+    0x6d, 0x61, 0x6b, 0x65, 0x72, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x05, 0x6d, 0x61, 0x6b,
+    0x65, 0x72, 0x12, 0x14, 0x0a, 0x05, 0x74, 0x61, 0x6b, 0x65, 0x72, 0x18, 0x04, 0x20, 0x01, 0x28,
+    0x0c, 0x52, 0x05, 0x74, 0x61, 0x6b, 0x65, 0x72, 0x12, 0x16, 0x0a, 0x06, 0x73, 0x65, 0x6c, 0x6c,
+    0x65, 0x72, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x06, 0x73, 0x65, 0x6c, 0x6c, 0x65, 0x72,
+    0x12, 0x14, 0x0a, 0x05, 0x62, 0x75, 0x79, 0x65, 0x72, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0c, 0x52,
+    0x05, 0x62, 0x75, 0x79, 0x65, 0x72, 0x12, 0x16, 0x0a, 0x06, 0x73, 0x63, 0x68, 0x65, 0x6d, 0x61,
+    0x18, 0x07, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06, 0x73, 0x63, 0x68, 0x65, 0x6d, 0x61, 0x12, 0x29,
+    0x0a, 0x10, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x5f, 0x61, 0x64, 0x64, 0x72, 0x65,
+    0x73, 0x73, 0x18, 0x08, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x0f, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61,
+    0x63, 0x74, 0x41, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x12, 0x19, 0x0a, 0x08, 0x74, 0x6f, 0x6b,
+    0x65, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x09, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x74, 0x6f, 0x6b,
+    0x65, 0x6e, 0x49, 0x64, 0x12, 0x1a, 0x0a, 0x08, 0x71, 0x75, 0x61, 0x6e, 0x74, 0x69, 0x74, 0x79,
+    0x18, 0x0a, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x71, 0x75, 0x61, 0x6e, 0x74, 0x69, 0x74, 0x79,
+    0x12, 0x23, 0x0a, 0x0d, 0x70, 0x61, 0x79, 0x6d, 0x65, 0x6e, 0x74, 0x5f, 0x74, 0x6f, 0x6b, 0x65,
+    0x6e, 0x18, 0x0b, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x0c, 0x70, 0x61, 0x79, 0x6d, 0x65, 0x6e, 0x74,
+    0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x12, 0x14, 0x0a, 0x05, 0x70, 0x72, 0x69, 0x63, 0x65, 0x18, 0x0c,
+    0x20, 0x01, 0x28, 0x09, 0x52, 0x05, 0x70, 0x72, 0x69, 0x63, 0x65, 0x12, 0x21, 0x0a, 0x0c, 0x70,
+    0x72, 0x6f, 0x74, 0x6f, 0x63, 0x6f, 0x6c, 0x5f, 0x66, 0x65, 0x65, 0x18, 0x0d, 0x20, 0x01, 0x28,
+    0x09, 0x52, 0x0b, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x63, 0x6f, 0x6c, 0x46, 0x65, 0x65, 0x12, 0x1f,
+    0x0a, 0x0b, 0x72, 0x6f, 0x79, 0x61, 0x6c, 0x74, 0x79, 0x5f, 0x66, 0x65, 0x65, 0x18, 0x0e, 0x20,
+    0x01, 0x28, 0x09, 0x52, 0x0a, 0x72, 0x6f, 0x79, 0x61, 0x6c, 0x74, 0x79, 0x46, 0x65, 0x65, 0x12,
+    0x17, 0x0a, 0x07, 0x74, 0x78, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x18, 0x0f, 0x20, 0x01, 0x28, 0x0c,
+    0x52, 0x06, 0x74, 0x78, 0x48, 0x61, 0x73, 0x68, 0x12, 0x18, 0x0a, 0x07, 0x6f, 0x72, 0x64, 0x69,
+    0x6e, 0x61, 0x6c, 0x18, 0x10, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x6f, 0x72, 0x64, 0x69, 0x6e,
+    0x61, 0x6c, 0x12, 0x21, 0x0a, 0x0c, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x5f, 0x6e, 0x75, 0x6d, 0x62,
+    0x65, 0x72, 0x18, 0x11, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x4e,
+    0x75, 0x6d, 0x62, 0x65, 0x72, 0x12, 0x38, 0x0a, 0x09, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61,
+    0x6d, 0x70, 0x18, 0x12, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1a, 0x2e, 0x67, 0x6f, 0x6f, 0x67, 0x6c,
+    0x65, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x2e, 0x54, 0x69, 0x6d, 0x65, 0x73,
+    0x74, 0x61, 0x6d, 0x70, 0x52, 0x09, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x12,
+    0x1e, 0x0a, 0x0a, 0x63, 0x6f, 0x6e, 0x66, 0x69, 0x64, 0x65, 0x6e, 0x63, 0x65, 0x18, 0x13, 0x20,
+    0x01, 0x28, 0x0d, 0x52, 0x0a, 0x63, 0x6f, 0x6e, 0x66, 0x69, 0x64, 0x65, 0x6e, 0x63, 0x65, 0x12,
+    0x1b, 0x0a, 0x09, 0x62, 0x75, 0x6e, 0x64, 0x6c, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x14, 0x20, 0x01,
+    0x28, 0x09, 0x52, 0x08, 0x62, 0x75, 0x6e, 0x64, 0x6c, 0x65, 0x49, 0x64, 0x12, 0x1f, 0x0a, 0x0b,
+    0x62, 0x75, 0x6e, 0x64, 0x6c, 0x65, 0x5f, 0x73, 0x69, 0x7a, 0x65, 0x18, 0x15, 0x20, 0x01, 0x28,
+    0x0d, 0x52, 0x0a, 0x62, 0x75, 0x6e, 0x64, 0x6c, 0x65, 0x53, 0x69, 0x7a, 0x65, 0x12, 0x21, 0x0a,
+    0x0c, 0x62, 0x75, 0x6e, 0x64, 0x6c, 0x65, 0x5f, 0x70, 0x72, 0x69, 0x63, 0x65, 0x18, 0x16, 0x20,
+    0x01, 0x28, 0x09, 0x52, 0x0b, 0x62, 0x75, 0x6e, 0x64, 0x6c, 0x65, 0x50, 0x72, 0x69, 0x63, 0x65,
+    0x12, 0x1e, 0x0a, 0x0a, 0x61, 0x6c, 0x6c, 0x6f, 0x63, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x17,
+    0x20, 0x01, 0x28, 0x09, 0x52, 0x0a, 0x61, 0x6c, 0x6c, 0x6f, 0x63, 0x61, 0x74, 0x69, 0x6f, 0x6e,
+    0x12, 0x22, 0x0a, 0x0d, 0x65, 0x74, 0x68, 0x5f, 0x75, 0x73, 0x64, 0x5f, 0x70, 0x72, 0x69, 0x63,
+    0x65, 0x18, 0x18, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x65, 0x74, 0x68, 0x55, 0x73, 0x64, 0x50,
+    0x72, 0x69, 0x63, 0x65, 0x12, 0x1b, 0x0a, 0x09, 0x70, 0x72, 0x69, 0x63, 0x65, 0x5f, 0x75, 0x73,
+    0x64, 0x18, 0x19, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x70, 0x72, 0x69, 0x63, 0x65, 0x55, 0x73,
+    0x64, 0x12, 0x1d, 0x0a, 0x0a, 0x77, 0x61, 0x73, 0x68, 0x5f, 0x73, 0x63, 0x6f, 0x72, 0x65, 0x18,
+    0x1a, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x09, 0x77, 0x61, 0x73, 0x68, 0x53, 0x63, 0x6f, 0x72, 0x65,
+    0x12, 0x21, 0x0a, 0x0c, 0x77, 0x61, 0x73, 0x68, 0x5f, 0x72, 0x65, 0x61, 0x73, 0x6f, 0x6e, 0x73,
+    0x18, 0x1b, 0x20, 0x03, 0x28, 0x09, 0x52, 0x0b, 0x77, 0x61, 0x73, 0x68, 0x52, 0x65, 0x61, 0x73,
+    0x6f, 0x6e, 0x73, 0x22, 0x2d, 0x0a, 0x0a, 0x41, 0x6c, 0x6c, 0x6f, 0x63, 0x61, 0x74, 0x69, 0x6f,
+    0x6e, 0x12, 0x0a, 0x0a, 0x06, 0x73, 0x69, 0x6e, 0x67, 0x6c, 0x65, 0x10, 0x00, 0x12, 0x08, 0x0a,
+    0x04, 0x65, 0x76, 0x65, 0x6e, 0x10, 0x01, 0x12, 0x09, 0x0a, 0x05, 0x66, 0x6c, 0x6f, 0x6f, 0x72,
+    0x10, 0x02, 0x22, 0x47, 0x0a, 0x0a, 0x57, 0x61, 0x73, 0x68, 0x52, 0x65, 0x61, 0x73, 0x6f, 0x6e,
+    0x12, 0x1a, 0x0a, 0x16, 0x66, 0x75, 0x6e, 0x64, 0x65, 0x64, 0x5f, 0x62, 0x79, 0x5f, 0x63, 0x6f,
+    0x75, 0x6e, 0x74, 0x65, 0x72, 0x70, 0x61, 0x72, 0x74, 0x79, 0x10, 0x00, 0x12, 0x0e, 0x0a, 0x0a,
+    0x72, 0x6f, 0x75, 0x6e, 0x64, 0x5f, 0x74, 0x72, 0x69, 0x70, 0x10, 0x01, 0x12, 0x0d, 0x0a, 0x09,
+    0x7a, 0x65, 0x72, 0x6f, 0x5f, 0x63, 0x6f, 0x73, 0x74, 0x10, 0x02, 0x22, 0x31, 0x0a, 0x05, 0x4d,
+    0x69, 0x6e, 0x74, 0x73, 0x12, 0x28, 0x0a, 0x05, 0x6d, 0x69, 0x6e, 0x74, 0x73, 0x18, 0x01, 0x20,
+    0x03, 0x28, 0x0b, 0x32, 0x12, 0x2e, 0x65, 0x74, 0x68, 0x2e, 0x73, 0x61, 0x6c, 0x65, 0x73, 0x2e,
+    0x76, 0x31, 0x2e, 0x4d, 0x69, 0x6e, 0x74, 0x52, 0x05, 0x6d, 0x69, 0x6e, 0x74, 0x73, 0x22, 0xf0,
+    0x04, 0x0a, 0x04, 0x4d, 0x69, 0x6e, 0x74, 0x12, 0x16, 0x0a, 0x06, 0x73, 0x63, 0x68, 0x65, 0x6d,
+    0x61, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06, 0x73, 0x63, 0x68, 0x65, 0x6d, 0x61, 0x12,
+    0x29, 0x0a, 0x10, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x5f, 0x61, 0x64, 0x64, 0x72,
+    0x65, 0x73, 0x73, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x0f, 0x63, 0x6f, 0x6e, 0x74, 0x72,
+    0x61, 0x63, 0x74, 0x41, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x12, 0x19, 0x0a, 0x08, 0x74, 0x6f,
+    0x6b, 0x65, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x74, 0x6f,
+    0x6b, 0x65, 0x6e, 0x49, 0x64, 0x12, 0x1a, 0x0a, 0x08, 0x71, 0x75, 0x61, 0x6e, 0x74, 0x69, 0x74,
+    0x79, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x71, 0x75, 0x61, 0x6e, 0x74, 0x69, 0x74,
+    0x79, 0x12, 0x16, 0x0a, 0x06, 0x6d, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x18, 0x05, 0x20, 0x01, 0x28,
+    0x0c, 0x52, 0x06, 0x6d, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x12, 0x14, 0x0a, 0x05, 0x70, 0x61, 0x79,
+    0x65, 0x72, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x05, 0x70, 0x61, 0x79, 0x65, 0x72, 0x12,
+    0x23, 0x0a, 0x0d, 0x70, 0x61, 0x79, 0x6d, 0x65, 0x6e, 0x74, 0x5f, 0x74, 0x6f, 0x6b, 0x65, 0x6e,
+    0x18, 0x07, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x0c, 0x70, 0x61, 0x79, 0x6d, 0x65, 0x6e, 0x74, 0x54,
+    0x6f, 0x6b, 0x65, 0x6e, 0x12, 0x19, 0x0a, 0x08, 0x74, 0x78, 0x5f, 0x70, 0x72, 0x69, 0x63, 0x65,
+    0x18, 0x08, 0x20, 0x01, 0x28, 0x09, 0x52, 0x07, 0x74, 0x78, 0x50, 0x72, 0x69, 0x63, 0x65, 0x12,
+    0x1f, 0x0a, 0x0b, 0x74, 0x78, 0x5f, 0x71, 0x75, 0x61, 0x6e, 0x74, 0x69, 0x74, 0x79, 0x18, 0x09,
+    0x20, 0x01, 0x28, 0x09, 0x52, 0x0a, 0x74, 0x78, 0x51, 0x75, 0x61, 0x6e, 0x74, 0x69, 0x74, 0x79,
+    0x12, 0x26, 0x0a, 0x0f, 0x70, 0x72, 0x69, 0x63, 0x65, 0x5f, 0x70, 0x65, 0x72, 0x5f, 0x74, 0x6f,
+    0x6b, 0x65, 0x6e, 0x18, 0x0a, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0d, 0x70, 0x72, 0x69, 0x63, 0x65,
+    0x50, 0x65, 0x72, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x12, 0x12, 0x0a, 0x04, 0x66, 0x72, 0x65, 0x65,
+    0x18, 0x0b, 0x20, 0x01, 0x28, 0x08, 0x52, 0x04, 0x66, 0x72, 0x65, 0x65, 0x12, 0x1e, 0x0a, 0x0b,
+    0x74, 0x78, 0x5f, 0x67, 0x61, 0x73, 0x5f, 0x75, 0x73, 0x65, 0x64, 0x18, 0x0c, 0x20, 0x01, 0x28,
+    0x04, 0x52, 0x09, 0x74, 0x78, 0x47, 0x61, 0x73, 0x55, 0x73, 0x65, 0x64, 0x12, 0x20, 0x0a, 0x0c,
+    0x74, 0x78, 0x5f, 0x67, 0x61, 0x73, 0x5f, 0x70, 0x72, 0x69, 0x63, 0x65, 0x18, 0x0d, 0x20, 0x01,
+    0x28, 0x09, 0x52, 0x0a, 0x74, 0x78, 0x47, 0x61, 0x73, 0x50, 0x72, 0x69, 0x63, 0x65, 0x12, 0x17,
+    0x0a, 0x07, 0x74, 0x78, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x18, 0x0e, 0x20, 0x01, 0x28, 0x0c, 0x52,
+    0x06, 0x74, 0x78, 0x48, 0x61, 0x73, 0x68, 0x12, 0x18, 0x0a, 0x07, 0x6f, 0x72, 0x64, 0x69, 0x6e,
+    0x61, 0x6c, 0x18, 0x0f, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61,
+    0x6c, 0x12, 0x21, 0x0a, 0x0c, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x5f, 0x6e, 0x75, 0x6d, 0x62, 0x65,
+    0x72, 0x18, 0x10, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x4e, 0x75,
+    0x6d, 0x62, 0x65, 0x72, 0x12, 0x38, 0x0a, 0x09, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d,
+    0x70, 0x18, 0x11, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1a, 0x2e, 0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65,
+    0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x2e, 0x54, 0x69, 0x6d, 0x65, 0x73, 0x74,
+    0x61, 0x6d, 0x70, 0x52, 0x09, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x12, 0x22,
+    0x0a, 0x0d, 0x65, 0x74, 0x68, 0x5f, 0x75, 0x73, 0x64, 0x5f, 0x70, 0x72, 0x69, 0x63, 0x65, 0x18,
+    0x12, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x65, 0x74, 0x68, 0x55, 0x73, 0x64, 0x50, 0x72, 0x69,
+    0x63, 0x65, 0x12, 0x2d, 0x0a, 0x13, 0x70, 0x72, 0x69, 0x63, 0x65, 0x5f, 0x70, 0x65, 0x72, 0x5f,
+    0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x5f, 0x75, 0x73, 0x64, 0x18, 0x13, 0x20, 0x01, 0x28, 0x09, 0x52,
+    0x10, 0x70, 0x72, 0x69, 0x63, 0x65, 0x50, 0x65, 0x72, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x55, 0x73,
+    0x64, 0x4a, 0xc3, 0x3b, 0x0a, 0x07, 0x12, 0x05, 0x00, 0x00, 0xb7, 0x01, 0x01, 0x0a, 0x08, 0x0a,
+    0x01, 0x0c, 0x12, 0x03, 0x00, 0x00, 0x12, 0x0a, 0x08, 0x0a, 0x01, 0x02, 0x12, 0x03, 0x02, 0x00,
+    0x15, 0x0a, 0x09, 0x0a, 0x02, 0x03, 0x00, 0x12, 0x03, 0x04, 0x00, 0x29, 0x0a, 0x0a, 0x0a, 0x02,
+    0x04, 0x00, 0x12, 0x04, 0x06, 0x00, 0x08, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12,
+    0x03, 0x06, 0x08, 0x0d, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x07, 0x02,
+    0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x04, 0x12, 0x03, 0x07, 0x02, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x06, 0x12, 0x03, 0x07, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x07, 0x10, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x07, 0x18, 0x19, 0x0a, 0xdc, 0x01, 0x0a, 0x02, 0x04, 0x01,
+    0x12, 0x04, 0x0d, 0x00, 0x75, 0x01, 0x1a, 0xcf, 0x01, 0x20, 0x53, 0x61, 0x6c, 0x65, 0x20, 0x69,
+    0x73, 0x20, 0x61, 0x20, 0x73, 0x69, 0x6e, 0x67, 0x6c, 0x65, 0x20, 0x4e, 0x46, 0x54, 0x20, 0x63,
+    0x68, 0x61, 0x6e, 0x67, 0x69, 0x6e, 0x67, 0x20, 0x68, 0x61, 0x6e, 0x64, 0x73, 0x20, 0x74, 0x68,
+    0x72, 0x6f, 0x75, 0x67, 0x68, 0x20, 0x61, 0x20, 0x6d, 0x61, 0x72, 0x6b, 0x65, 0x74, 0x70, 0x6c,
+    0x61, 0x63, 0x65, 0x20, 0x73, 0x65, 0x74, 0x74, 0x6c, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x2e, 0x20,
+    0x41, 0x20, 0x73, 0x65, 0x74, 0x74, 0x6c, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x20, 0x74, 0x68, 0x61,
+    0x74, 0x20, 0x6d, 0x6f, 0x76, 0x65, 0x73, 0x0a, 0x20, 0x73, 0x65, 0x76, 0x65, 0x72, 0x61, 0x6c,
+    0x20, 0x4e, 0x46, 0x54, 0x73, 0x20, 0x61, 0x74, 0x20, 0x6f, 0x6e, 0x63, 0x65, 0x20, 0x70, 0x72,
+    0x6f, 0x64, 0x75, 0x63, 0x65, 0x73, 0x20, 0x6f, 0x6e, 0x65, 0x20, 0x53, 0x61, 0x6c, 0x65, 0x20,
+    0x70, 0x65, 0x72, 0x20, 0x4e, 0x46, 0x54, 0x2c, 0x20, 0x61, 0x6c, 0x6c, 0x20, 0x73, 0x68, 0x61,
+    0x72, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x61, 0x6d, 0x65, 0x20, 0x60, 0x74,
+    0x78, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x60, 0x2c, 0x20, 0x60, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x5f,
+    0x68, 0x61, 0x73, 0x68, 0x60, 0x20, 0x61, 0x6e, 0x64, 0x0a, 0x20, 0x60, 0x62, 0x75, 0x6e, 0x64,
+    0x6c, 0x65, 0x5f, 0x69, 0x64, 0x60, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01, 0x12,
+    0x03, 0x0d, 0x08, 0x0c, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x01, 0x04, 0x00, 0x12, 0x04, 0x0e, 0x02,
+    0x12, 0x03, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x04, 0x00, 0x01, 0x12, 0x03, 0x0e, 0x07, 0x11,
+    0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x01, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x0f, 0x04, 0x0f, 0x0a,
+    0x0e, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x0f, 0x04, 0x0a, 0x0a,
+    0x0e, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x0f, 0x0d, 0x0e, 0x0a,
+    0x0d, 0x0a, 0x06, 0x04, 0x01, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x10, 0x04, 0x0d, 0x0a, 0x0e,
+    0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x10, 0x04, 0x08, 0x0a, 0x0e,
+    0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x10, 0x0b, 0x0c, 0x0a, 0x0d,
+    0x0a, 0x06, 0x04, 0x01, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x11, 0x04, 0x0e, 0x0a, 0x0e, 0x0a,
+    0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x11, 0x04, 0x09, 0x0a, 0x0e, 0x0a,
+    0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x03, 0x11, 0x0c, 0x0d, 0x0a, 0x0c, 0x0a,
+    0x04, 0x04, 0x01, 0x04, 0x01, 0x12, 0x04, 0x14, 0x02, 0x18, 0x03, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x01, 0x04, 0x01, 0x01, 0x12, 0x03, 0x14, 0x07, 0x11, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x01, 0x04,
+    0x01, 0x02, 0x00, 0x12, 0x03, 0x15, 0x04, 0x1f, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x01,
+    0x02, 0x00, 0x01, 0x12, 0x03, 0x15, 0x04, 0x1a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x01,
+    0x02, 0x00, 0x02, 0x12, 0x03, 0x15, 0x1d, 0x1e, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x01, 0x04, 0x01,
+    0x02, 0x01, 0x12, 0x03, 0x16, 0x04, 0x13, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x01, 0x02,
+    0x01, 0x01, 0x12, 0x03, 0x16, 0x04, 0x0e, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x01, 0x02,
+    0x01, 0x02, 0x12, 0x03, 0x16, 0x11, 0x12, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x01, 0x04, 0x01, 0x02,
+    0x02, 0x12, 0x03, 0x17, 0x04, 0x12, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x01, 0x02, 0x02,
+    0x01, 0x12, 0x03, 0x17, 0x04, 0x0d, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x01, 0x02, 0x02,
+    0x02, 0x12, 0x03, 0x17, 0x10, 0x11, 0x0a, 0x5f, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x00, 0x12, 0x03,
+    0x1b, 0x02, 0x19, 0x1a, 0x52, 0x20, 0x4d, 0x61, 0x72, 0x6b, 0x65, 0x74, 0x70, 0x6c, 0x61, 0x63,
+    0x65, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6e, 0x61, 0x6d, 0x65, 0x20, 0x6f, 0x66,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x6d, 0x61, 0x72, 0x6b, 0x65, 0x74, 0x70, 0x6c, 0x61, 0x63, 0x65,
+    0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x73, 0x65, 0x74, 0x74, 0x6c, 0x65, 0x64, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x73, 0x61, 0x6c, 0x65, 0x2c, 0x20, 0x65, 0x2e, 0x67, 0x2e, 0x20, 0x60, 0x6e, 0x66,
+    0x74, 0x63, 0x6f, 0x6d, 0x60, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x05,
+    0x12, 0x03, 0x1b, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03,
+    0x1b, 0x09, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x1b, 0x17,
+    0x18, 0x0a, 0x83, 0x01, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x01, 0x12, 0x03, 0x1f, 0x02, 0x17, 0x1a,
+    0x76, 0x20, 0x4f, 0x72, 0x64, 0x65, 0x72, 0x48, 0x61, 0x73, 0x68, 0x20, 0x69, 0x64, 0x65, 0x6e,
+    0x74, 0x69, 0x66, 0x69, 0x65, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x65, 0x74, 0x74, 0x6c,
+    0x65, 0x64, 0x20, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x20, 0x6f, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x6d, 0x61, 0x72, 0x6b, 0x65, 0x74, 0x70, 0x6c, 0x61, 0x63, 0x65, 0x2e, 0x20, 0x46, 0x6f, 0x72,
+    0x20, 0x4e, 0x46, 0x54, 0x2e, 0x63, 0x6f, 0x6d, 0x2c, 0x20, 0x74, 0x68, 0x69, 0x73, 0x20, 0x69,
+    0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x74, 0x72, 0x75, 0x63, 0x74, 0x20, 0x68, 0x61, 0x73,
+    0x68, 0x0a, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6d, 0x61, 0x6b, 0x65, 0x72, 0x20,
+    0x6f, 0x72, 0x64, 0x65, 0x72, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x05,
+    0x12, 0x03, 0x1f, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x01, 0x12, 0x03,
+    0x1f, 0x08, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x03, 0x12, 0x03, 0x1f, 0x15,
+    0x16, 0x0a, 0x4a, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x02, 0x12, 0x03, 0x22, 0x02, 0x12, 0x1a, 0x3d,
+    0x20, 0x4d, 0x61, 0x6b, 0x65, 0x72, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x64,
+    0x64, 0x72, 0x65, 0x73, 0x73, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x73, 0x69, 0x67, 0x6e, 0x65,
+    0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x20, 0x74, 0x68, 0x61, 0x74,
+    0x20, 0x77, 0x61, 0x73, 0x20, 0x66, 0x69, 0x6c, 0x6c, 0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x01, 0x02, 0x02, 0x05, 0x12, 0x03, 0x22, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x01, 0x02, 0x02, 0x01, 0x12, 0x03, 0x22, 0x08, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
+    0x02, 0x03, 0x12, 0x03, 0x22, 0x10, 0x11, 0x0a, 0x40, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x03, 0x12,
+    0x03, 0x25, 0x02, 0x12, 0x1a, 0x33, 0x20, 0x54, 0x61, 0x6b, 0x65, 0x72, 0x20, 0x69, 0x73, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x20, 0x74, 0x68, 0x61, 0x74,
+    0x20, 0x66, 0x69, 0x6c, 0x6c, 0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6d, 0x61, 0x6b, 0x65,
+    0x72, 0x20, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
+    0x03, 0x05, 0x12, 0x03, 0x25, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x03, 0x01,
+    0x12, 0x03, 0x25, 0x08, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x03, 0x03, 0x12, 0x03,
+    0x25, 0x10, 0x11, 0x0a, 0x88, 0x01, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x04, 0x12, 0x03, 0x29, 0x02,
+    0x13, 0x1a, 0x7b, 0x20, 0x53, 0x65, 0x6c, 0x6c, 0x65, 0x72, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x67,
+    0x61, 0x76, 0x65, 0x20, 0x61, 0x77, 0x61, 0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x4e, 0x46, 0x54,
+    0x2c, 0x20, 0x65, 0x71, 0x75, 0x61, 0x6c, 0x20, 0x74, 0x6f, 0x20, 0x65, 0x69, 0x74, 0x68, 0x65,
+    0x72, 0x20, 0x60, 0x6d, 0x61, 0x6b, 0x65, 0x72, 0x60, 0x20, 0x6f, 0x72, 0x20, 0x60, 0x74, 0x61,
+    0x6b, 0x65, 0x72, 0x60, 0x20, 0x64, 0x65, 0x70, 0x65, 0x6e, 0x64, 0x69, 0x6e, 0x67, 0x20, 0x6f,
+    0x6e, 0x0a, 0x20, 0x77, 0x68, 0x69, 0x63, 0x68, 0x20, 0x73, 0x69, 0x64, 0x65, 0x20, 0x6c, 0x69,
+    0x73, 0x74, 0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x4e, 0x46, 0x54, 0x2e, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x01, 0x02, 0x04, 0x05, 0x12, 0x03, 0x29, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x01, 0x02, 0x04, 0x01, 0x12, 0x03, 0x29, 0x08, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
+    0x02, 0x04, 0x03, 0x12, 0x03, 0x29, 0x11, 0x12, 0x0a, 0x3a, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x05,
+    0x12, 0x03, 0x2c, 0x02, 0x12, 0x1a, 0x2d, 0x20, 0x42, 0x75, 0x79, 0x65, 0x72, 0x20, 0x69, 0x73,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x20, 0x74, 0x68, 0x61,
+    0x74, 0x20, 0x72, 0x65, 0x63, 0x65, 0x69, 0x76, 0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x4e,
+    0x46, 0x54, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x05, 0x05, 0x12, 0x03, 0x2c,
+    0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x05, 0x01, 0x12, 0x03, 0x2c, 0x08, 0x0d,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x05, 0x03, 0x12, 0x03, 0x2c, 0x10, 0x11, 0x0a, 0x5a,
+    0x0a, 0x04, 0x04, 0x01, 0x02, 0x06, 0x12, 0x03, 0x2f, 0x02, 0x14, 0x1a, 0x4d, 0x20, 0x53, 0x63,
+    0x68, 0x65, 0x6d, 0x61, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65,
+    0x6e, 0x20, 0x73, 0x74, 0x61, 0x6e, 0x64, 0x61, 0x72, 0x64, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x4e, 0x46, 0x54, 0x20, 0x73, 0x6f, 0x6c, 0x64, 0x2c, 0x20, 0x65, 0x69, 0x74, 0x68,
+    0x65, 0x72, 0x20, 0x60, 0x65, 0x72, 0x63, 0x37, 0x32, 0x31, 0x60, 0x20, 0x6f, 0x72, 0x20, 0x60,
+    0x65, 0x72, 0x63, 0x31, 0x31, 0x35, 0x35, 0x60, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
+    0x02, 0x06, 0x05, 0x12, 0x03, 0x2f, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x06,
+    0x01, 0x12, 0x03, 0x2f, 0x09, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x06, 0x03, 0x12,
+    0x03, 0x2f, 0x12, 0x13, 0x0a, 0x42, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x07, 0x12, 0x03, 0x32, 0x02,
+    0x1d, 0x1a, 0x35, 0x20, 0x43, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x41, 0x64, 0x64, 0x72,
+    0x65, 0x73, 0x73, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x64, 0x64, 0x72, 0x65,
+    0x73, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x4e, 0x46, 0x54, 0x20, 0x63, 0x6f,
+    0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x07,
+    0x05, 0x12, 0x03, 0x32, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x07, 0x01, 0x12,
+    0x03, 0x32, 0x08, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x07, 0x03, 0x12, 0x03, 0x32,
+    0x1b, 0x1c, 0x0a, 0x4e, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x08, 0x12, 0x03, 0x35, 0x02, 0x15, 0x1a,
+    0x41, 0x20, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x49, 0x44, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x69, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x4e, 0x46, 0x54, 0x20, 0x77, 0x69, 0x74, 0x68, 0x69, 0x6e, 0x20, 0x60, 0x63,
+    0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x5f, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x60,
+    0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x08, 0x05, 0x12, 0x03, 0x35, 0x02, 0x07,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x08, 0x01, 0x12, 0x03, 0x35, 0x08, 0x10, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x01, 0x02, 0x08, 0x03, 0x12, 0x03, 0x35, 0x13, 0x14, 0x0a, 0x5b, 0x0a, 0x04,
+    0x04, 0x01, 0x02, 0x09, 0x12, 0x03, 0x38, 0x02, 0x17, 0x1a, 0x4e, 0x20, 0x48, 0x6f, 0x77, 0x20,
+    0x6d, 0x61, 0x6e, 0x79, 0x20, 0x75, 0x6e, 0x69, 0x74, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x4e, 0x46, 0x54, 0x20, 0x77, 0x65, 0x72, 0x65, 0x20, 0x73, 0x6f, 0x6c, 0x64, 0x2c,
+    0x20, 0x77, 0x69, 0x6c, 0x6c, 0x20, 0x61, 0x6c, 0x77, 0x61, 0x79, 0x73, 0x20, 0x62, 0x65, 0x20,
+    0x31, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x61, 0x73, 0x65, 0x20, 0x6f, 0x66,
+    0x20, 0x45, 0x52, 0x43, 0x37, 0x32, 0x31, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
+    0x09, 0x05, 0x12, 0x03, 0x38, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x09, 0x01,
+    0x12, 0x03, 0x38, 0x09, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x09, 0x03, 0x12, 0x03,
+    0x38, 0x14, 0x16, 0x0a, 0x69, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x0a, 0x12, 0x03, 0x3b, 0x02, 0x1b,
+    0x1a, 0x5c, 0x20, 0x50, 0x61, 0x79, 0x6d, 0x65, 0x6e, 0x74, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x20,
+    0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x20, 0x6f,
+    0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x45, 0x52, 0x43, 0x32, 0x30, 0x20, 0x75, 0x73, 0x65, 0x64,
+    0x20, 0x74, 0x6f, 0x20, 0x70, 0x61, 0x79, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x73, 0x61, 0x6c, 0x65, 0x2c, 0x20, 0x65, 0x6d, 0x70, 0x74, 0x79, 0x20, 0x77, 0x68, 0x65, 0x6e,
+    0x20, 0x70, 0x61, 0x69, 0x64, 0x20, 0x69, 0x6e, 0x20, 0x45, 0x54, 0x48, 0x2e, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x01, 0x02, 0x0a, 0x05, 0x12, 0x03, 0x3b, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x01, 0x02, 0x0a, 0x01, 0x12, 0x03, 0x3b, 0x08, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
+    0x02, 0x0a, 0x03, 0x12, 0x03, 0x3b, 0x18, 0x1a, 0x0a, 0xb4, 0x01, 0x0a, 0x04, 0x04, 0x01, 0x02,
+    0x0b, 0x12, 0x03, 0x3f, 0x02, 0x14, 0x1a, 0xa6, 0x01, 0x20, 0x50, 0x72, 0x69, 0x63, 0x65, 0x20,
+    0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x6d, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x70, 0x61,
+    0x69, 0x64, 0x20, 0x62, 0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x75, 0x79, 0x65, 0x72, 0x20,
+    0x66, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x69, 0x73, 0x20, 0x4e, 0x46, 0x54, 0x2c, 0x20, 0x69, 0x6e,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x6d, 0x61, 0x6c, 0x6c, 0x65, 0x73, 0x74, 0x20, 0x75, 0x6e,
+    0x69, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x60, 0x70, 0x61, 0x79, 0x6d, 0x65, 0x6e, 0x74, 0x5f, 0x74,
+    0x6f, 0x6b, 0x65, 0x6e, 0x60, 0x2e, 0x0a, 0x20, 0x46, 0x6f, 0x72, 0x20, 0x61, 0x20, 0x62, 0x75,
+    0x6e, 0x64, 0x6c, 0x65, 0x2c, 0x20, 0x74, 0x68, 0x69, 0x73, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x70, 0x61, 0x72, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x60, 0x62, 0x75, 0x6e, 0x64, 0x6c,
+    0x65, 0x5f, 0x70, 0x72, 0x69, 0x63, 0x65, 0x60, 0x20, 0x61, 0x6c, 0x6c, 0x6f, 0x63, 0x61, 0x74,
+    0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x4e, 0x46, 0x54, 0x2e, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0b, 0x05, 0x12, 0x03, 0x3f, 0x02, 0x08, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x01, 0x02, 0x0b, 0x01, 0x12, 0x03, 0x3f, 0x09, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x01, 0x02, 0x0b, 0x03, 0x12, 0x03, 0x3f, 0x11, 0x13, 0x0a, 0x4f, 0x0a, 0x04, 0x04, 0x01, 0x02,
+    0x0c, 0x12, 0x03, 0x42, 0x02, 0x1b, 0x1a, 0x42, 0x20, 0x50, 0x72, 0x6f, 0x74, 0x6f, 0x63, 0x6f,
+    0x6c, 0x46, 0x65, 0x65, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x61, 0x72, 0x74,
+    0x20, 0x6f, 0x66, 0x20, 0x60, 0x70, 0x72, 0x69, 0x63, 0x65, 0x60, 0x20, 0x74, 0x68, 0x61, 0x74,
+    0x20, 0x77, 0x65, 0x6e, 0x74, 0x20, 0x74, 0x6f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6d, 0x61, 0x72,
+    0x6b, 0x65, 0x74, 0x70, 0x6c, 0x61, 0x63, 0x65, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
+    0x02, 0x0c, 0x05, 0x12, 0x03, 0x42, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0c,
+    0x01, 0x12, 0x03, 0x42, 0x09, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0c, 0x03, 0x12,
+    0x03, 0x42, 0x18, 0x1a, 0x0a, 0x5c, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x0d, 0x12, 0x03, 0x45, 0x02,
+    0x1a, 0x1a, 0x4f, 0x20, 0x52, 0x6f, 0x79, 0x61, 0x6c, 0x74, 0x79, 0x46, 0x65, 0x65, 0x20, 0x69,
+    0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x61, 0x72, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x60, 0x70,
+    0x72, 0x69, 0x63, 0x65, 0x60, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x77, 0x65, 0x6e, 0x74, 0x20,
+    0x74, 0x6f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x72, 0x65, 0x61, 0x74, 0x6f, 0x72, 0x20, 0x6f,
+    0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x6c, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e,
+    0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0d, 0x05, 0x12, 0x03, 0x45, 0x02, 0x08,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0d, 0x01, 0x12, 0x03, 0x45, 0x09, 0x14, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x01, 0x02, 0x0d, 0x03, 0x12, 0x03, 0x45, 0x17, 0x19, 0x0a, 0x34, 0x0a, 0x04,
+    0x04, 0x01, 0x02, 0x0e, 0x12, 0x03, 0x48, 0x02, 0x15, 0x1a, 0x27, 0x20, 0x54, 0x78, 0x48, 0x61,
+    0x73, 0x68, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x68, 0x61, 0x73, 0x68, 0x20, 0x6f,
+    0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f,
+    0x6e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0e, 0x05, 0x12, 0x03, 0x48, 0x02, 0x07,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0e, 0x01, 0x12, 0x03, 0x48, 0x08, 0x0f, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x01, 0x02, 0x0e, 0x03, 0x12, 0x03, 0x48, 0x12, 0x14, 0x0a, 0x41, 0x0a, 0x04,
+    0x04, 0x01, 0x02, 0x0f, 0x12, 0x03, 0x4b, 0x02, 0x16, 0x1a, 0x34, 0x20, 0x4f, 0x72, 0x64, 0x69,
+    0x6e, 0x61, 0x6c, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x64, 0x65, 0x72,
+    0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x61, 0x6c, 0x65, 0x20, 0x77, 0x69, 0x74,
+    0x68, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2e, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0f, 0x05, 0x12, 0x03, 0x4b, 0x02, 0x08, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x01, 0x02, 0x0f, 0x01, 0x12, 0x03, 0x4b, 0x09, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x01, 0x02, 0x0f, 0x03, 0x12, 0x03, 0x4b, 0x13, 0x15, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02,
+    0x10, 0x12, 0x03, 0x4d, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x10, 0x05, 0x12,
+    0x03, 0x4d, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x10, 0x01, 0x12, 0x03, 0x4d,
+    0x09, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x10, 0x03, 0x12, 0x03, 0x4d, 0x18, 0x1a,
+    0x0a, 0x37, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x11, 0x12, 0x03, 0x50, 0x02, 0x2b, 0x1a, 0x2a, 0x20,
+    0x54, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
+    0x11, 0x06, 0x12, 0x03, 0x50, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x11, 0x01,
+    0x12, 0x03, 0x50, 0x1c, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x11, 0x03, 0x12, 0x03,
+    0x50, 0x28, 0x2a, 0x0a, 0xe1, 0x01, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x12, 0x12, 0x03, 0x55, 0x02,
+    0x19, 0x1a, 0xd3, 0x01, 0x20, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x64, 0x65, 0x6e, 0x63, 0x65, 0x20,
+    0x69, 0x73, 0x20, 0x68, 0x6f, 0x77, 0x20, 0x6c, 0x69, 0x6b, 0x65, 0x6c, 0x79, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x20, 0x69, 0x73, 0x20, 0x61, 0x20,
+    0x73, 0x61, 0x6c, 0x65, 0x2c, 0x20, 0x66, 0x72, 0x6f, 0x6d, 0x20, 0x30, 0x20, 0x74, 0x6f, 0x20,
+    0x31, 0x30, 0x30, 0x2e, 0x20, 0x53, 0x61, 0x6c, 0x65, 0x73, 0x20, 0x64, 0x65, 0x63, 0x6f, 0x64,
+    0x65, 0x64, 0x20, 0x66, 0x72, 0x6f, 0x6d, 0x20, 0x61, 0x20, 0x6b, 0x6e, 0x6f, 0x77, 0x6e, 0x0a,
+    0x20, 0x6d, 0x61, 0x72, 0x6b, 0x65, 0x74, 0x70, 0x6c, 0x61, 0x63, 0x65, 0x20, 0x61, 0x72, 0x65,
+    0x20, 0x61, 0x6c, 0x77, 0x61, 0x79, 0x73, 0x20, 0x31, 0x30, 0x30, 0x2c, 0x20, 0x73, 0x61, 0x6c,
+    0x65, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x60, 0x75, 0x6e, 0x6b, 0x6e, 0x6f,
+    0x77, 0x6e, 0x60, 0x20, 0x6d, 0x61, 0x72, 0x6b, 0x65, 0x74, 0x70, 0x6c, 0x61, 0x63, 0x65, 0x20,
+    0x61, 0x72, 0x65, 0x20, 0x69, 0x6e, 0x66, 0x65, 0x72, 0x72, 0x65, 0x64, 0x20, 0x66, 0x72, 0x6f,
+    0x6d, 0x20, 0x74, 0x68, 0x65, 0x20, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x0a, 0x20, 0x66, 0x6c, 0x6f,
+    0x77, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61,
+    0x63, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x12, 0x05,
+    0x12, 0x03, 0x55, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x12, 0x01, 0x12, 0x03,
+    0x55, 0x09, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x12, 0x03, 0x12, 0x03, 0x55, 0x16,
+    0x18, 0x0a, 0x71, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x13, 0x12, 0x03, 0x59, 0x02, 0x18, 0x1a, 0x64,
+    0x20, 0x42, 0x75, 0x6e, 0x64, 0x6c, 0x65, 0x49, 0x44, 0x20, 0x69, 0x64, 0x65, 0x6e, 0x74, 0x69,
+    0x66, 0x69, 0x65, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x4e, 0x46, 0x54, 0x73, 0x20, 0x73, 0x6f,
+    0x6c, 0x64, 0x20, 0x74, 0x6f, 0x67, 0x65, 0x74, 0x68, 0x65, 0x72, 0x20, 0x66, 0x6f, 0x72, 0x20,
+    0x61, 0x20, 0x73, 0x69, 0x6e, 0x67, 0x6c, 0x65, 0x20, 0x70, 0x72, 0x69, 0x63, 0x65, 0x2c, 0x20,
+    0x65, 0x6d, 0x70, 0x74, 0x79, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x4e,
+    0x46, 0x54, 0x20, 0x77, 0x61, 0x73, 0x20, 0x73, 0x6f, 0x6c, 0x64, 0x0a, 0x20, 0x61, 0x6c, 0x6f,
+    0x6e, 0x65, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x13, 0x05, 0x12, 0x03, 0x59,
+    0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x13, 0x01, 0x12, 0x03, 0x59, 0x09, 0x12,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x13, 0x03, 0x12, 0x03, 0x59, 0x15, 0x17, 0x0a, 0x5d,
+    0x0a, 0x04, 0x04, 0x01, 0x02, 0x14, 0x12, 0x03, 0x5c, 0x02, 0x1a, 0x1a, 0x50, 0x20, 0x42, 0x75,
+    0x6e, 0x64, 0x6c, 0x65, 0x53, 0x69, 0x7a, 0x65, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x4e, 0x46, 0x54, 0x73, 0x20, 0x73,
+    0x6f, 0x6c, 0x64, 0x20, 0x74, 0x6f, 0x67, 0x65, 0x74, 0x68, 0x65, 0x72, 0x2c, 0x20, 0x31, 0x20,
+    0x77, 0x68, 0x65, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x4e, 0x46, 0x54, 0x20, 0x77, 0x61, 0x73,
+    0x20, 0x73, 0x6f, 0x6c, 0x64, 0x20, 0x61, 0x6c, 0x6f, 0x6e, 0x65, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x01, 0x02, 0x14, 0x05, 0x12, 0x03, 0x5c, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x01, 0x02, 0x14, 0x01, 0x12, 0x03, 0x5c, 0x09, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
+    0x14, 0x03, 0x12, 0x03, 0x5c, 0x17, 0x19, 0x0a, 0x7f, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x15, 0x12,
+    0x03, 0x60, 0x02, 0x1b, 0x1a, 0x72, 0x20, 0x42, 0x75, 0x6e, 0x64, 0x6c, 0x65, 0x50, 0x72, 0x69,
+    0x63, 0x65, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x74, 0x61, 0x6c, 0x20,
+    0x61, 0x6d, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x70, 0x61, 0x69, 0x64, 0x20, 0x62, 0x79, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x62, 0x75, 0x79, 0x65, 0x72, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x62, 0x75, 0x6e, 0x64, 0x6c, 0x65, 0x2c, 0x20, 0x65, 0x71, 0x75, 0x61, 0x6c, 0x20, 0x74,
+    0x6f, 0x20, 0x60, 0x70, 0x72, 0x69, 0x63, 0x65, 0x60, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x4e, 0x46, 0x54, 0x0a, 0x20, 0x77, 0x61, 0x73, 0x20, 0x73, 0x6f, 0x6c, 0x64,
+    0x20, 0x61, 0x6c, 0x6f, 0x6e, 0x65, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x15,
+    0x05, 0x12, 0x03, 0x60, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x15, 0x01, 0x12,
+    0x03, 0x60, 0x09, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x15, 0x03, 0x12, 0x03, 0x60,
+    0x18, 0x1a, 0x0a, 0x8c, 0x02, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x16, 0x12, 0x03, 0x65, 0x02, 0x19,
+    0x1a, 0xfe, 0x01, 0x20, 0x41, 0x6c, 0x6c, 0x6f, 0x63, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x69,
+    0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x20, 0x72, 0x65, 0x70,
+    0x72, 0x65, 0x73, 0x65, 0x6e, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x6f, 0x66, 0x20, 0x6f,
+    0x6e, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x6e, 0x75, 0x6d, 0x20, 0x64,
+    0x65, 0x66, 0x69, 0x6e, 0x65, 0x64, 0x20, 0x69, 0x6e, 0x20, 0x41, 0x6c, 0x6c, 0x6f, 0x63, 0x61,
+    0x74, 0x69, 0x6f, 0x6e, 0x2c, 0x20, 0x68, 0x6f, 0x77, 0x0a, 0x20, 0x60, 0x62, 0x75, 0x6e, 0x64,
+    0x6c, 0x65, 0x5f, 0x70, 0x72, 0x69, 0x63, 0x65, 0x60, 0x20, 0x77, 0x61, 0x73, 0x20, 0x73, 0x70,
+    0x6c, 0x69, 0x74, 0x20, 0x69, 0x6e, 0x74, 0x6f, 0x20, 0x60, 0x70, 0x72, 0x69, 0x63, 0x65, 0x60,
+    0x3a, 0x20, 0x60, 0x73, 0x69, 0x6e, 0x67, 0x6c, 0x65, 0x60, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x61,
+    0x6e, 0x20, 0x4e, 0x46, 0x54, 0x20, 0x73, 0x6f, 0x6c, 0x64, 0x20, 0x61, 0x6c, 0x6f, 0x6e, 0x65,
+    0x2c, 0x20, 0x60, 0x65, 0x76, 0x65, 0x6e, 0x60, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x61, 0x20, 0x73,
+    0x70, 0x6c, 0x69, 0x74, 0x20, 0x70, 0x65, 0x72, 0x0a, 0x20, 0x75, 0x6e, 0x69, 0x74, 0x20, 0x61,
+    0x6e, 0x64, 0x20, 0x60, 0x66, 0x6c, 0x6f, 0x6f, 0x72, 0x60, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x61,
+    0x20, 0x73, 0x70, 0x6c, 0x69, 0x74, 0x20, 0x70, 0x72, 0x6f, 0x2d, 0x72, 0x61, 0x74, 0x61, 0x20,
+    0x74, 0x6f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x66, 0x6c, 0x6f, 0x6f, 0x72, 0x20, 0x6f, 0x66, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x6c, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x2e,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x16, 0x05, 0x12, 0x03, 0x65, 0x02, 0x08, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x16, 0x01, 0x12, 0x03, 0x65, 0x09, 0x13, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x01, 0x02, 0x16, 0x03, 0x12, 0x03, 0x65, 0x16, 0x18, 0x0a, 0x6a, 0x0a, 0x04, 0x04,
+    0x01, 0x02, 0x17, 0x12, 0x03, 0x68, 0x02, 0x1c, 0x1a, 0x5d, 0x20, 0x45, 0x74, 0x68, 0x55, 0x73,
+    0x64, 0x50, 0x72, 0x69, 0x63, 0x65, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x72,
+    0x69, 0x63, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x6f, 0x6e, 0x65, 0x20, 0x45, 0x54, 0x48, 0x20, 0x69,
+    0x6e, 0x20, 0x55, 0x53, 0x44, 0x20, 0x61, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f,
+    0x63, 0x6b, 0x2c, 0x20, 0x73, 0x65, 0x74, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x73, 0x61, 0x6c, 0x65,
+    0x73, 0x20, 0x70, 0x61, 0x69, 0x64, 0x20, 0x69, 0x6e, 0x20, 0x45, 0x54, 0x48, 0x20, 0x6f, 0x72,
+    0x20, 0x57, 0x45, 0x54, 0x48, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x17, 0x05,
+    0x12, 0x03, 0x68, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x17, 0x01, 0x12, 0x03,
+    0x68, 0x09, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x17, 0x03, 0x12, 0x03, 0x68, 0x19,
+    0x1b, 0x0a, 0x6c, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x18, 0x12, 0x03, 0x6b, 0x02, 0x18, 0x1a, 0x5f,
+    0x20, 0x50, 0x72, 0x69, 0x63, 0x65, 0x55, 0x53, 0x44, 0x20, 0x69, 0x73, 0x20, 0x60, 0x70, 0x72,
+    0x69, 0x63, 0x65, 0x60, 0x20, 0x63, 0x6f, 0x6e, 0x76, 0x65, 0x72, 0x74, 0x65, 0x64, 0x20, 0x74,
+    0x6f, 0x20, 0x55, 0x53, 0x44, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x60, 0x65, 0x74, 0x68, 0x5f,
+    0x75, 0x73, 0x64, 0x5f, 0x70, 0x72, 0x69, 0x63, 0x65, 0x60, 0x2c, 0x20, 0x73, 0x65, 0x74, 0x20,
+    0x66, 0x6f, 0x72, 0x20, 0x73, 0x61, 0x6c, 0x65, 0x73, 0x20, 0x70, 0x61, 0x69, 0x64, 0x20, 0x69,
+    0x6e, 0x20, 0x45, 0x54, 0x48, 0x20, 0x6f, 0x72, 0x20, 0x57, 0x45, 0x54, 0x48, 0x2e, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x18, 0x05, 0x12, 0x03, 0x6b, 0x02, 0x08, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x01, 0x02, 0x18, 0x01, 0x12, 0x03, 0x6b, 0x09, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x01, 0x02, 0x18, 0x03, 0x12, 0x03, 0x6b, 0x15, 0x17, 0x0a, 0x4f, 0x0a, 0x04, 0x04, 0x01, 0x02,
+    0x19, 0x12, 0x03, 0x6e, 0x02, 0x19, 0x1a, 0x42, 0x20, 0x57, 0x61, 0x73, 0x68, 0x53, 0x63, 0x6f,
+    0x72, 0x65, 0x20, 0x69, 0x73, 0x20, 0x68, 0x6f, 0x77, 0x20, 0x6c, 0x69, 0x6b, 0x65, 0x6c, 0x79,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x61, 0x6c, 0x65, 0x20, 0x69, 0x73, 0x20, 0x61, 0x20, 0x77,
+    0x61, 0x73, 0x68, 0x20, 0x74, 0x72, 0x61, 0x64, 0x65, 0x2c, 0x20, 0x66, 0x72, 0x6f, 0x6d, 0x20,
+    0x30, 0x20, 0x74, 0x6f, 0x20, 0x31, 0x30, 0x30, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
+    0x02, 0x19, 0x05, 0x12, 0x03, 0x6e, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x19,
+    0x01, 0x12, 0x03, 0x6e, 0x09, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x19, 0x03, 0x12,
+    0x03, 0x6e, 0x16, 0x18, 0x0a, 0xcd, 0x02, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x1a, 0x12, 0x03, 0x74,
+    0x02, 0x24, 0x1a, 0xbf, 0x02, 0x20, 0x57, 0x61, 0x73, 0x68, 0x52, 0x65, 0x61, 0x73, 0x6f, 0x6e,
+    0x73, 0x20, 0x61, 0x72, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67,
+    0x20, 0x72, 0x65, 0x70, 0x72, 0x65, 0x73, 0x65, 0x6e, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x73,
+    0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x6e, 0x75, 0x6d, 0x20, 0x64, 0x65, 0x66,
+    0x69, 0x6e, 0x65, 0x64, 0x20, 0x69, 0x6e, 0x20, 0x57, 0x61, 0x73, 0x68, 0x52, 0x65, 0x61, 0x73,
+    0x6f, 0x6e, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x72, 0x61, 0x69, 0x73, 0x65, 0x64, 0x0a, 0x20,
+    0x60, 0x77, 0x61, 0x73, 0x68, 0x5f, 0x73, 0x63, 0x6f, 0x72, 0x65, 0x60, 0x3a, 0x20, 0x60, 0x66,
+    0x75, 0x6e, 0x64, 0x65, 0x64, 0x5f, 0x62, 0x79, 0x5f, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x65, 0x72,
+    0x70, 0x61, 0x72, 0x74, 0x79, 0x60, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x62, 0x75, 0x79, 0x65, 0x72, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x65,
+    0x6c, 0x6c, 0x65, 0x72, 0x20, 0x66, 0x75, 0x6e, 0x64, 0x65, 0x64, 0x20, 0x65, 0x61, 0x63, 0x68,
+    0x20, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x20, 0x62, 0x65, 0x66, 0x6f, 0x72, 0x65, 0x0a, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x73, 0x61, 0x6c, 0x65, 0x2c, 0x20, 0x60, 0x72, 0x6f, 0x75, 0x6e, 0x64, 0x5f,
+    0x74, 0x72, 0x69, 0x70, 0x60, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x4e,
+    0x46, 0x54, 0x20, 0x72, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x73, 0x20, 0x74, 0x6f, 0x20, 0x61, 0x20,
+    0x77, 0x61, 0x6c, 0x6c, 0x65, 0x74, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x73, 0x6f, 0x6c, 0x64,
+    0x20, 0x69, 0x74, 0x20, 0x72, 0x65, 0x63, 0x65, 0x6e, 0x74, 0x6c, 0x79, 0x20, 0x61, 0x6e, 0x64,
+    0x20, 0x60, 0x7a, 0x65, 0x72, 0x6f, 0x5f, 0x63, 0x6f, 0x73, 0x74, 0x60, 0x0a, 0x20, 0x77, 0x68,
+    0x65, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x65, 0x6c, 0x6c, 0x65, 0x72, 0x20, 0x67, 0x6f,
+    0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x4e, 0x46, 0x54, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x66, 0x72,
+    0x65, 0x65, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x1a, 0x04, 0x12, 0x03, 0x74,
+    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x1a, 0x05, 0x12, 0x03, 0x74, 0x0b, 0x11,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x1a, 0x01, 0x12, 0x03, 0x74, 0x12, 0x1e, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x01, 0x02, 0x1a, 0x03, 0x12, 0x03, 0x74, 0x21, 0x23, 0x0a, 0x0a, 0x0a, 0x02,
+    0x04, 0x02, 0x12, 0x04, 0x76, 0x00, 0x78, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x02, 0x01, 0x12,
+    0x03, 0x76, 0x08, 0x0d, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x00, 0x12, 0x03, 0x77, 0x02,
+    0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x04, 0x12, 0x03, 0x77, 0x02, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x06, 0x12, 0x03, 0x77, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x02, 0x02, 0x00, 0x01, 0x12, 0x03, 0x77, 0x10, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x02, 0x02, 0x00, 0x03, 0x12, 0x03, 0x77, 0x18, 0x19, 0x0a, 0xa6, 0x01, 0x0a, 0x02, 0x04, 0x03,
+    0x12, 0x05, 0x7c, 0x00, 0xb7, 0x01, 0x01, 0x1a, 0x98, 0x01, 0x20, 0x4d, 0x69, 0x6e, 0x74, 0x20,
+    0x69, 0x73, 0x20, 0x61, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73,
+    0x66, 0x65, 0x72, 0x72, 0x65, 0x64, 0x20, 0x66, 0x72, 0x6f, 0x6d, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x7a, 0x65, 0x72, 0x6f, 0x20, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x2c, 0x20, 0x77, 0x69,
+    0x74, 0x68, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x61, 0x79, 0x6d, 0x65, 0x6e, 0x74, 0x20, 0x6f,
+    0x66, 0x20, 0x69, 0x74, 0x73, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f,
+    0x6e, 0x20, 0x73, 0x70, 0x72, 0x65, 0x61, 0x64, 0x0a, 0x20, 0x65, 0x76, 0x65, 0x6e, 0x6c, 0x79,
+    0x20, 0x61, 0x63, 0x72, 0x6f, 0x73, 0x73, 0x20, 0x61, 0x6c, 0x6c, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x20, 0x6d, 0x69, 0x6e, 0x74, 0x65, 0x64, 0x20, 0x62, 0x79,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e,
+    0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03, 0x01, 0x12, 0x03, 0x7c, 0x08, 0x0c, 0x0a, 0x5c,
+    0x0a, 0x04, 0x04, 0x03, 0x02, 0x00, 0x12, 0x03, 0x7e, 0x02, 0x14, 0x1a, 0x4f, 0x20, 0x53, 0x63,
+    0x68, 0x65, 0x6d, 0x61, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65,
+    0x6e, 0x20, 0x73, 0x74, 0x61, 0x6e, 0x64, 0x61, 0x72, 0x64, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x4e, 0x46, 0x54, 0x20, 0x6d, 0x69, 0x6e, 0x74, 0x65, 0x64, 0x2c, 0x20, 0x65, 0x69,
+    0x74, 0x68, 0x65, 0x72, 0x20, 0x60, 0x65, 0x72, 0x63, 0x37, 0x32, 0x31, 0x60, 0x20, 0x6f, 0x72,
+    0x20, 0x60, 0x65, 0x72, 0x63, 0x31, 0x31, 0x35, 0x35, 0x60, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x03, 0x02, 0x00, 0x05, 0x12, 0x03, 0x7e, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03,
+    0x02, 0x00, 0x01, 0x12, 0x03, 0x7e, 0x09, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00,
+    0x03, 0x12, 0x03, 0x7e, 0x12, 0x13, 0x0a, 0x43, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x01, 0x12, 0x04,
+    0x81, 0x01, 0x02, 0x1d, 0x1a, 0x35, 0x20, 0x43, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x41,
+    0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x64,
+    0x64, 0x72, 0x65, 0x73, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x4e, 0x46, 0x54,
+    0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x03, 0x02, 0x01, 0x05, 0x12, 0x04, 0x81, 0x01, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x03,
+    0x02, 0x01, 0x01, 0x12, 0x04, 0x81, 0x01, 0x08, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x03, 0x02,
+    0x01, 0x03, 0x12, 0x04, 0x81, 0x01, 0x1b, 0x1c, 0x0a, 0x4f, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x02,
+    0x12, 0x04, 0x84, 0x01, 0x02, 0x15, 0x1a, 0x41, 0x20, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x49, 0x44,
+    0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x69, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x66, 0x69,
+    0x65, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x4e, 0x46, 0x54, 0x20, 0x77, 0x69,
+    0x74, 0x68, 0x69, 0x6e, 0x20, 0x60, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x5f, 0x61,
+    0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x60, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x03, 0x02,
+    0x02, 0x05, 0x12, 0x04, 0x84, 0x01, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x02,
+    0x01, 0x12, 0x04, 0x84, 0x01, 0x08, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x02, 0x03,
+    0x12, 0x04, 0x84, 0x01, 0x13, 0x14, 0x0a, 0x5e, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x03, 0x12, 0x04,
+    0x87, 0x01, 0x02, 0x16, 0x1a, 0x50, 0x20, 0x48, 0x6f, 0x77, 0x20, 0x6d, 0x61, 0x6e, 0x79, 0x20,
+    0x75, 0x6e, 0x69, 0x74, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x4e, 0x46, 0x54,
+    0x20, 0x77, 0x65, 0x72, 0x65, 0x20, 0x6d, 0x69, 0x6e, 0x74, 0x65, 0x64, 0x2c, 0x20, 0x77, 0x69,
+    0x6c, 0x6c, 0x20, 0x61, 0x6c, 0x77, 0x61, 0x79, 0x73, 0x20, 0x62, 0x65, 0x20, 0x31, 0x20, 0x69,
+    0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x61, 0x73, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x45, 0x52,
+    0x43, 0x37, 0x32, 0x31, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x03, 0x05, 0x12,
+    0x04, 0x87, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x03, 0x01, 0x12, 0x04,
+    0x87, 0x01, 0x09, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x03, 0x03, 0x12, 0x04, 0x87,
+    0x01, 0x14, 0x15, 0x0a, 0x43, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x04, 0x12, 0x04, 0x8a, 0x01, 0x02,
+    0x13, 0x1a, 0x35, 0x20, 0x4d, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x72,
+    0x65, 0x63, 0x65, 0x69, 0x76, 0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6d, 0x69, 0x6e, 0x74,
+    0x65, 0x64, 0x20, 0x4e, 0x46, 0x54, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x04,
+    0x05, 0x12, 0x04, 0x8a, 0x01, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x04, 0x01,
+    0x12, 0x04, 0x8a, 0x01, 0x08, 0x0e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x04, 0x03, 0x12,
+    0x04, 0x8a, 0x01, 0x11, 0x12, 0x0a, 0x9c, 0x01, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x05, 0x12, 0x04,
+    0x8e, 0x01, 0x02, 0x12, 0x1a, 0x8d, 0x01, 0x20, 0x50, 0x61, 0x79, 0x65, 0x72, 0x20, 0x69, 0x73,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x74, 0x68, 0x61,
+    0x74, 0x20, 0x73, 0x69, 0x67, 0x6e, 0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6d, 0x69, 0x6e,
+    0x74, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x2c, 0x20, 0x77,
+    0x68, 0x69, 0x63, 0x68, 0x20, 0x6d, 0x61, 0x79, 0x20, 0x64, 0x69, 0x66, 0x66, 0x65, 0x72, 0x20,
+    0x66, 0x72, 0x6f, 0x6d, 0x20, 0x60, 0x6d, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x60, 0x20, 0x66, 0x6f,
+    0x72, 0x0a, 0x20, 0x61, 0x69, 0x72, 0x64, 0x72, 0x6f, 0x70, 0x73, 0x20, 0x61, 0x6e, 0x64, 0x20,
+    0x6d, 0x69, 0x6e, 0x74, 0x73, 0x20, 0x6f, 0x6e, 0x20, 0x62, 0x65, 0x68, 0x61, 0x6c, 0x66, 0x20,
+    0x6f, 0x66, 0x20, 0x61, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x20, 0x61, 0x64, 0x64, 0x72, 0x65,
+    0x73, 0x73, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x05, 0x05, 0x12, 0x04, 0x8e,
+    0x01, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x05, 0x01, 0x12, 0x04, 0x8e, 0x01,
+    0x08, 0x0d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x05, 0x03, 0x12, 0x04, 0x8e, 0x01, 0x10,
+    0x11, 0x0a, 0x88, 0x01, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x06, 0x12, 0x04, 0x92, 0x01, 0x02, 0x1a,
+    0x1a, 0x7a, 0x20, 0x50, 0x61, 0x79, 0x6d, 0x65, 0x6e, 0x74, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x20,
+    0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x20, 0x6f,
+    0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x45, 0x52, 0x43, 0x32, 0x30, 0x20, 0x70, 0x61, 0x69, 0x64,
+    0x20, 0x62, 0x79, 0x20, 0x60, 0x70, 0x61, 0x79, 0x65, 0x72, 0x60, 0x20, 0x69, 0x6e, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x2c, 0x20,
+    0x65, 0x6d, 0x70, 0x74, 0x79, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x70, 0x61, 0x69, 0x64, 0x0a,
+    0x20, 0x69, 0x6e, 0x20, 0x45, 0x54, 0x48, 0x20, 0x6f, 0x72, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x70,
+    0x61, 0x69, 0x64, 0x20, 0x61, 0x74, 0x20, 0x61, 0x6c, 0x6c, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x03, 0x02, 0x06, 0x05, 0x12, 0x04, 0x92, 0x01, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x03, 0x02, 0x06, 0x01, 0x12, 0x04, 0x92, 0x01, 0x08, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x03,
+    0x02, 0x06, 0x03, 0x12, 0x04, 0x92, 0x01, 0x18, 0x19, 0x0a, 0x79, 0x0a, 0x04, 0x04, 0x03, 0x02,
+    0x07, 0x12, 0x04, 0x96, 0x01, 0x02, 0x16, 0x1a, 0x6b, 0x20, 0x54, 0x78, 0x50, 0x72, 0x69, 0x63,
+    0x65, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x74, 0x61, 0x6c, 0x20, 0x61,
+    0x6d, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x70, 0x61, 0x69, 0x64, 0x20, 0x62, 0x79, 0x20, 0x60, 0x70,
+    0x61, 0x79, 0x65, 0x72, 0x60, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x72, 0x61,
+    0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x2c, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x73, 0x6d, 0x61, 0x6c, 0x6c, 0x65, 0x73, 0x74, 0x20, 0x75, 0x6e, 0x69, 0x74, 0x20, 0x6f,
+    0x66, 0x0a, 0x20, 0x60, 0x70, 0x61, 0x79, 0x6d, 0x65, 0x6e, 0x74, 0x5f, 0x74, 0x6f, 0x6b, 0x65,
+    0x6e, 0x60, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x07, 0x05, 0x12, 0x04, 0x96,
+    0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x07, 0x01, 0x12, 0x04, 0x96, 0x01,
+    0x09, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x07, 0x03, 0x12, 0x04, 0x96, 0x01, 0x14,
+    0x15, 0x0a, 0x60, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x08, 0x12, 0x04, 0x99, 0x01, 0x02, 0x19, 0x1a,
+    0x52, 0x20, 0x54, 0x78, 0x51, 0x75, 0x61, 0x6e, 0x74, 0x69, 0x74, 0x79, 0x20, 0x69, 0x73, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x75, 0x6e,
+    0x69, 0x74, 0x73, 0x20, 0x6d, 0x69, 0x6e, 0x74, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x2c, 0x20, 0x6f,
+    0x76, 0x65, 0x72, 0x20, 0x61, 0x6c, 0x6c, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74,
+    0x73, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x08, 0x05, 0x12, 0x04, 0x99, 0x01,
+    0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x08, 0x01, 0x12, 0x04, 0x99, 0x01, 0x09,
+    0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x08, 0x03, 0x12, 0x04, 0x99, 0x01, 0x17, 0x18,
+    0x0a, 0x45, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x09, 0x12, 0x04, 0x9c, 0x01, 0x02, 0x1e, 0x1a, 0x37,
+    0x20, 0x50, 0x72, 0x69, 0x63, 0x65, 0x50, 0x65, 0x72, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x69,
+    0x73, 0x20, 0x60, 0x74, 0x78, 0x5f, 0x70, 0x72, 0x69, 0x63, 0x65, 0x60, 0x20, 0x64, 0x69, 0x76,
+    0x69, 0x64, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x60, 0x74, 0x78, 0x5f, 0x71, 0x75, 0x61, 0x6e,
+    0x74, 0x69, 0x74, 0x79, 0x60, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x09, 0x05,
+    0x12, 0x04, 0x9c, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x09, 0x01, 0x12,
+    0x04, 0x9c, 0x01, 0x09, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x09, 0x03, 0x12, 0x04,
+    0x9c, 0x01, 0x1b, 0x1d, 0x0a, 0x4d, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x0a, 0x12, 0x04, 0x9f, 0x01,
+    0x02, 0x11, 0x1a, 0x3f, 0x20, 0x46, 0x72, 0x65, 0x65, 0x20, 0x69, 0x73, 0x20, 0x74, 0x72, 0x75,
+    0x65, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x6e, 0x6f, 0x74, 0x68, 0x69, 0x6e, 0x67, 0x20, 0x77,
+    0x61, 0x73, 0x20, 0x70, 0x61, 0x69, 0x64, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x6d, 0x69, 0x6e, 0x74, 0x2c, 0x20, 0x62, 0x65, 0x73, 0x69, 0x64, 0x65, 0x73, 0x20, 0x67, 0x61,
+    0x73, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x0a, 0x05, 0x12, 0x04, 0x9f, 0x01,
+    0x02, 0x06, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x0a, 0x01, 0x12, 0x04, 0x9f, 0x01, 0x07,
+    0x0b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x0a, 0x03, 0x12, 0x04, 0x9f, 0x01, 0x0e, 0x10,
+    0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x0b, 0x12, 0x04, 0xa1, 0x01, 0x02, 0x1a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x03, 0x02, 0x0b, 0x05, 0x12, 0x04, 0xa1, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x03, 0x02, 0x0b, 0x01, 0x12, 0x04, 0xa1, 0x01, 0x09, 0x14, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x03, 0x02, 0x0b, 0x03, 0x12, 0x04, 0xa1, 0x01, 0x17, 0x19, 0x0a, 0x4c, 0x0a, 0x04, 0x04,
+    0x03, 0x02, 0x0c, 0x12, 0x04, 0xa4, 0x01, 0x02, 0x1b, 0x1a, 0x3e, 0x20, 0x54, 0x78, 0x47, 0x61,
+    0x73, 0x50, 0x72, 0x69, 0x63, 0x65, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x67, 0x61,
+    0x73, 0x20, 0x70, 0x72, 0x69, 0x63, 0x65, 0x20, 0x70, 0x61, 0x69, 0x64, 0x20, 0x62, 0x79, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x2c,
+    0x20, 0x69, 0x6e, 0x20, 0x77, 0x65, 0x69, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x03, 0x02,
+    0x0c, 0x05, 0x12, 0x04, 0xa4, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x0c,
+    0x01, 0x12, 0x04, 0xa4, 0x01, 0x09, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x0c, 0x03,
+    0x12, 0x04, 0xa4, 0x01, 0x18, 0x1a, 0x0a, 0x35, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x0d, 0x12, 0x04,
+    0xa7, 0x01, 0x02, 0x15, 0x1a, 0x27, 0x20, 0x54, 0x78, 0x48, 0x61, 0x73, 0x68, 0x20, 0x69, 0x73,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x68, 0x61, 0x73, 0x68, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x0a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x03, 0x02, 0x0d, 0x05, 0x12, 0x04, 0xa7, 0x01, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x03, 0x02, 0x0d, 0x01, 0x12, 0x04, 0xa7, 0x01, 0x08, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x03, 0x02, 0x0d, 0x03, 0x12, 0x04, 0xa7, 0x01, 0x12, 0x14, 0x0a, 0x42, 0x0a, 0x04, 0x04, 0x03,
+    0x02, 0x0e, 0x12, 0x04, 0xaa, 0x01, 0x02, 0x16, 0x1a, 0x34, 0x20, 0x4f, 0x72, 0x64, 0x69, 0x6e,
+    0x61, 0x6c, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x20,
+    0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6d, 0x69, 0x6e, 0x74, 0x20, 0x77, 0x69, 0x74, 0x68,
+    0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2e, 0x0a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x03, 0x02, 0x0e, 0x05, 0x12, 0x04, 0xaa, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x03, 0x02, 0x0e, 0x01, 0x12, 0x04, 0xaa, 0x01, 0x09, 0x10, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x03, 0x02, 0x0e, 0x03, 0x12, 0x04, 0xaa, 0x01, 0x13, 0x15, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
+    0x03, 0x02, 0x0f, 0x12, 0x04, 0xac, 0x01, 0x02, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x03, 0x02,
+    0x0f, 0x05, 0x12, 0x04, 0xac, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x0f,
+    0x01, 0x12, 0x04, 0xac, 0x01, 0x09, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x0f, 0x03,
+    0x12, 0x04, 0xac, 0x01, 0x18, 0x1a, 0x0a, 0x38, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x10, 0x12, 0x04,
+    0xaf, 0x01, 0x02, 0x2b, 0x1a, 0x2a, 0x20, 0x54, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70,
+    0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d,
+    0x70, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2e, 0x0a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x10, 0x06, 0x12, 0x04, 0xaf, 0x01, 0x02, 0x1b, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x10, 0x01, 0x12, 0x04, 0xaf, 0x01, 0x1c, 0x25, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x03, 0x02, 0x10, 0x03, 0x12, 0x04, 0xaf, 0x01, 0x28, 0x2a, 0x0a, 0x6b, 0x0a,
+    0x04, 0x04, 0x03, 0x02, 0x11, 0x12, 0x04, 0xb2, 0x01, 0x02, 0x1c, 0x1a, 0x5d, 0x20, 0x45, 0x74,
+    0x68, 0x55, 0x73, 0x64, 0x50, 0x72, 0x69, 0x63, 0x65, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x70, 0x72, 0x69, 0x63, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x6f, 0x6e, 0x65, 0x20, 0x45, 0x54,
+    0x48, 0x20, 0x69, 0x6e, 0x20, 0x55, 0x53, 0x44, 0x20, 0x61, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2c, 0x20, 0x73, 0x65, 0x74, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x6d,
+    0x69, 0x6e, 0x74, 0x73, 0x20, 0x70, 0x61, 0x69, 0x64, 0x20, 0x69, 0x6e, 0x20, 0x45, 0x54, 0x48,
+    0x20, 0x6f, 0x72, 0x20, 0x57, 0x45, 0x54, 0x48, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x03,
+    0x02, 0x11, 0x05, 0x12, 0x04, 0xb2, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x03, 0x02,
+    0x11, 0x01, 0x12, 0x04, 0xb2, 0x01, 0x09, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x11,
+    0x03, 0x12, 0x04, 0xb2, 0x01, 0x19, 0x1b, 0x0a, 0x80, 0x01, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x12,
+    0x12, 0x04, 0xb6, 0x01, 0x02, 0x22, 0x1a, 0x72, 0x20, 0x50, 0x72, 0x69, 0x63, 0x65, 0x50, 0x65,
+    0x72, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x55, 0x53, 0x44, 0x20, 0x69, 0x73, 0x20, 0x60, 0x70, 0x72,
+    0x69, 0x63, 0x65, 0x5f, 0x70, 0x65, 0x72, 0x5f, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x60, 0x20, 0x63,
+    0x6f, 0x6e, 0x76, 0x65, 0x72, 0x74, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x55, 0x53, 0x44, 0x20,
+    0x77, 0x69, 0x74, 0x68, 0x20, 0x60, 0x65, 0x74, 0x68, 0x5f, 0x75, 0x73, 0x64, 0x5f, 0x70, 0x72,
+    0x69, 0x63, 0x65, 0x60, 0x2c, 0x20, 0x73, 0x65, 0x74, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x6d, 0x69,
+    0x6e, 0x74, 0x73, 0x0a, 0x20, 0x70, 0x61, 0x69, 0x64, 0x20, 0x69, 0x6e, 0x20, 0x45, 0x54, 0x48,
+    0x20, 0x6f, 0x72, 0x20, 0x57, 0x45, 0x54, 0x48, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x03,
+    0x02, 0x12, 0x05, 0x12, 0x04, 0xb6, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x03, 0x02,
+    0x12, 0x01, 0x12, 0x04, 0xb6, 0x01, 0x09, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x12,
+    0x03, 0x12, 0x04, 0xb6, 0x01, 0x1f, 0x21, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x33,
+];
+// @@protoc_insertion_point(module)
\ No newline at end of file
@@ -46,6 +46,46 @@ pub struct Transfer {
     /// Timestamp is the timestamp of the block.
     #[prost(message, optional, tag="11")]
     pub timestamp: ::core::option::Option<::prost_types::Timestamp>,
+    /// TxFrom is the account that signed the transaction, which is the initiator of the transfer even
+    /// when a marketplace or another contract moved the token.
+    #[prost(bytes="vec", tag="12")]
+    pub tx_from: ::prost::alloc::vec::Vec<u8>,
+    /// TxTo is the account the transaction was sent to, the marketplace or the token contract itself
+    /// for a direct `safeTransferFrom`.
+    #[prost(bytes="vec", tag="13")]
+    pub tx_to: ::prost::alloc::vec::Vec<u8>,
+    /// TxValue is the ETH value sent with the transaction, in wei.
+    #[prost(string, tag="14")]
+    pub tx_value: ::prost::alloc::string::String,
+    #[prost(uint64, tag="15")]
+    pub tx_gas_used: u64,
+    /// TxGasPrice is the gas price paid by the transaction, in wei.
+    #[prost(string, tag="16")]
+    pub tx_gas_price: ::prost::alloc::string::String,
+    /// TxSelector is the 4-byte method selector of the transaction input, empty when the transaction
+    /// has no calldata.
+    #[prost(bytes="vec", tag="17")]
+    pub tx_selector: ::prost::alloc::vec::Vec<u8>,
+    /// CallIndex is the index within the transaction of the call that emitted the transfer log, which
+    /// is a call executing the token contract.
+    #[prost(uint32, tag="18")]
+    pub call_index: u32,
+    /// CallDepth is the depth of the emitting call, 0 when the transaction called the token contract
+    /// directly.
+    #[prost(uint32, tag="19")]
+    pub call_depth: u32,
+    /// CallCaller is the account that called the token contract, the marketplace or aggregator that
+    /// moved the token when `call_depth > 0`.
+    #[prost(bytes="vec", tag="20")]
+    pub call_caller: ::prost::alloc::vec::Vec<u8>,
+    /// CallSelector is the 4-byte method selector of the emitting call.
+    #[prost(bytes="vec", tag="21")]
+    pub call_selector: ::prost::alloc::vec::Vec<u8>,
+    /// CallMethod is the name of the standard transfer method of the emitting call, one of
+    /// `transferFrom`, `safeTransferFrom` or `safeBatchTransferFrom`, empty for any other method (mint,
+    /// custom marketplace transfer, ...).
+    #[prost(string, tag="22")]
+    pub call_method: ::prost::alloc::string::String,
 }
 /// Nested message and enum types in `Transfer`.
 pub mod transfer {
@@ -59,7 +99,7 @@ pub mod transfer {
 }
 /// Encoded file descriptor set for the `eth.transfers.v1` package
 pub const FILE_DESCRIPTOR_SET: &[u8] = &[
-    0x0a, 0xc7, 0x15, 0x0a, 0x0f, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x73, 0x2e, 0x70,
+    0x0a, 0xaf, 0x26, 0x0a, 0x0f, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x73, 0x2e, 0x70,
     0x72, 0x6f, 0x74, 0x6f, 0x12, 0x10, 0x65, 0x74, 0x68, 0x2e, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66,
     0x65, 0x72, 0x73, 0x2e, 0x76, 0x31, 0x1a, 0x1f, 0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2f, 0x70,
     0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x2f, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d,
@@ -67,8 +107,8 @@ pub const FILE_DESCRIPTOR_SET: &[u8] = &[
     0x66, 0x65, 0x72, 0x73, 0x12, 0x38, 0x0a, 0x09, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72,
     0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1a, 0x2e, 0x65, 0x74, 0x68, 0x2e, 0x74, 0x72,
     0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x54, 0x72, 0x61, 0x6e, 0x73,
-    0x66, 0x65, 0x72, 0x52, 0x09, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x73, 0x22, 0xa0,
-    0x03, 0x0a, 0x08, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x12, 0x16, 0x0a, 0x06, 0x73,
+    0x66, 0x65, 0x72, 0x52, 0x09, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x73, 0x22, 0xf1,
+    0x05, 0x0a, 0x08, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x12, 0x16, 0x0a, 0x06, 0x73,
     0x63, 0x68, 0x65, 0x6d, 0x61, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06, 0x73, 0x63, 0x68,
     0x65, 0x6d, 0x61, 0x12, 0x21, 0x0a, 0x0c, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x5f, 0x6e, 0x75, 0x6d,
     0x62, 0x65, 0x72, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x62, 0x6c, 0x6f, 0x63, 0x6b,
@@ -91,146 +131,281 @@ pub const FILE_DESCRIPTOR_SET: &[u8] = &[
     0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x18, 0x0b, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1a, 0x2e, 0x67,
     0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x2e, 0x54,
     0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x52, 0x09, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74,
-    0x61, 0x6d, 0x70, 0x22, 0x2c, 0x0a, 0x06, 0x53, 0x63, 0x68, 0x65, 0x6d, 0x61, 0x12, 0x09, 0x0a,
-    0x05, 0x65, 0x72, 0x63, 0x32, 0x30, 0x10, 0x00, 0x12, 0x0a, 0x0a, 0x06, 0x65, 0x72, 0x63, 0x37,
-    0x32, 0x31, 0x10, 0x01, 0x12, 0x0b, 0x0a, 0x07, 0x65, 0x72, 0x63, 0x31, 0x31, 0x35, 0x35, 0x10,
-    0x02, 0x4a, 0x8e, 0x11, 0x0a, 0x06, 0x12, 0x04, 0x00, 0x00, 0x38, 0x01, 0x0a, 0x08, 0x0a, 0x01,
-    0x0c, 0x12, 0x03, 0x00, 0x00, 0x12, 0x0a, 0x08, 0x0a, 0x01, 0x02, 0x12, 0x03, 0x02, 0x00, 0x19,
-    0x0a, 0x09, 0x0a, 0x02, 0x03, 0x00, 0x12, 0x03, 0x04, 0x00, 0x29, 0x0a, 0x0a, 0x0a, 0x02, 0x04,
-    0x00, 0x12, 0x04, 0x06, 0x00, 0x08, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03,
-    0x06, 0x08, 0x11, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x07, 0x02, 0x22,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x04, 0x12, 0x03, 0x07, 0x02, 0x0a, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x06, 0x12, 0x03, 0x07, 0x0b, 0x13, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x07, 0x14, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
-    0x02, 0x00, 0x03, 0x12, 0x03, 0x07, 0x20, 0x21, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04,
-    0x0a, 0x00, 0x38, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01, 0x12, 0x03, 0x0a, 0x08, 0x10,
-    0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x01, 0x04, 0x00, 0x12, 0x04, 0x0b, 0x02, 0x0f, 0x03, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x01, 0x04, 0x00, 0x01, 0x12, 0x03, 0x0b, 0x07, 0x0d, 0x0a, 0x0d, 0x0a, 0x06,
-    0x04, 0x01, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x0c, 0x04, 0x0e, 0x0a, 0x0e, 0x0a, 0x07, 0x04,
-    0x01, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x0c, 0x04, 0x09, 0x0a, 0x0e, 0x0a, 0x07, 0x04,
-    0x01, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x0c, 0x0c, 0x0d, 0x0a, 0x0d, 0x0a, 0x06, 0x04,
-    0x01, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x0d, 0x04, 0x0f, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x01,
-    0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x0d, 0x04, 0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x01,
-    0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x0d, 0x0d, 0x0e, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x01,
-    0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x0e, 0x04, 0x10, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x01, 0x04,
-    0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x0e, 0x04, 0x0b, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x01, 0x04,
-    0x00, 0x02, 0x02, 0x02, 0x12, 0x03, 0x0e, 0x0e, 0x0f, 0x0a, 0xed, 0x01, 0x0a, 0x04, 0x04, 0x01,
-    0x02, 0x00, 0x12, 0x03, 0x14, 0x02, 0x14, 0x1a, 0xdf, 0x01, 0x20, 0x53, 0x63, 0x68, 0x65, 0x6d,
-    0x61, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x20,
-    0x72, 0x65, 0x70, 0x72, 0x65, 0x73, 0x65, 0x6e, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x6f,
-    0x66, 0x20, 0x6f, 0x6e, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x6e, 0x75,
-    0x6d, 0x20, 0x64, 0x65, 0x66, 0x69, 0x6e, 0x65, 0x64, 0x20, 0x69, 0x6e, 0x20, 0x53, 0x63, 0x68,
-    0x65, 0x6d, 0x61, 0x2e, 0x20, 0x57, 0x65, 0x20, 0x75, 0x73, 0x65, 0x20, 0x69, 0x74, 0x20, 0x61,
-    0x73, 0x20, 0x61, 0x20, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x0a, 0x20, 0x68, 0x65, 0x72, 0x65,
-    0x20, 0x62, 0x65, 0x63, 0x61, 0x75, 0x73, 0x65, 0x20, 0x52, 0x75, 0x73, 0x74, 0x20, 0x63, 0x6f,
-    0x64, 0x65, 0x20, 0x75, 0x73, 0x65, 0x73, 0x20, 0x61, 0x20, 0x60, 0x75, 0x33, 0x32, 0x60, 0x20,
-    0x66, 0x6f, 0x72, 0x20, 0x69, 0x74, 0x73, 0x20, 0x72, 0x65, 0x70, 0x72, 0x65, 0x73, 0x65, 0x6e,
-    0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x62, 0x75, 0x74, 0x20, 0x69, 0x74, 0x27, 0x73, 0x20,
-    0x6e, 0x69, 0x63, 0x65, 0x72, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x66, 0x69,
-    0x6c, 0x65, 0x20, 0x66, 0x6f, 0x72, 0x6d, 0x61, 0x74, 0x0a, 0x20, 0x74, 0x6f, 0x20, 0x68, 0x61,
-    0x76, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x79, 0x70, 0x65, 0x20, 0x61, 0x73, 0x20, 0x61,
-    0x20, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
-    0x00, 0x05, 0x12, 0x03, 0x14, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01,
-    0x12, 0x03, 0x14, 0x09, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03,
-    0x14, 0x12, 0x13, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x01, 0x12, 0x03, 0x16, 0x02, 0x1a,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x05, 0x12, 0x03, 0x16, 0x02, 0x08, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x16, 0x09, 0x15, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x01, 0x02, 0x01, 0x03, 0x12, 0x03, 0x16, 0x18, 0x19, 0x0a, 0x7b, 0x0a, 0x04, 0x04, 0x01,
-    0x02, 0x02, 0x12, 0x03, 0x1a, 0x02, 0x19, 0x1a, 0x6e, 0x20, 0x54, 0x68, 0x65, 0x20, 0x70, 0x65,
-    0x72, 0x73, 0x6f, 0x6e, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x72, 0x65, 0x63, 0x65, 0x69, 0x76,
-    0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x2c,
-    0x20, 0x6d, 0x69, 0x67, 0x68, 0x74, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x62, 0x65, 0x20, 0x74, 0x68,
-    0x65, 0x20, 0x73, 0x61, 0x6d, 0x65, 0x20, 0x61, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x6e,
-    0x65, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x64, 0x69, 0x64, 0x20, 0x69, 0x6e, 0x69, 0x74, 0x69,
-    0x61, 0x74, 0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x0a, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61,
-    0x63, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x05,
-    0x12, 0x03, 0x1a, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x01, 0x12, 0x03,
-    0x1a, 0x08, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x03, 0x12, 0x03, 0x1a, 0x17,
-    0x18, 0x0a, 0x35, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x03, 0x12, 0x03, 0x1d, 0x02, 0x17, 0x1a, 0x28,
-    0x20, 0x54, 0x68, 0x65, 0x20, 0x70, 0x65, 0x72, 0x73, 0x6f, 0x6e, 0x20, 0x74, 0x68, 0x61, 0x74,
-    0x20, 0x72, 0x65, 0x63, 0x65, 0x69, 0x76, 0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x72,
-    0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x03,
-    0x05, 0x12, 0x03, 0x1d, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x03, 0x01, 0x12,
-    0x03, 0x1d, 0x08, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x03, 0x03, 0x12, 0x03, 0x1d,
-    0x15, 0x16, 0x0a, 0xc0, 0x02, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x04, 0x12, 0x03, 0x23, 0x02, 0x15,
-    0x1a, 0xb2, 0x02, 0x20, 0x4f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x6f, 0x72, 0x20, 0x69, 0x73, 0x20,
-    0x74, 0x68, 0x65, 0x20, 0x22, 0x73, 0x65, 0x6e, 0x64, 0x65, 0x72, 0x22, 0x20, 0x6f, 0x66, 0x20,
-    0x74, 0x68, 0x65, 0x20, 0x61, 0x63, 0x74, 0x75, 0x61, 0x6c, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73,
-    0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x69, 0x6e, 0x69, 0x74,
-    0x69, 0x61, 0x74, 0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66,
-    0x65, 0x72, 0x2c, 0x20, 0x69, 0x74, 0x27, 0x73, 0x20, 0x65, 0x71, 0x75, 0x61, 0x6c, 0x20, 0x74,
-    0x6f, 0x0a, 0x20, 0x60, 0x6d, 0x73, 0x67, 0x2e, 0x73, 0x65, 0x6e, 0x64, 0x65, 0x72, 0x60, 0x20,
-    0x77, 0x69, 0x74, 0x68, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x6d, 0x61, 0x72, 0x74,
-    0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x2e, 0x20, 0x54, 0x68, 0x69, 0x73, 0x20,
-    0x77, 0x69, 0x6c, 0x6c, 0x20, 0x62, 0x65, 0x20, 0x64, 0x69, 0x66, 0x66, 0x65, 0x72, 0x65, 0x6e,
-    0x74, 0x20, 0x74, 0x68, 0x61, 0x6e, 0x20, 0x60, 0x66, 0x72, 0x6f, 0x6d, 0x60, 0x20, 0x69, 0x6e,
-    0x20, 0x63, 0x61, 0x73, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x60, 0x41, 0x70, 0x70, 0x72, 0x6f, 0x76,
-    0x61, 0x6c, 0x60, 0x0a, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x65, 0x78, 0x61, 0x6d, 0x70, 0x6c, 0x65,
-    0x2e, 0x20, 0x4f, 0x6e, 0x6c, 0x79, 0x20, 0x61, 0x76, 0x61, 0x69, 0x6c, 0x61, 0x62, 0x6c, 0x65,
-    0x20, 0x69, 0x66, 0x20, 0x60, 0x73, 0x63, 0x68, 0x65, 0x6d, 0x61, 0x20, 0x3d, 0x20, 0x45, 0x52,
-    0x43, 0x31, 0x31, 0x35, 0x35, 0x60, 0x2c, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x60, 0x45, 0x52, 0x43,
-    0x32, 0x30, 0x60, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x60, 0x45, 0x52, 0x43, 0x37, 0x32, 0x31, 0x60,
-    0x2c, 0x20, 0x74, 0x68, 0x69, 0x73, 0x20, 0x77, 0x69, 0x6c, 0x6c, 0x20, 0x62, 0x65, 0x20, 0x74,
-    0x68, 0x65, 0x20, 0x65, 0x6d, 0x70, 0x74, 0x79, 0x0a, 0x20, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67,
-    0x20, 0x22, 0x22, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x04, 0x05, 0x12, 0x03,
-    0x23, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x04, 0x01, 0x12, 0x03, 0x23, 0x08,
-    0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x04, 0x03, 0x12, 0x03, 0x23, 0x13, 0x14, 0x0a,
-    0x68, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x05, 0x12, 0x03, 0x26, 0x02, 0x16, 0x1a, 0x5b, 0x20, 0x48,
-    0x6f, 0x77, 0x20, 0x6d, 0x61, 0x6e, 0x79, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x77, 0x65,
-    0x72, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x72, 0x65, 0x64, 0x20, 0x69,
-    0x6e, 0x20, 0x74, 0x68, 0x69, 0x73, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x2c,
-    0x20, 0x77, 0x69, 0x6c, 0x6c, 0x20, 0x61, 0x6c, 0x77, 0x61, 0x79, 0x73, 0x20, 0x62, 0x65, 0x20,
-    0x31, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x61, 0x73, 0x65, 0x20, 0x6f, 0x66,
-    0x20, 0x45, 0x52, 0x43, 0x37, 0x32, 0x31, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
-    0x05, 0x05, 0x12, 0x03, 0x26, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x05, 0x01,
-    0x12, 0x03, 0x26, 0x09, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x05, 0x03, 0x12, 0x03,
-    0x26, 0x14, 0x15, 0x0a, 0x83, 0x02, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x06, 0x12, 0x03, 0x2b, 0x02,
-    0x15, 0x1a, 0xf5, 0x01, 0x20, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x49, 0x44, 0x20, 0x74, 0x68, 0x65,
-    0x20, 0x69, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x74,
-    0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x77, 0x68, 0x69,
-    0x63, 0x68, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x20,
-    0x69, 0x73, 0x20, 0x68, 0x61, 0x70, 0x70, 0x65, 0x6e, 0x69, 0x6e, 0x67, 0x2e, 0x20, 0x4f, 0x6e,
-    0x6c, 0x79, 0x0a, 0x20, 0x61, 0x76, 0x61, 0x69, 0x6c, 0x61, 0x62, 0x6c, 0x65, 0x20, 0x77, 0x68,
-    0x65, 0x6e, 0x20, 0x60, 0x73, 0x63, 0x68, 0x65, 0x6d, 0x61, 0x20, 0x3d, 0x20, 0x45, 0x52, 0x43,
-    0x37, 0x32, 0x31, 0x60, 0x20, 0x6f, 0x72, 0x20, 0x60, 0x73, 0x63, 0x68, 0x65, 0x6d, 0x61, 0x20,
-    0x3d, 0x20, 0x45, 0x52, 0x43, 0x31, 0x31, 0x35, 0x35, 0x60, 0x2e, 0x20, 0x57, 0x68, 0x65, 0x6e,
-    0x20, 0x60, 0x73, 0x63, 0x68, 0x65, 0x6d, 0x61, 0x20, 0x3d, 0x20, 0x45, 0x52, 0x43, 0x32, 0x30,
-    0x60, 0x2c, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x69, 0x64, 0x0a,
-    0x20, 0x77, 0x69, 0x6c, 0x6c, 0x20, 0x62, 0x65, 0x20, 0x65, 0x6d, 0x70, 0x74, 0x79, 0x20, 0x73,
-    0x74, 0x72, 0x69, 0x6e, 0x67, 0x20, 0x22, 0x22, 0x20, 0x61, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20,
-    0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x20, 0x69, 0x74, 0x73, 0x65, 0x6c, 0x66, 0x20,
-    0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x69, 0x64, 0x65,
-    0x6e, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
-    0x06, 0x05, 0x12, 0x03, 0x2b, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x06, 0x01,
-    0x12, 0x03, 0x2b, 0x08, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x06, 0x03, 0x12, 0x03,
-    0x2b, 0x13, 0x14, 0x0a, 0x5f, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x07, 0x12, 0x03, 0x2e, 0x02, 0x1d,
-    0x1a, 0x52, 0x20, 0x43, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x41, 0x64, 0x64, 0x72, 0x65,
-    0x73, 0x73, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73,
-    0x73, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x6d, 0x61, 0x72, 0x74, 0x20, 0x63,
-    0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x69, 0x73, 0x20,
+    0x61, 0x6d, 0x70, 0x12, 0x17, 0x0a, 0x07, 0x74, 0x78, 0x5f, 0x66, 0x72, 0x6f, 0x6d, 0x18, 0x0c,
+    0x20, 0x01, 0x28, 0x0c, 0x52, 0x06, 0x74, 0x78, 0x46, 0x72, 0x6f, 0x6d, 0x12, 0x13, 0x0a, 0x05,
+    0x74, 0x78, 0x5f, 0x74, 0x6f, 0x18, 0x0d, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x04, 0x74, 0x78, 0x54,
+    0x6f, 0x12, 0x19, 0x0a, 0x08, 0x74, 0x78, 0x5f, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x18, 0x0e, 0x20,
+    0x01, 0x28, 0x09, 0x52, 0x07, 0x74, 0x78, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x12, 0x1e, 0x0a, 0x0b,
+    0x74, 0x78, 0x5f, 0x67, 0x61, 0x73, 0x5f, 0x75, 0x73, 0x65, 0x64, 0x18, 0x0f, 0x20, 0x01, 0x28,
+    0x04, 0x52, 0x09, 0x74, 0x78, 0x47, 0x61, 0x73, 0x55, 0x73, 0x65, 0x64, 0x12, 0x20, 0x0a, 0x0c,
+    0x74, 0x78, 0x5f, 0x67, 0x61, 0x73, 0x5f, 0x70, 0x72, 0x69, 0x63, 0x65, 0x18, 0x10, 0x20, 0x01,
+    0x28, 0x09, 0x52, 0x0a, 0x74, 0x78, 0x47, 0x61, 0x73, 0x50, 0x72, 0x69, 0x63, 0x65, 0x12, 0x1f,
+    0x0a, 0x0b, 0x74, 0x78, 0x5f, 0x73, 0x65, 0x6c, 0x65, 0x63, 0x74, 0x6f, 0x72, 0x18, 0x11, 0x20,
+    0x01, 0x28, 0x0c, 0x52, 0x0a, 0x74, 0x78, 0x53, 0x65, 0x6c, 0x65, 0x63, 0x74, 0x6f, 0x72, 0x12,
+    0x1d, 0x0a, 0x0a, 0x63, 0x61, 0x6c, 0x6c, 0x5f, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x18, 0x12, 0x20,
+    0x01, 0x28, 0x0d, 0x52, 0x09, 0x63, 0x61, 0x6c, 0x6c, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x12, 0x1d,
+    0x0a, 0x0a, 0x63, 0x61, 0x6c, 0x6c, 0x5f, 0x64, 0x65, 0x70, 0x74, 0x68, 0x18, 0x13, 0x20, 0x01,
+    0x28, 0x0d, 0x52, 0x09, 0x63, 0x61, 0x6c, 0x6c, 0x44, 0x65, 0x70, 0x74, 0x68, 0x12, 0x1f, 0x0a,
+    0x0b, 0x63, 0x61, 0x6c, 0x6c, 0x5f, 0x63, 0x61, 0x6c, 0x6c, 0x65, 0x72, 0x18, 0x14, 0x20, 0x01,
+    0x28, 0x0c, 0x52, 0x0a, 0x63, 0x61, 0x6c, 0x6c, 0x43, 0x61, 0x6c, 0x6c, 0x65, 0x72, 0x12, 0x23,
+    0x0a, 0x0d, 0x63, 0x61, 0x6c, 0x6c, 0x5f, 0x73, 0x65, 0x6c, 0x65, 0x63, 0x74, 0x6f, 0x72, 0x18,
+    0x15, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x0c, 0x63, 0x61, 0x6c, 0x6c, 0x53, 0x65, 0x6c, 0x65, 0x63,
+    0x74, 0x6f, 0x72, 0x12, 0x1f, 0x0a, 0x0b, 0x63, 0x61, 0x6c, 0x6c, 0x5f, 0x6d, 0x65, 0x74, 0x68,
+    0x6f, 0x64, 0x18, 0x16, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0a, 0x63, 0x61, 0x6c, 0x6c, 0x4d, 0x65,
+    0x74, 0x68, 0x6f, 0x64, 0x22, 0x2c, 0x0a, 0x06, 0x53, 0x63, 0x68, 0x65, 0x6d, 0x61, 0x12, 0x09,
+    0x0a, 0x05, 0x65, 0x72, 0x63, 0x32, 0x30, 0x10, 0x00, 0x12, 0x0a, 0x0a, 0x06, 0x65, 0x72, 0x63,
+    0x37, 0x32, 0x31, 0x10, 0x01, 0x12, 0x0b, 0x0a, 0x07, 0x65, 0x72, 0x63, 0x31, 0x31, 0x35, 0x35,
+    0x10, 0x02, 0x4a, 0xa5, 0x1f, 0x0a, 0x06, 0x12, 0x04, 0x00, 0x00, 0x60, 0x01, 0x0a, 0x08, 0x0a,
+    0x01, 0x0c, 0x12, 0x03, 0x00, 0x00, 0x12, 0x0a, 0x08, 0x0a, 0x01, 0x02, 0x12, 0x03, 0x02, 0x00,
+    0x19, 0x0a, 0x09, 0x0a, 0x02, 0x03, 0x00, 0x12, 0x03, 0x04, 0x00, 0x29, 0x0a, 0x0a, 0x0a, 0x02,
+    0x04, 0x00, 0x12, 0x04, 0x06, 0x00, 0x08, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12,
+    0x03, 0x06, 0x08, 0x11, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x07, 0x02,
+    0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x04, 0x12, 0x03, 0x07, 0x02, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x06, 0x12, 0x03, 0x07, 0x0b, 0x13, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x07, 0x14, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x07, 0x20, 0x21, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x01, 0x12,
+    0x04, 0x0a, 0x00, 0x60, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01, 0x12, 0x03, 0x0a, 0x08,
+    0x10, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x01, 0x04, 0x00, 0x12, 0x04, 0x0b, 0x02, 0x0f, 0x03, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x04, 0x00, 0x01, 0x12, 0x03, 0x0b, 0x07, 0x0d, 0x0a, 0x0d, 0x0a,
+    0x06, 0x04, 0x01, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x0c, 0x04, 0x0e, 0x0a, 0x0e, 0x0a, 0x07,
+    0x04, 0x01, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x0c, 0x04, 0x09, 0x0a, 0x0e, 0x0a, 0x07,
+    0x04, 0x01, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x0c, 0x0c, 0x0d, 0x0a, 0x0d, 0x0a, 0x06,
+    0x04, 0x01, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x0d, 0x04, 0x0f, 0x0a, 0x0e, 0x0a, 0x07, 0x04,
+    0x01, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x0d, 0x04, 0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04,
+    0x01, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x0d, 0x0d, 0x0e, 0x0a, 0x0d, 0x0a, 0x06, 0x04,
+    0x01, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x0e, 0x04, 0x10, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x01,
+    0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x0e, 0x04, 0x0b, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x01,
+    0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x03, 0x0e, 0x0e, 0x0f, 0x0a, 0xed, 0x01, 0x0a, 0x04, 0x04,
+    0x01, 0x02, 0x00, 0x12, 0x03, 0x14, 0x02, 0x14, 0x1a, 0xdf, 0x01, 0x20, 0x53, 0x63, 0x68, 0x65,
+    0x6d, 0x61, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67,
+    0x20, 0x72, 0x65, 0x70, 0x72, 0x65, 0x73, 0x65, 0x6e, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20,
+    0x6f, 0x66, 0x20, 0x6f, 0x6e, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x6e,
+    0x75, 0x6d, 0x20, 0x64, 0x65, 0x66, 0x69, 0x6e, 0x65, 0x64, 0x20, 0x69, 0x6e, 0x20, 0x53, 0x63,
+    0x68, 0x65, 0x6d, 0x61, 0x2e, 0x20, 0x57, 0x65, 0x20, 0x75, 0x73, 0x65, 0x20, 0x69, 0x74, 0x20,
+    0x61, 0x73, 0x20, 0x61, 0x20, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x0a, 0x20, 0x68, 0x65, 0x72,
+    0x65, 0x20, 0x62, 0x65, 0x63, 0x61, 0x75, 0x73, 0x65, 0x20, 0x52, 0x75, 0x73, 0x74, 0x20, 0x63,
+    0x6f, 0x64, 0x65, 0x20, 0x75, 0x73, 0x65, 0x73, 0x20, 0x61, 0x20, 0x60, 0x75, 0x33, 0x32, 0x60,
+    0x20, 0x66, 0x6f, 0x72, 0x20, 0x69, 0x74, 0x73, 0x20, 0x72, 0x65, 0x70, 0x72, 0x65, 0x73, 0x65,
+    0x6e, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x62, 0x75, 0x74, 0x20, 0x69, 0x74, 0x27, 0x73,
+    0x20, 0x6e, 0x69, 0x63, 0x65, 0x72, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x66,
+    0x69, 0x6c, 0x65, 0x20, 0x66, 0x6f, 0x72, 0x6d, 0x61, 0x74, 0x0a, 0x20, 0x74, 0x6f, 0x20, 0x68,
+    0x61, 0x76, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x79, 0x70, 0x65, 0x20, 0x61, 0x73, 0x20,
+    0x61, 0x20, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
+    0x02, 0x00, 0x05, 0x12, 0x03, 0x14, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00,
+    0x01, 0x12, 0x03, 0x14, 0x09, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12,
+    0x03, 0x14, 0x12, 0x13, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x01, 0x12, 0x03, 0x16, 0x02,
+    0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x05, 0x12, 0x03, 0x16, 0x02, 0x08, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x16, 0x09, 0x15, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x01, 0x02, 0x01, 0x03, 0x12, 0x03, 0x16, 0x18, 0x19, 0x0a, 0x7b, 0x0a, 0x04, 0x04,
+    0x01, 0x02, 0x02, 0x12, 0x03, 0x1a, 0x02, 0x19, 0x1a, 0x6e, 0x20, 0x54, 0x68, 0x65, 0x20, 0x70,
+    0x65, 0x72, 0x73, 0x6f, 0x6e, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x72, 0x65, 0x63, 0x65, 0x69,
+    0x76, 0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72,
+    0x2c, 0x20, 0x6d, 0x69, 0x67, 0x68, 0x74, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x62, 0x65, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x73, 0x61, 0x6d, 0x65, 0x20, 0x61, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f,
+    0x6e, 0x65, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x64, 0x69, 0x64, 0x20, 0x69, 0x6e, 0x69, 0x74,
+    0x69, 0x61, 0x74, 0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x0a, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73,
+    0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02,
+    0x05, 0x12, 0x03, 0x1a, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x01, 0x12,
+    0x03, 0x1a, 0x08, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x03, 0x12, 0x03, 0x1a,
+    0x17, 0x18, 0x0a, 0x35, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x03, 0x12, 0x03, 0x1d, 0x02, 0x17, 0x1a,
+    0x28, 0x20, 0x54, 0x68, 0x65, 0x20, 0x70, 0x65, 0x72, 0x73, 0x6f, 0x6e, 0x20, 0x74, 0x68, 0x61,
+    0x74, 0x20, 0x72, 0x65, 0x63, 0x65, 0x69, 0x76, 0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74,
+    0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
+    0x03, 0x05, 0x12, 0x03, 0x1d, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x03, 0x01,
+    0x12, 0x03, 0x1d, 0x08, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x03, 0x03, 0x12, 0x03,
+    0x1d, 0x15, 0x16, 0x0a, 0xc0, 0x02, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x04, 0x12, 0x03, 0x23, 0x02,
+    0x15, 0x1a, 0xb2, 0x02, 0x20, 0x4f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x6f, 0x72, 0x20, 0x69, 0x73,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x22, 0x73, 0x65, 0x6e, 0x64, 0x65, 0x72, 0x22, 0x20, 0x6f, 0x66,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x63, 0x74, 0x75, 0x61, 0x6c, 0x20, 0x74, 0x72, 0x61, 0x6e,
+    0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x69, 0x6e, 0x69,
+    0x74, 0x69, 0x61, 0x74, 0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73,
+    0x66, 0x65, 0x72, 0x2c, 0x20, 0x69, 0x74, 0x27, 0x73, 0x20, 0x65, 0x71, 0x75, 0x61, 0x6c, 0x20,
+    0x74, 0x6f, 0x0a, 0x20, 0x60, 0x6d, 0x73, 0x67, 0x2e, 0x73, 0x65, 0x6e, 0x64, 0x65, 0x72, 0x60,
+    0x20, 0x77, 0x69, 0x74, 0x68, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x6d, 0x61, 0x72,
+    0x74, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x2e, 0x20, 0x54, 0x68, 0x69, 0x73,
+    0x20, 0x77, 0x69, 0x6c, 0x6c, 0x20, 0x62, 0x65, 0x20, 0x64, 0x69, 0x66, 0x66, 0x65, 0x72, 0x65,
+    0x6e, 0x74, 0x20, 0x74, 0x68, 0x61, 0x6e, 0x20, 0x60, 0x66, 0x72, 0x6f, 0x6d, 0x60, 0x20, 0x69,
+    0x6e, 0x20, 0x63, 0x61, 0x73, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x60, 0x41, 0x70, 0x70, 0x72, 0x6f,
+    0x76, 0x61, 0x6c, 0x60, 0x0a, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x65, 0x78, 0x61, 0x6d, 0x70, 0x6c,
+    0x65, 0x2e, 0x20, 0x4f, 0x6e, 0x6c, 0x79, 0x20, 0x61, 0x76, 0x61, 0x69, 0x6c, 0x61, 0x62, 0x6c,
+    0x65, 0x20, 0x69, 0x66, 0x20, 0x60, 0x73, 0x63, 0x68, 0x65, 0x6d, 0x61, 0x20, 0x3d, 0x20, 0x45,
+    0x52, 0x43, 0x31, 0x31, 0x35, 0x35, 0x60, 0x2c, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x60, 0x45, 0x52,
+    0x43, 0x32, 0x30, 0x60, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x60, 0x45, 0x52, 0x43, 0x37, 0x32, 0x31,
+    0x60, 0x2c, 0x20, 0x74, 0x68, 0x69, 0x73, 0x20, 0x77, 0x69, 0x6c, 0x6c, 0x20, 0x62, 0x65, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x65, 0x6d, 0x70, 0x74, 0x79, 0x0a, 0x20, 0x73, 0x74, 0x72, 0x69, 0x6e,
+    0x67, 0x20, 0x22, 0x22, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x04, 0x05, 0x12,
+    0x03, 0x23, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x04, 0x01, 0x12, 0x03, 0x23,
+    0x08, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x04, 0x03, 0x12, 0x03, 0x23, 0x13, 0x14,
+    0x0a, 0x68, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x05, 0x12, 0x03, 0x26, 0x02, 0x16, 0x1a, 0x5b, 0x20,
+    0x48, 0x6f, 0x77, 0x20, 0x6d, 0x61, 0x6e, 0x79, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x77,
+    0x65, 0x72, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x72, 0x65, 0x64, 0x20,
+    0x69, 0x6e, 0x20, 0x74, 0x68, 0x69, 0x73, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72,
+    0x2c, 0x20, 0x77, 0x69, 0x6c, 0x6c, 0x20, 0x61, 0x6c, 0x77, 0x61, 0x79, 0x73, 0x20, 0x62, 0x65,
+    0x20, 0x31, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x61, 0x73, 0x65, 0x20, 0x6f,
+    0x66, 0x20, 0x45, 0x52, 0x43, 0x37, 0x32, 0x31, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
+    0x02, 0x05, 0x05, 0x12, 0x03, 0x26, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x05,
+    0x01, 0x12, 0x03, 0x26, 0x09, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x05, 0x03, 0x12,
+    0x03, 0x26, 0x14, 0x15, 0x0a, 0x83, 0x02, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x06, 0x12, 0x03, 0x2b,
+    0x02, 0x15, 0x1a, 0xf5, 0x01, 0x20, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x49, 0x44, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x69, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x20, 0x6f, 0x66, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x77, 0x68,
+    0x69, 0x63, 0x68, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72,
+    0x20, 0x69, 0x73, 0x20, 0x68, 0x61, 0x70, 0x70, 0x65, 0x6e, 0x69, 0x6e, 0x67, 0x2e, 0x20, 0x4f,
+    0x6e, 0x6c, 0x79, 0x0a, 0x20, 0x61, 0x76, 0x61, 0x69, 0x6c, 0x61, 0x62, 0x6c, 0x65, 0x20, 0x77,
+    0x68, 0x65, 0x6e, 0x20, 0x60, 0x73, 0x63, 0x68, 0x65, 0x6d, 0x61, 0x20, 0x3d, 0x20, 0x45, 0x52,
+    0x43, 0x37, 0x32, 0x31, 0x60, 0x20, 0x6f, 0x72, 0x20, 0x60, 0x73, 0x63, 0x68, 0x65, 0x6d, 0x61,
+    0x20, 0x3d, 0x20, 0x45, 0x52, 0x43, 0x31, 0x31, 0x35, 0x35, 0x60, 0x2e, 0x20, 0x57, 0x68, 0x65,
+    0x6e, 0x20, 0x60, 0x73, 0x63, 0x68, 0x65, 0x6d, 0x61, 0x20, 0x3d, 0x20, 0x45, 0x52, 0x43, 0x32,
+    0x30, 0x60, 0x2c, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x69, 0x64,
+    0x0a, 0x20, 0x77, 0x69, 0x6c, 0x6c, 0x20, 0x62, 0x65, 0x20, 0x65, 0x6d, 0x70, 0x74, 0x79, 0x20,
+    0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x20, 0x22, 0x22, 0x20, 0x61, 0x73, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x20, 0x69, 0x74, 0x73, 0x65, 0x6c, 0x66,
+    0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x69, 0x64,
+    0x65, 0x6e, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
+    0x02, 0x06, 0x05, 0x12, 0x03, 0x2b, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x06,
+    0x01, 0x12, 0x03, 0x2b, 0x08, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x06, 0x03, 0x12,
+    0x03, 0x2b, 0x13, 0x14, 0x0a, 0x5f, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x07, 0x12, 0x03, 0x2e, 0x02,
+    0x1d, 0x1a, 0x52, 0x20, 0x43, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x41, 0x64, 0x64, 0x72,
+    0x65, 0x73, 0x73, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x64, 0x64, 0x72, 0x65,
+    0x73, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x6d, 0x61, 0x72, 0x74, 0x20,
+    0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x69, 0x73,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72,
+    0x61, 0x63, 0x74, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x07, 0x05, 0x12, 0x03,
+    0x2e, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x07, 0x01, 0x12, 0x03, 0x2e, 0x08,
+    0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x07, 0x03, 0x12, 0x03, 0x2e, 0x1b, 0x1c, 0x0a,
+    0x34, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x08, 0x12, 0x03, 0x31, 0x02, 0x14, 0x1a, 0x27, 0x20, 0x54,
+    0x78, 0x48, 0x61, 0x73, 0x68, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x68, 0x61, 0x73,
+    0x68, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63,
+    0x74, 0x69, 0x6f, 0x6e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x08, 0x05, 0x12, 0x03,
+    0x31, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x08, 0x01, 0x12, 0x03, 0x31, 0x08,
+    0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x08, 0x03, 0x12, 0x03, 0x31, 0x12, 0x13, 0x0a,
+    0x45, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x09, 0x12, 0x03, 0x34, 0x02, 0x16, 0x1a, 0x38, 0x20, 0x4f,
+    0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72,
+    0x64, 0x65, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73,
+    0x66, 0x65, 0x72, 0x20, 0x77, 0x69, 0x74, 0x68, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62,
+    0x6c, 0x6f, 0x63, 0x6b, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x09, 0x05, 0x12,
+    0x03, 0x34, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x09, 0x01, 0x12, 0x03, 0x34,
+    0x09, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x09, 0x03, 0x12, 0x03, 0x34, 0x13, 0x15,
+    0x0a, 0x37, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x0a, 0x12, 0x03, 0x37, 0x02, 0x2b, 0x1a, 0x2a, 0x20,
+    0x54, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
+    0x0a, 0x06, 0x12, 0x03, 0x37, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0a, 0x01,
+    0x12, 0x03, 0x37, 0x1c, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0a, 0x03, 0x12, 0x03,
+    0x37, 0x28, 0x2a, 0x0a, 0xa7, 0x01, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x0b, 0x12, 0x03, 0x3b, 0x02,
+    0x15, 0x1a, 0x99, 0x01, 0x20, 0x54, 0x78, 0x46, 0x72, 0x6f, 0x6d, 0x20, 0x69, 0x73, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20,
+    0x73, 0x69, 0x67, 0x6e, 0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73,
+    0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x2c, 0x20, 0x77, 0x68, 0x69, 0x63, 0x68, 0x20, 0x69, 0x73,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x69, 0x6e, 0x69, 0x74, 0x69, 0x61, 0x74, 0x6f, 0x72, 0x20, 0x6f,
+    0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x20, 0x65,
+    0x76, 0x65, 0x6e, 0x0a, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x61, 0x20, 0x6d, 0x61, 0x72, 0x6b,
+    0x65, 0x74, 0x70, 0x6c, 0x61, 0x63, 0x65, 0x20, 0x6f, 0x72, 0x20, 0x61, 0x6e, 0x6f, 0x74, 0x68,
+    0x65, 0x72, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x20, 0x6d, 0x6f, 0x76, 0x65,
+    0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x01, 0x02, 0x0b, 0x05, 0x12, 0x03, 0x3b, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x01, 0x02, 0x0b, 0x01, 0x12, 0x03, 0x3b, 0x08, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
+    0x0b, 0x03, 0x12, 0x03, 0x3b, 0x12, 0x14, 0x0a, 0x8f, 0x01, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x0c,
+    0x12, 0x03, 0x3f, 0x02, 0x13, 0x1a, 0x81, 0x01, 0x20, 0x54, 0x78, 0x54, 0x6f, 0x20, 0x69, 0x73,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x77, 0x61, 0x73,
+    0x20, 0x73, 0x65, 0x6e, 0x74, 0x20, 0x74, 0x6f, 0x2c, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6d, 0x61,
+    0x72, 0x6b, 0x65, 0x74, 0x70, 0x6c, 0x61, 0x63, 0x65, 0x20, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x20,
+    0x69, 0x74, 0x73, 0x65, 0x6c, 0x66, 0x0a, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x61, 0x20, 0x64, 0x69,
+    0x72, 0x65, 0x63, 0x74, 0x20, 0x60, 0x73, 0x61, 0x66, 0x65, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x66,
+    0x65, 0x72, 0x46, 0x72, 0x6f, 0x6d, 0x60, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
+    0x0c, 0x05, 0x12, 0x03, 0x3f, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0c, 0x01,
+    0x12, 0x03, 0x3f, 0x08, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0c, 0x03, 0x12, 0x03,
+    0x3f, 0x10, 0x12, 0x0a, 0x4a, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x0d, 0x12, 0x03, 0x42, 0x02, 0x17,
+    0x1a, 0x3d, 0x20, 0x54, 0x78, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x45, 0x54, 0x48, 0x20, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x20, 0x73, 0x65, 0x6e, 0x74,
+    0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61,
+    0x63, 0x74, 0x69, 0x6f, 0x6e, 0x2c, 0x20, 0x69, 0x6e, 0x20, 0x77, 0x65, 0x69, 0x2e, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0d, 0x05, 0x12, 0x03, 0x42, 0x02, 0x08, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x01, 0x02, 0x0d, 0x01, 0x12, 0x03, 0x42, 0x09, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x01, 0x02, 0x0d, 0x03, 0x12, 0x03, 0x42, 0x14, 0x16, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02,
+    0x0e, 0x12, 0x03, 0x44, 0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0e, 0x05, 0x12,
+    0x03, 0x44, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0e, 0x01, 0x12, 0x03, 0x44,
+    0x09, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0e, 0x03, 0x12, 0x03, 0x44, 0x17, 0x19,
+    0x0a, 0x4b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x0f, 0x12, 0x03, 0x47, 0x02, 0x1b, 0x1a, 0x3e, 0x20,
+    0x54, 0x78, 0x47, 0x61, 0x73, 0x50, 0x72, 0x69, 0x63, 0x65, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x67, 0x61, 0x73, 0x20, 0x70, 0x72, 0x69, 0x63, 0x65, 0x20, 0x70, 0x61, 0x69, 0x64,
+    0x20, 0x62, 0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74,
+    0x69, 0x6f, 0x6e, 0x2c, 0x20, 0x69, 0x6e, 0x20, 0x77, 0x65, 0x69, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x01, 0x02, 0x0f, 0x05, 0x12, 0x03, 0x47, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x01, 0x02, 0x0f, 0x01, 0x12, 0x03, 0x47, 0x09, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
+    0x0f, 0x03, 0x12, 0x03, 0x47, 0x18, 0x1a, 0x0a, 0x7e, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x10, 0x12,
+    0x03, 0x4b, 0x02, 0x19, 0x1a, 0x71, 0x20, 0x54, 0x78, 0x53, 0x65, 0x6c, 0x65, 0x63, 0x74, 0x6f,
+    0x72, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x34, 0x2d, 0x62, 0x79, 0x74, 0x65, 0x20,
+    0x6d, 0x65, 0x74, 0x68, 0x6f, 0x64, 0x20, 0x73, 0x65, 0x6c, 0x65, 0x63, 0x74, 0x6f, 0x72, 0x20,
+    0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69,
+    0x6f, 0x6e, 0x20, 0x69, 0x6e, 0x70, 0x75, 0x74, 0x2c, 0x20, 0x65, 0x6d, 0x70, 0x74, 0x79, 0x20,
+    0x77, 0x68, 0x65, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63,
+    0x74, 0x69, 0x6f, 0x6e, 0x0a, 0x20, 0x68, 0x61, 0x73, 0x20, 0x6e, 0x6f, 0x20, 0x63, 0x61, 0x6c,
+    0x6c, 0x64, 0x61, 0x74, 0x61, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x10, 0x05,
+    0x12, 0x03, 0x4b, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x10, 0x01, 0x12, 0x03,
+    0x4b, 0x08, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x10, 0x03, 0x12, 0x03, 0x4b, 0x16,
+    0x18, 0x0a, 0x97, 0x01, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x11, 0x12, 0x03, 0x4f, 0x02, 0x19, 0x1a,
+    0x89, 0x01, 0x20, 0x43, 0x61, 0x6c, 0x6c, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x20, 0x69, 0x73, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x20, 0x77, 0x69, 0x74, 0x68, 0x69, 0x6e,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e,
+    0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x61, 0x6c, 0x6c, 0x20, 0x74, 0x68, 0x61,
+    0x74, 0x20, 0x65, 0x6d, 0x69, 0x74, 0x74, 0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x72,
+    0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x20, 0x6c, 0x6f, 0x67, 0x2c, 0x20, 0x77, 0x68, 0x69, 0x63,
+    0x68, 0x0a, 0x20, 0x69, 0x73, 0x20, 0x61, 0x20, 0x63, 0x61, 0x6c, 0x6c, 0x20, 0x65, 0x78, 0x65,
+    0x63, 0x75, 0x74, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e,
+    0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x01, 0x02, 0x11, 0x05, 0x12, 0x03, 0x4f, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
+    0x11, 0x01, 0x12, 0x03, 0x4f, 0x09, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x11, 0x03,
+    0x12, 0x03, 0x4f, 0x16, 0x18, 0x0a, 0x77, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x12, 0x12, 0x03, 0x53,
+    0x02, 0x19, 0x1a, 0x6a, 0x20, 0x43, 0x61, 0x6c, 0x6c, 0x44, 0x65, 0x70, 0x74, 0x68, 0x20, 0x69,
+    0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x64, 0x65, 0x70, 0x74, 0x68, 0x20, 0x6f, 0x66, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x65, 0x6d, 0x69, 0x74, 0x74, 0x69, 0x6e, 0x67, 0x20, 0x63, 0x61, 0x6c, 0x6c,
+    0x2c, 0x20, 0x30, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x72, 0x61,
+    0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x63, 0x61, 0x6c, 0x6c, 0x65, 0x64, 0x20,
     0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61,
-    0x63, 0x74, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x07, 0x05, 0x12, 0x03, 0x2e,
-    0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x07, 0x01, 0x12, 0x03, 0x2e, 0x08, 0x18,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x07, 0x03, 0x12, 0x03, 0x2e, 0x1b, 0x1c, 0x0a, 0x34,
-    0x0a, 0x04, 0x04, 0x01, 0x02, 0x08, 0x12, 0x03, 0x31, 0x02, 0x14, 0x1a, 0x27, 0x20, 0x54, 0x78,
-    0x48, 0x61, 0x73, 0x68, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x68, 0x61, 0x73, 0x68,
-    0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74,
-    0x69, 0x6f, 0x6e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x08, 0x05, 0x12, 0x03, 0x31,
-    0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x08, 0x01, 0x12, 0x03, 0x31, 0x08, 0x0f,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x08, 0x03, 0x12, 0x03, 0x31, 0x12, 0x13, 0x0a, 0x45,
-    0x0a, 0x04, 0x04, 0x01, 0x02, 0x09, 0x12, 0x03, 0x34, 0x02, 0x16, 0x1a, 0x38, 0x20, 0x4f, 0x72,
-    0x64, 0x69, 0x6e, 0x61, 0x6c, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x64,
-    0x65, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66,
-    0x65, 0x72, 0x20, 0x77, 0x69, 0x74, 0x68, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x6c,
-    0x6f, 0x63, 0x6b, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x09, 0x05, 0x12, 0x03,
-    0x34, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x09, 0x01, 0x12, 0x03, 0x34, 0x09,
-    0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x09, 0x03, 0x12, 0x03, 0x34, 0x13, 0x15, 0x0a,
-    0x37, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x0a, 0x12, 0x03, 0x37, 0x02, 0x2b, 0x1a, 0x2a, 0x20, 0x54,
-    0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20,
-    0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65,
-    0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0a,
-    0x06, 0x12, 0x03, 0x37, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0a, 0x01, 0x12,
-    0x03, 0x37, 0x1c, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0a, 0x03, 0x12, 0x03, 0x37,
-    0x28, 0x2a, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x33,
+    0x63, 0x74, 0x0a, 0x20, 0x64, 0x69, 0x72, 0x65, 0x63, 0x74, 0x6c, 0x79, 0x2e, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x01, 0x02, 0x12, 0x05, 0x12, 0x03, 0x53, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x01, 0x02, 0x12, 0x01, 0x12, 0x03, 0x53, 0x09, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
+    0x02, 0x12, 0x03, 0x12, 0x03, 0x53, 0x16, 0x18, 0x0a, 0x94, 0x01, 0x0a, 0x04, 0x04, 0x01, 0x02,
+    0x13, 0x12, 0x03, 0x57, 0x02, 0x19, 0x1a, 0x86, 0x01, 0x20, 0x43, 0x61, 0x6c, 0x6c, 0x43, 0x61,
+    0x6c, 0x6c, 0x65, 0x72, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x63, 0x63, 0x6f,
+    0x75, 0x6e, 0x74, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x63, 0x61, 0x6c, 0x6c, 0x65, 0x64, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61,
+    0x63, 0x74, 0x2c, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6d, 0x61, 0x72, 0x6b, 0x65, 0x74, 0x70, 0x6c,
+    0x61, 0x63, 0x65, 0x20, 0x6f, 0x72, 0x20, 0x61, 0x67, 0x67, 0x72, 0x65, 0x67, 0x61, 0x74, 0x6f,
+    0x72, 0x20, 0x74, 0x68, 0x61, 0x74, 0x0a, 0x20, 0x6d, 0x6f, 0x76, 0x65, 0x64, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x60, 0x63, 0x61,
+    0x6c, 0x6c, 0x5f, 0x64, 0x65, 0x70, 0x74, 0x68, 0x20, 0x3e, 0x20, 0x30, 0x60, 0x2e, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x13, 0x05, 0x12, 0x03, 0x57, 0x02, 0x07, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x01, 0x02, 0x13, 0x01, 0x12, 0x03, 0x57, 0x08, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x01, 0x02, 0x13, 0x03, 0x12, 0x03, 0x57, 0x16, 0x18, 0x0a, 0x4f, 0x0a, 0x04, 0x04, 0x01, 0x02,
+    0x14, 0x12, 0x03, 0x5a, 0x02, 0x1b, 0x1a, 0x42, 0x20, 0x43, 0x61, 0x6c, 0x6c, 0x53, 0x65, 0x6c,
+    0x65, 0x63, 0x74, 0x6f, 0x72, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x34, 0x2d, 0x62,
+    0x79, 0x74, 0x65, 0x20, 0x6d, 0x65, 0x74, 0x68, 0x6f, 0x64, 0x20, 0x73, 0x65, 0x6c, 0x65, 0x63,
+    0x74, 0x6f, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x6d, 0x69, 0x74, 0x74,
+    0x69, 0x6e, 0x67, 0x20, 0x63, 0x61, 0x6c, 0x6c, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
+    0x02, 0x14, 0x05, 0x12, 0x03, 0x5a, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x14,
+    0x01, 0x12, 0x03, 0x5a, 0x08, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x14, 0x03, 0x12,
+    0x03, 0x5a, 0x18, 0x1a, 0x0a, 0xe9, 0x01, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x15, 0x12, 0x03, 0x5f,
+    0x02, 0x1a, 0x1a, 0xdb, 0x01, 0x20, 0x43, 0x61, 0x6c, 0x6c, 0x4d, 0x65, 0x74, 0x68, 0x6f, 0x64,
+    0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6e, 0x61, 0x6d, 0x65, 0x20, 0x6f, 0x66, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x73, 0x74, 0x61, 0x6e, 0x64, 0x61, 0x72, 0x64, 0x20, 0x74, 0x72, 0x61,
+    0x6e, 0x73, 0x66, 0x65, 0x72, 0x20, 0x6d, 0x65, 0x74, 0x68, 0x6f, 0x64, 0x20, 0x6f, 0x66, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x65, 0x6d, 0x69, 0x74, 0x74, 0x69, 0x6e, 0x67, 0x20, 0x63, 0x61, 0x6c,
+    0x6c, 0x2c, 0x20, 0x6f, 0x6e, 0x65, 0x20, 0x6f, 0x66, 0x0a, 0x20, 0x60, 0x74, 0x72, 0x61, 0x6e,
+    0x73, 0x66, 0x65, 0x72, 0x46, 0x72, 0x6f, 0x6d, 0x60, 0x2c, 0x20, 0x60, 0x73, 0x61, 0x66, 0x65,
+    0x54, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x46, 0x72, 0x6f, 0x6d, 0x60, 0x20, 0x6f, 0x72,
+    0x20, 0x60, 0x73, 0x61, 0x66, 0x65, 0x42, 0x61, 0x74, 0x63, 0x68, 0x54, 0x72, 0x61, 0x6e, 0x73,
+    0x66, 0x65, 0x72, 0x46, 0x72, 0x6f, 0x6d, 0x60, 0x2c, 0x20, 0x65, 0x6d, 0x70, 0x74, 0x79, 0x20,
+    0x66, 0x6f, 0x72, 0x20, 0x61, 0x6e, 0x79, 0x20, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x20, 0x6d, 0x65,
+    0x74, 0x68, 0x6f, 0x64, 0x20, 0x28, 0x6d, 0x69, 0x6e, 0x74, 0x2c, 0x0a, 0x20, 0x63, 0x75, 0x73,
+    0x74, 0x6f, 0x6d, 0x20, 0x6d, 0x61, 0x72, 0x6b, 0x65, 0x74, 0x70, 0x6c, 0x61, 0x63, 0x65, 0x20,
+    0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x2c, 0x20, 0x2e, 0x2e, 0x2e, 0x29, 0x2e, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x15, 0x05, 0x12, 0x03, 0x5f, 0x02, 0x08, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x01, 0x02, 0x15, 0x01, 0x12, 0x03, 0x5f, 0x09, 0x14, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x01, 0x02, 0x15, 0x03, 0x12, 0x03, 0x5f, 0x17, 0x19, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74,
+    0x6f, 0x33,
 ];
 // @@protoc_insertion_point(module)
\ No newline at end of file
@@ -1,3 +1,23 @@
 #[path = "eth.transfers.v1.rs"]
 #[allow(dead_code)]
-pub mod transfers;
\ No newline at end of file
+pub mod transfers;
+
+#[path = "eth.contracts.v1.rs"]
+#[allow(dead_code)]
+pub mod contracts;
+
+#[path = "eth.orders.v1.rs"]
+#[allow(dead_code)]
+pub mod orders;
+
+#[path = "eth.ownership.v1.rs"]
+#[allow(dead_code)]
+pub mod ownership;
+
+#[path = "eth.sales.v1.rs"]
+#[allow(dead_code)]
+pub mod sales;
+
+#[path = "eth.pools.v1.rs"]
+#[allow(dead_code)]
+pub mod pools;
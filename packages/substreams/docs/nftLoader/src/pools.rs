@@ -0,0 +1,261 @@
+use substreams::pb::substreams::store_delta::Operation as DeltaOperation;
+use substreams::scalar::BigInt;
+use substreams::store::{
+    DeltaBigInt, Deltas, StoreAdd, StoreAddBigInt, StoreGet, StoreGetProto, StoreSet, StoreSetProto,
+};
+use substreams::Hex;
+use substreams_database_change::pb::database::{table_change::Operation, DatabaseChanges};
+
+use crate::pb::pools;
+use crate::pb::sales;
+use crate::pb::transfers;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    // The pool buys the NFT.
+    In,
+    // The pool sells the NFT.
+    Out,
+}
+
+// A swap of a pool in a transaction, in one direction. Swaps of AMMs usually carry no NFT, those
+// being matched from the transfers of the transaction.
+#[derive(Debug, Clone)]
+pub struct Swap {
+    pub tx_hash: Vec<u8>,
+    pub pool: pools::Pool,
+    pub direction: Direction,
+}
+
+pub fn pool_key(address: &[u8]) -> String {
+    format!("pool:{}", Hex(address))
+}
+
+// Keys are `inventory:<pool>:<contract>:<token id>`.
+pub fn inventory_key(pool: &[u8], contract_address: &[u8], token_id: &[u8]) -> String {
+    format!("inventory:{}:{}:{}", Hex(pool), Hex(contract_address), Hex(token_id))
+}
+
+pub fn store_pools(pools: &[pools::Pool], store: &StoreSetProto<pools::Pool>) {
+    for pool in pools {
+        store.set(pool.ordinal, pool_key(&pool.address), pool);
+    }
+}
+
+/// The pool on either side of a transfer, when the transfer moves an NFT of the collection the
+/// pool trades. `pool_at` gives the pool at an address, if any.
+pub fn transfer_pool(
+    transfer: &transfers::Transfer,
+    pool_at: impl Fn(&[u8]) -> Option<pools::Pool>,
+) -> Option<(pools::Pool, Direction)> {
+    let pool_of = |address: &[u8]| pool_at(address).filter(|pool| pool.nft_contract == transfer.contract_address);
+
+    if let Some(pool) = pool_of(&transfer.to_address) {
+        return Some((pool, Direction::In));
+    }
+    pool_of(&transfer.from_address).map(|pool| (pool, Direction::Out))
+}
+
+/// The NFT transfers of the transaction of a swap into or out of its pool, which are the NFTs it
+/// traded.
+pub fn swap_transfers<'a>(
+    transfers: &'a [transfers::Transfer],
+    swap: &'a Swap,
+) -> impl Iterator<Item = &'a transfers::Transfer> {
+    transfers.iter().filter(move |transfer| {
+        let counterparty = match swap.direction {
+            Direction::In => &transfer.to_address,
+            Direction::Out => &transfer.from_address,
+        };
+
+        transfer.tx_hash == swap.tx_hash
+            && transfer.contract_address == swap.pool.nft_contract
+            && *counterparty == swap.pool.address
+    })
+}
+
+/// Builds the sale of an NFT transferred into or out of a pool, the pool being the buyer or the
+/// seller.
+pub fn pool_sale(marketplace: &str, pool: &pools::Pool, transfer: &transfers::Transfer, price: &BigInt) -> sales::Sale {
+    sales::Sale {
+        marketplace: marketplace.to_string(),
+        order_hash: Vec::new(),
+        maker: pool.address.clone(),
+        taker: transfer.tx_from.clone(),
+        seller: transfer.from_address.clone(),
+        buyer: transfer.to_address.clone(),
+        schema: transfer.schema.clone(),
+        contract_address: transfer.contract_address.clone(),
+        token_id: transfer.token_id.clone(),
+        quantity: transfer.quantity.clone(),
+        payment_token: pool.payment_token.clone(),
+        price: price.to_string(),
+        protocol_fee: String::new(),
+        royalty_fee: String::new(),
+        tx_hash: transfer.tx_hash.clone(),
+        ordinal: transfer.ordinal,
+        block_number: transfer.block_number,
+        timestamp: transfer.timestamp.clone(),
+        confidence: 100,
+        ..Default::default()
+    }
+}
+
+/// Tracks the NFTs held by each pool, from the transfers into and out of the pool.
+pub fn store_inventory(transfers: &[transfers::Transfer], pools: &StoreGetProto<pools::Pool>, store: &StoreAddBigInt) {
+    for (ordinal, key, quantity) in inventory_deltas(transfers, |address| pools.get_last(pool_key(address))) {
+        store.add(ordinal, key, quantity);
+    }
+}
+
+// The quantity each transfer adds to or removes from the inventory of a pool, by ordinal and
+// inventory key.
+fn inventory_deltas(
+    transfers: &[transfers::Transfer],
+    pool_at: impl Fn(&[u8]) -> Option<pools::Pool>,
+) -> Vec<(u64, String, BigInt)> {
+    transfers
+        .iter()
+        .filter_map(|transfer| {
+            let (pool, direction) = transfer_pool(transfer, &pool_at)?;
+            let quantity = BigInt::try_from(transfer.quantity.clone()).unwrap_or_else(|_| BigInt::from(1));
+            let quantity = match direction {
+                Direction::In => quantity,
+                Direction::Out => BigInt::zero() - quantity,
+            };
+
+            Some((
+                transfer.ordinal,
+                inventory_key(&pool.address, &transfer.contract_address, &transfer.token_id),
+                quantity,
+            ))
+        })
+        .collect()
+}
+
+pub fn push_pool_create(changes: &mut DatabaseChanges, value: pools::Pool) {
+    changes
+        .push_change("pools", &Hex(&value.address).to_string(), value.ordinal, Operation::Create)
+        .change("amm", (None, value.amm))
+        .change("nft_contract", (None, Hex(value.nft_contract)))
+        .change("payment_token", (None, Hex(value.payment_token)))
+        .change("tx_hash", (None, Hex(value.tx_hash)))
+        .change("block_number", (None, value.block_number))
+        .change("ordinal", (None, value.ordinal))
+        .change("timestamp", (None, value.timestamp.unwrap()));
+}
+
+pub fn push_inventory_changes(changes: &mut DatabaseChanges, deltas: Deltas<DeltaBigInt>) {
+    for delta in deltas.deltas {
+        let mut parts = delta.key.trim_start_matches("inventory:").splitn(3, ':');
+        let pool = parts.next().unwrap_or_default().to_string();
+        let contract = parts.next().unwrap_or_default().to_string();
+        let token_id = parts.next().unwrap_or_default().to_string();
+        let key = format!("{}-{}-{}", pool, contract, token_id);
+
+        match delta.operation {
+            DeltaOperation::Create => {
+                changes
+                    .push_change("pool_inventory", &key, delta.ordinal, Operation::Create)
+                    .change("pool", (None, pool))
+                    .change("contract", (None, contract))
+                    .change("token_id", (None, token_id))
+                    .change("quantity", (None, delta.new_value.to_string()));
+            }
+            _ => {
+                changes
+                    .push_change("pool_inventory", &key, delta.ordinal, Operation::Update)
+                    .change("quantity", (Some(delta.old_value.to_string()), delta.new_value.to_string()));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const POOL: [u8; 20] = [0x10; 20];
+    const COLLECTION: [u8; 20] = [0x11; 20];
+    const TRADER: [u8; 20] = [0x02; 20];
+
+    fn transfer(contract_address: [u8; 20], from: [u8; 20], to: [u8; 20], ordinal: u64) -> transfers::Transfer {
+        transfers::Transfer {
+            contract_address: contract_address.to_vec(),
+            token_id: vec![7],
+            from_address: from.to_vec(),
+            to_address: to.to_vec(),
+            quantity: "1".to_string(),
+            ordinal,
+            ..Default::default()
+        }
+    }
+
+    fn pool_at(address: &[u8]) -> Option<pools::Pool> {
+        if address != POOL {
+            return None;
+        }
+
+        Some(pools::Pool {
+            address: POOL.to_vec(),
+            nft_contract: COLLECTION.to_vec(),
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn it_inventory_deltas_of_swaps_in_and_out() {
+        let transfers = vec![
+            transfer(COLLECTION, TRADER, POOL, 1),
+            transfer([0x12; 20], TRADER, POOL, 2),
+            transfer(COLLECTION, TRADER, TRADER, 3),
+            transfer(COLLECTION, POOL, TRADER, 4),
+        ];
+        let key = inventory_key(&POOL, &COLLECTION, &[7]);
+
+        assert_eq!(
+            inventory_deltas(&transfers, pool_at),
+            vec![(1, key.clone(), BigInt::from(1)), (4, key, BigInt::from(-1))]
+        );
+    }
+
+    #[test]
+    fn it_push_inventory_changes() {
+        let key = inventory_key(&POOL, &COLLECTION, &[7]);
+        let delta = |operation, ordinal, old_value: i64, new_value: i64| DeltaBigInt {
+            operation,
+            ordinal,
+            key: key.clone(),
+            old_value: BigInt::from(old_value),
+            new_value: BigInt::from(new_value),
+        };
+        let mut changes = DatabaseChanges::default();
+        push_inventory_changes(
+            &mut changes,
+            Deltas {
+                deltas: vec![
+                    delta(DeltaOperation::Create, 1, 0, 1),
+                    delta(DeltaOperation::Update, 4, 1, 0),
+                ],
+            },
+        );
+
+        let row_key = format!("{}-{}-07", Hex(POOL), Hex(COLLECTION));
+        let quantities: Vec<(i32, String, String, String)> = changes
+            .table_changes
+            .iter()
+            .map(|change| {
+                let field = change.fields.iter().find(|field| field.name == "quantity").unwrap();
+
+                (change.operation, change.pk.clone(), field.old_value.clone(), field.new_value.clone())
+            })
+            .collect();
+        assert_eq!(
+            quantities,
+            vec![
+                (Operation::Create as i32, row_key.clone(), String::new(), "1".to_string()),
+                (Operation::Update as i32, row_key, "1".to_string(), "0".to_string()),
+            ]
+        );
+    }
+}
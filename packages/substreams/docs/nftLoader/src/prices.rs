@@ -0,0 +1,192 @@
+use hex_literal::hex;
+use substreams::scalar::BigInt;
+use substreams::store::{StoreGet, StoreGetBigInt, StoreSet, StoreSetBigInt};
+use substreams_ethereum::pb::eth::v2 as eth;
+use substreams_ethereum::Event;
+
+use crate::abi::chainlink_aggregator::events::AnswerUpdated as AnswerUpdatedEvent;
+use crate::abi::uniswap_v3_pool::events::Swap as SwapEvent;
+use crate::pb::sales;
+
+pub const WETH_ADDRESS: [u8; 20] = hex!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2");
+
+// The aggregators behind the Chainlink ETH/USD proxy (0x5f4ec3df9cbd43714fe2740f5e3616155c5b8419),
+// an aggregator must be added here when the proxy is pointed to a new one.
+pub const CHAINLINK_ETH_USD_AGGREGATORS: [[u8; 20]; 2] = [
+    hex!("37bc7498f4ff12c19678ee8fe19d713b87f6a9e6"),
+    hex!("e62b71cf983019bff55bc83b48601ce8419650cc"),
+];
+
+// The Uniswap V3 USDC/WETH pools, 0.05% and 0.3% fee tiers. USDC is token0 and WETH token1.
+pub const UNISWAP_V3_USDC_WETH_POOLS: [[u8; 20]; 2] = [
+    hex!("88e6a0c2ddd26feeb64f039a2c41296fcb3f5640"),
+    hex!("8ad599c3a0ff1de082011efddc58f1908eb6e6d8"),
+];
+
+// Prices are stored with the 8 decimals of the Chainlink answer.
+const USD_DECIMALS: u64 = 8;
+const ETH_DECIMALS: u64 = 18;
+
+const CHAINLINK_KEY: &str = "eth_usd:chainlink";
+const UNISWAP_V3_KEY: &str = "eth_usd:uniswap_v3";
+
+// The Chainlink ETH/USD feed is updated at least every hour, an answer older than that compared to
+// the Uniswap V3 price means the feed stalled or moved to an aggregator missing above.
+const CHAINLINK_MAX_LAG_BLOCKS: u64 = 300;
+
+// The block of the latest price of a source is stored along with it, under `<key>:block`.
+fn block_key(key: &str) -> String {
+    format!("{}:block", key)
+}
+
+/// Tracks the latest ETH/USD price of each source and the block it was set at, the Chainlink
+/// answer and the price of the last swap of the Uniswap V3 USDC/WETH pools.
+pub fn store_eth_usd(blk: &eth::Block, store: &StoreSetBigInt) {
+    let block_number = BigInt::from(blk.number);
+
+    for log in blk.receipts().flat_map(|receipt| receipt.receipt.logs.iter()) {
+        if CHAINLINK_ETH_USD_AGGREGATORS.iter().any(|aggregator| log.address == aggregator) {
+            if let Some(event) = AnswerUpdatedEvent::match_and_decode(log) {
+                if event.current > BigInt::zero() {
+                    store.set(log.ordinal, CHAINLINK_KEY, &event.current);
+                    store.set(log.ordinal, block_key(CHAINLINK_KEY), &block_number);
+                }
+            }
+        } else if UNISWAP_V3_USDC_WETH_POOLS.iter().any(|pool| log.address == pool) {
+            if let Some(event) = SwapEvent::match_and_decode(log) {
+                if let Some(price) = uniswap_v3_eth_usd(&event.sqrt_price_x96) {
+                    store.set(log.ordinal, UNISWAP_V3_KEY, &price);
+                    store.set(log.ordinal, block_key(UNISWAP_V3_KEY), &block_number);
+                }
+            }
+        }
+    }
+}
+
+// `sqrtPriceX96` is `sqrt(weth / usdc) * 2^96` in raw units, so one ETH is worth
+// `10^(18 - 6) * 2^192 / sqrtPriceX96^2` USD.
+fn uniswap_v3_eth_usd(sqrt_price_x96: &BigInt) -> Option<BigInt> {
+    if *sqrt_price_x96 == BigInt::zero() {
+        return None;
+    }
+
+    let mut two_pow_192 = vec![0u8; 25];
+    two_pow_192[0] = 1;
+    let scale = pow10(ETH_DECIMALS - 6 + USD_DECIMALS);
+
+    Some(
+        scale * BigInt::from_unsigned_bytes_be(&two_pow_192)
+            / (sqrt_price_x96.clone() * sqrt_price_x96.clone()),
+    )
+}
+
+fn pow10(exponent: u64) -> BigInt {
+    (0..exponent).fold(BigInt::from(1), |value, _| value * BigInt::from(10))
+}
+
+/// The latest ETH/USD price with 8 decimals, from Chainlink unless its answer lags the Uniswap V3
+/// price by more than `CHAINLINK_MAX_LAG_BLOCKS`.
+pub fn eth_usd(prices: &StoreGetBigInt) -> Option<BigInt> {
+    let source = |key: &str| {
+        let block_number = prices.get_last(block_key(key))?.to_u64();
+
+        prices.get_last(key).map(|price| (block_number, price))
+    };
+
+    freshest(source(CHAINLINK_KEY), source(UNISWAP_V3_KEY))
+}
+
+// Picks between the (block, price) of each source.
+fn freshest(chainlink: Option<(u64, BigInt)>, uniswap_v3: Option<(u64, BigInt)>) -> Option<BigInt> {
+    match (chainlink, uniswap_v3) {
+        (Some((chainlink_block, _)), Some((uniswap_v3_block, price)))
+            if chainlink_block + CHAINLINK_MAX_LAG_BLOCKS < uniswap_v3_block =>
+        {
+            Some(price)
+        }
+        (Some((_, price)), _) | (None, Some((_, price))) => Some(price),
+        (None, None) => None,
+    }
+}
+
+pub fn is_eth(payment_token: &[u8]) -> bool {
+    payment_token.is_empty() || payment_token == WETH_ADDRESS
+}
+
+fn usd_value(wei: &str, eth_usd: &BigInt) -> String {
+    match BigInt::try_from(wei.to_string()) {
+        Ok(wei) => (wei * eth_usd.clone()).to_decimal(ETH_DECIMALS + USD_DECIMALS).to_string(),
+        Err(_) => String::new(),
+    }
+}
+
+/// Stamps the sales paid in ETH or WETH with the ETH/USD price of the block and their USD price.
+pub fn stamp_sales(sales: &mut [sales::Sale], prices: &StoreGetBigInt) {
+    let eth_usd = match eth_usd(prices) {
+        Some(eth_usd) => eth_usd,
+        None => return,
+    };
+
+    for sale in sales.iter_mut().filter(|sale| is_eth(&sale.payment_token)) {
+        sale.eth_usd_price = eth_usd.to_decimal(USD_DECIMALS).to_string();
+        sale.price_usd = usd_value(&sale.price, &eth_usd);
+    }
+}
+
+/// Stamps the mints paid in ETH or WETH with the ETH/USD price of the block and their USD price
+/// per token.
+pub fn stamp_mints(mints: &mut [sales::Mint], prices: &StoreGetBigInt) {
+    let eth_usd = match eth_usd(prices) {
+        Some(eth_usd) => eth_usd,
+        None => return,
+    };
+
+    for mint in mints.iter_mut().filter(|mint| is_eth(&mint.payment_token)) {
+        mint.eth_usd_price = eth_usd.to_decimal(USD_DECIMALS).to_string();
+        mint.price_per_token_usd = usd_value(&mint.price_per_token, &eth_usd);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn two_pow_96() -> BigInt {
+        let mut bytes = vec![0u8; 13];
+        bytes[0] = 1;
+
+        BigInt::from_unsigned_bytes_be(&bytes)
+    }
+
+    #[test]
+    fn it_uniswap_v3_eth_usd() {
+        // 1 USDC raw unit for 10^8 wei of WETH, that is 10000 USD per ETH.
+        let sqrt_price_x96 = two_pow_96() * BigInt::from(10_000);
+        assert_eq!(uniswap_v3_eth_usd(&sqrt_price_x96), Some(BigInt::from(10_000) * pow10(USD_DECIMALS)));
+
+        let sqrt_price_x96 = two_pow_96() * BigInt::from(20_000);
+        assert_eq!(uniswap_v3_eth_usd(&sqrt_price_x96), Some(BigInt::from(2_500) * pow10(USD_DECIMALS)));
+
+        assert_eq!(uniswap_v3_eth_usd(&BigInt::zero()), None);
+    }
+
+    #[test]
+    fn it_usd_value() {
+        let eth_usd = BigInt::from(2_000) * pow10(USD_DECIMALS);
+
+        assert_eq!(usd_value("1500000000000000000", &eth_usd), "3000.00000000000000000000000000");
+        assert_eq!(usd_value("1", &eth_usd), "0.00000000000000200000000000");
+        assert_eq!(usd_value("not a number", &eth_usd), "");
+    }
+
+    #[test]
+    fn it_freshest() {
+        let chainlink = Some((1_000, BigInt::from(1)));
+
+        assert_eq!(freshest(chainlink.clone(), Some((1_300, BigInt::from(2)))), Some(BigInt::from(1)));
+        assert_eq!(freshest(chainlink.clone(), Some((1_301, BigInt::from(2)))), Some(BigInt::from(2)));
+        assert_eq!(freshest(chainlink, None), Some(BigInt::from(1)));
+        assert_eq!(freshest(None, Some((1_000, BigInt::from(2)))), Some(BigInt::from(2)));
+        assert_eq!(freshest(None, None), None);
+    }
+}
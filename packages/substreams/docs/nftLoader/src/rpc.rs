@@ -0,0 +1,21 @@
+use substreams::log;
+use substreams_ethereum::pb::eth::rpc::RpcResponse;
+use substreams_ethereum::rpc::{RPCDecodable, RpcBatch};
+use substreams_ethereum::Function;
+
+/// Makes the calls of the batch, in a single round trip. Responses are in the order the calls were
+/// added, none being returned for an empty batch.
+pub fn execute(batch: RpcBatch) -> Vec<RpcResponse> {
+    match batch.execute() {
+        Ok(responses) => responses.responses,
+        Err(err) => {
+            log::info!("Unable to execute the batch of calls: {}", err);
+            Vec::new()
+        }
+    }
+}
+
+/// Decodes the response to a call of `T`, a missing or failed call decoding to `None`.
+pub fn decode<R, T: RPCDecodable<R> + Function>(response: Option<&RpcResponse>) -> Option<R> {
+    response.and_then(RpcBatch::decode::<R, T>)
+}
@@ -0,0 +1,58 @@
+use hex_literal::hex;
+use substreams_ethereum::pb::eth::v2 as eth;
+use substreams_ethereum::Event;
+
+use crate::abi::seaport::events::CounterIncremented as CounterIncrementedEvent;
+use crate::abi::seaport::events::OrderCancelled as OrderCancelledEvent;
+use crate::invalidation_kind_to_string;
+use crate::pb::orders;
+use crate::pb::orders::order_invalidation::Kind;
+
+pub const MARKETPLACE_NAME: &str = "seaport";
+
+// Seaport 1.1 and 1.4 share the same event signatures.
+pub const SEAPORT_ADDRESSES: [[u8; 20]; 2] = [
+    hex!("00000000006c3852cbef3e08e8df289169ede581"),
+    hex!("00000000000001ad428e4906ae43d8f9852d0dd6"),
+];
+
+/// Decodes the order cancellations of the block. Incrementing the counter of an offerer
+/// invalidates every order it signed with the previous counter.
+pub fn invalidations_from_block(blk: &eth::Block) -> Vec<orders::OrderInvalidation> {
+    let header = blk.header.as_ref().unwrap();
+
+    blk.receipts()
+        .flat_map(|receipt| {
+            let hash = &receipt.transaction.hash;
+            let timestamp = Some(header.timestamp.as_ref().unwrap().clone());
+
+            receipt
+                .receipt
+                .logs
+                .iter()
+                .filter(|log| SEAPORT_ADDRESSES.iter().any(|address| log.address == address))
+                .filter_map(move |log| {
+                    let (kind, maker, order_hash, nonce) =
+                        if let Some(event) = OrderCancelledEvent::match_and_decode(log) {
+                            (Kind::CancelledOrder, event.offerer, event.order_hash.to_vec(), String::new())
+                        } else if let Some(event) = CounterIncrementedEvent::match_and_decode(log) {
+                            (Kind::MinNonce, event.offerer, Vec::new(), event.new_counter.to_string())
+                        } else {
+                            return None;
+                        };
+
+                    Some(orders::OrderInvalidation {
+                        marketplace: MARKETPLACE_NAME.to_string(),
+                        kind: invalidation_kind_to_string(kind),
+                        maker,
+                        order_hash,
+                        nonce,
+                        tx_hash: hash.to_vec(),
+                        ordinal: log.block_index as u64,
+                        block_number: blk.number,
+                        timestamp: timestamp.clone(),
+                    })
+                })
+        })
+        .collect()
+}
@@ -0,0 +1,163 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use substreams::pb::substreams::store_delta::Operation as DeltaOperation;
+use substreams::store::{DeltaInt64, Deltas, StoreAdd, StoreAddInt64, StoreMax, StoreMaxInt64};
+use substreams::Hex;
+use substreams_database_change::pb::database::{table_change::Operation, DatabaseChanges};
+
+use crate::pb::contracts;
+use crate::pb::sales;
+use crate::pb::transfers;
+use crate::pb::transfers::transfer::Schema;
+use crate::schema_to_string;
+
+const ZERO_ADDRESS: [u8; 20] = [0u8; 20];
+
+// Names and symbols of phishing contracts usually send the holder to a website to claim a reward. A link is
+// enough to flag them, while a bare domain also needs an action word, collections being often named after
+// their own website.
+const LINK_PREFIXES: [&str; 4] = ["http://", "https://", "www.", "t.me/"];
+const DOMAIN_TLDS: [&str; 12] = ["com", "io", "xyz", "org", "net", "app", "site", "me", "live", "fun", "top", "gift"];
+const ACTION_WORDS: [&str; 10] = [
+    "claim", "claims", "visit", "reward", "rewards", "voucher", "airdrop", "giveaway", "redeem", "free",
+];
+
+// (signal key, tx hash) => (ordinal of the last transfer, recipients)
+type FanOuts<'a> = HashMap<(String, &'a [u8]), (u64, HashSet<&'a [u8]>)>;
+
+// Keys are `contract:<address>:<signal>`, the signal being the column of the table.
+pub fn signal_key(contract_address: &[u8], signal: &str) -> String {
+    format!("contract:{}:{}", Hex(contract_address), signal)
+}
+
+pub fn is_phishing_name(name: &str) -> bool {
+    let name = name.to_lowercase();
+    let tokens: Vec<&str> = name
+        .split_whitespace()
+        .map(|token| token.trim_matches(|c: char| !c.is_alphanumeric() && c != '/'))
+        .collect();
+
+    if tokens.iter().any(|token| LINK_PREFIXES.iter().any(|prefix| token.starts_with(prefix))) {
+        return true;
+    }
+
+    tokens.iter().any(|token| is_domain(token))
+        && name
+            .split(|c: char| !c.is_alphanumeric())
+            .any(|word| ACTION_WORDS.contains(&word))
+}
+
+// A `<name>.<tld>` token, possibly with subdomains and followed by a path.
+fn is_domain(token: &str) -> bool {
+    let host = token.split('/').next().unwrap_or_default();
+    let labels: Vec<&str> = host.split('.').collect();
+
+    labels.len() >= 2
+        && labels
+            .iter()
+            .all(|label| !label.is_empty() && label.chars().all(|c| c.is_alphanumeric() || c == '-'))
+        && DOMAIN_TLDS.contains(&labels[labels.len() - 1])
+}
+
+/// Counts the transfers and the sales of each contract, a contract transferred a lot without ever
+/// being sold being a spam signal.
+pub fn store_counts(transfers: &[transfers::Transfer], sales: &[sales::Sale], store: &StoreAddInt64) {
+    for transfer in transfers {
+        store.add(transfer.ordinal, signal_key(&transfer.contract_address, "transfer_count"), 1);
+    }
+
+    for sale in sales {
+        store.add(sale.ordinal, signal_key(&sale.contract_address, "sale_count"), 1);
+    }
+}
+
+/// Records the largest fan-out of each contract, the number of distinct wallets other than the
+/// transaction signer receiving its NFTs in a single transaction, for mints and for ERC1155
+/// transfers. Also flags the contracts deployed with a phishing-style name or symbol.
+pub fn store_signals(transfers: &[transfers::Transfer], new_contracts: &[contracts::Contract], store: &StoreMaxInt64) {
+    let mut fan_outs = FanOuts::new();
+    let erc1155 = schema_to_string(Schema::Erc1155);
+
+    for transfer in transfers.iter().filter(|transfer| transfer.to_address != transfer.tx_from) {
+        let signal = if transfer.from_address == ZERO_ADDRESS {
+            "max_mint_fan_out"
+        } else if transfer.schema == erc1155 {
+            "max_airdrop_fan_out"
+        } else {
+            continue;
+        };
+
+        let fan_out = fan_outs
+            .entry((signal_key(&transfer.contract_address, signal), &transfer.tx_hash))
+            .or_insert_with(|| (0, HashSet::new()));
+        fan_out.0 = transfer.ordinal;
+        fan_out.1.insert(&transfer.to_address);
+    }
+
+    for ((key, _), (ordinal, recipients)) in fan_outs {
+        store.max(ordinal, key, recipients.len() as i64);
+    }
+
+    for contract in new_contracts {
+        if is_phishing_name(&contract.name) || is_phishing_name(&contract.symbol) {
+            store.max(contract.ordinal, signal_key(&contract.address, "phishing_name"), 1);
+        }
+    }
+}
+
+/// Updates the spam signals of every contract whose counts or signals changed in the block. The row
+/// is created along with the first transfer count of the contract, which always comes first.
+pub fn push_spam_signal_changes(
+    changes: &mut DatabaseChanges,
+    counts: Deltas<DeltaInt64>,
+    signals: Deltas<DeltaInt64>,
+) {
+    // contract => (created in this block, ordinal of the last delta, signal => last value)
+    let mut rows: BTreeMap<String, (bool, u64, BTreeMap<String, i64>)> = BTreeMap::new();
+    for delta in counts.deltas.into_iter().chain(signals.deltas) {
+        let mut parts = delta.key.trim_start_matches("contract:").splitn(2, ':');
+        let contract = parts.next().unwrap_or_default().to_string();
+        let signal = parts.next().unwrap_or_default().to_string();
+
+        let row = rows.entry(contract).or_insert_with(|| (false, 0, BTreeMap::new()));
+        row.0 |= signal == "transfer_count" && delta.operation == DeltaOperation::Create;
+        row.1 = row.1.max(delta.ordinal);
+        row.2.insert(signal, delta.new_value);
+    }
+
+    for (contract, (created, ordinal, signals)) in rows {
+        let operation = if created { Operation::Create } else { Operation::Update };
+        let row = changes.push_change("contract_spam_signals", &contract, ordinal, operation);
+        if created {
+            row.change("contract", (None, contract.clone()));
+        }
+        for (signal, value) in signals {
+            match signal.as_str() {
+                "phishing_name" => row.change(signal.as_str(), (None, (value > 0).to_string())),
+                _ => row.change(signal.as_str(), (None, value)),
+            };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_is_phishing_name() {
+        assert!(is_phishing_name("Visit uniswap-rewards.xyz to claim"));
+        assert!(is_phishing_name("$5000 AIRDROP at apes-drop.io/claim!"));
+        assert!(is_phishing_name("https://apes-drop.site"));
+        assert!(is_phishing_name("Join t.me/apesdrop"));
+        assert!(is_phishing_name("www.apesdrop.com"));
+        assert!(!is_phishing_name("Bored Ape Yacht Club"));
+        assert!(!is_phishing_name("CryptoPunks"));
+        assert!(!is_phishing_name("NFT.com Genesis Key"));
+        assert!(!is_phishing_name("NFT.com"));
+        assert!(!is_phishing_name("Reward Pass"));
+        assert!(!is_phishing_name("Claimable Land"));
+        assert!(!is_phishing_name("$5000 AIRDROP"));
+        assert!(!is_phishing_name("Claim v2.0"));
+    }
+}
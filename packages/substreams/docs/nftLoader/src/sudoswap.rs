@@ -0,0 +1,265 @@
+use std::collections::{HashMap, HashSet};
+
+use hex_literal::hex;
+use substreams::scalar::BigInt;
+use substreams::store::{StoreGet, StoreGetProto};
+use substreams::Hex;
+use substreams_ethereum::pb::eth::v2 as eth;
+use substreams_ethereum::rpc::RpcBatch;
+use substreams_ethereum::Event;
+
+use crate::abi::sudoswap_pair::events::SwapNftInPair as SwapNftInPairEvent;
+use crate::abi::sudoswap_pair::events::SwapNftOutPair as SwapNftOutPairEvent;
+use crate::abi::sudoswap_pair::functions::Nft as NftFunction;
+use crate::abi::sudoswap_pair::functions::Token as TokenFunction;
+use crate::abi::sudoswap_pair_factory::events::NewPair as NewPairEvent;
+use crate::inferred_sales::ValueFlows;
+use crate::pb::pools;
+use crate::pb::sales;
+use crate::pb::transfers;
+use crate::pools::{pool_key, pool_sale, swap_transfers, Direction, Swap};
+use crate::rpc;
+
+pub const MARKETPLACE_NAME: &str = "sudoswap";
+
+// The factory creates the pairs and collects the protocol fee of their swaps.
+pub const PAIR_FACTORY_ADDRESS: [u8; 20] = hex!("b16c1342e617a5b6e4b631eb114483fdb289c0a4");
+
+// The calls made for every new pair, `nft()` then `token()`.
+const PAIR_CALLS: usize = 2;
+
+/// Decodes the pairs created by the factory of the block. The NFT contract and the ERC20 of the
+/// pairs are read from the pairs in a single batch, ETH pairs having no `token()`.
+pub fn pools_from_block(blk: &eth::Block) -> Vec<pools::Pool> {
+    let header = blk.header.as_ref().unwrap();
+
+    let pools: Vec<pools::Pool> = blk
+        .receipts()
+        .flat_map(|receipt| {
+            receipt
+                .receipt
+                .logs
+                .iter()
+                .filter(|log| log.address == PAIR_FACTORY_ADDRESS)
+                .filter_map(move |log| {
+                    let event = NewPairEvent::match_and_decode(log)?;
+
+                    Some(pools::Pool {
+                        amm: MARKETPLACE_NAME.to_string(),
+                        address: event.pool_address,
+                        tx_hash: receipt.transaction.hash.clone(),
+                        ordinal: log.block_index as u64,
+                        block_number: blk.number,
+                        timestamp: Some(header.timestamp.as_ref().unwrap().clone()),
+                        ..Default::default()
+                    })
+                })
+        })
+        .collect();
+    if pools.is_empty() {
+        return Vec::new();
+    }
+
+    let mut batch = RpcBatch::new();
+    for pool in pools.iter() {
+        batch = batch
+            .add(NftFunction {}, pool.address.clone())
+            .add(TokenFunction {}, pool.address.clone());
+    }
+    let responses = rpc::execute(batch);
+
+    pools
+        .into_iter()
+        .enumerate()
+        .filter_map(|(index, pool)| {
+            Some(pools::Pool {
+                nft_contract: rpc::decode::<_, NftFunction>(responses.get(index * PAIR_CALLS))?,
+                payment_token: rpc::decode::<_, TokenFunction>(responses.get(index * PAIR_CALLS + 1))
+                    .unwrap_or_default(),
+                ..pool
+            })
+        })
+        .collect()
+}
+
+/// Decodes the swaps of the pairs of the block. Swap events carry no data, and several swaps with
+/// the same pair in a transaction are a single one.
+pub fn swaps_from_block(blk: &eth::Block, pools: &StoreGetProto<pools::Pool>) -> Vec<Swap> {
+    let mut seen: HashSet<(Vec<u8>, Vec<u8>, Direction)> = HashSet::new();
+    let mut swaps = Vec::new();
+
+    for receipt in blk.receipts() {
+        for log in receipt.receipt.logs.iter() {
+            let direction = if SwapNftInPairEvent::match_log(log) {
+                Direction::In
+            } else if SwapNftOutPairEvent::match_log(log) {
+                Direction::Out
+            } else {
+                continue;
+            };
+
+            if !seen.insert((receipt.transaction.hash.clone(), log.address.clone(), direction)) {
+                continue;
+            }
+            match pools.get_last(pool_key(&log.address)) {
+                Some(pool) if pool.amm == MARKETPLACE_NAME => swaps.push(Swap {
+                    tx_hash: receipt.transaction.hash.clone(),
+                    pool,
+                    direction,
+                }),
+                _ => continue,
+            }
+        }
+    }
+
+    swaps
+}
+
+/// Builds the sales of the swaps. The NFTs traded are the transfers into or out of the pair in the
+/// same transaction, and the amount paid is the value that flowed in the other direction. The NFTs
+/// of a swap are a bundle sold for that amount, which includes the protocol fee paid to the factory.
+pub fn sales_from_swaps(
+    swaps: &[Swap],
+    transfers: &[transfers::Transfer],
+    value_flows: &HashMap<Vec<u8>, ValueFlows>,
+) -> Vec<sales::Sale> {
+    let mut sales = Vec::new();
+
+    for swap in swaps {
+        let flows = value_flows.get(&swap.tx_hash);
+        let (price, protocol_fee) = match flows.and_then(|flows| payment(flows, &swap.pool, swap.direction)) {
+            Some(payment) => payment,
+            None => continue,
+        };
+
+        for transfer in swap_transfers(transfers, swap) {
+            let mut sale = pool_sale(MARKETPLACE_NAME, &swap.pool, transfer, &price);
+            sale.protocol_fee = protocol_fee.to_string();
+            sale.bundle_id = format!(
+                "{}-{}-{}",
+                Hex(&swap.tx_hash),
+                Hex(&swap.pool.address),
+                if swap.direction == Direction::In { "in" } else { "out" }
+            );
+
+            sales.push(sale);
+        }
+    }
+
+    sales
+}
+
+// The amount paid for the NFTs of a swap and the protocol fee it includes. When the pair sells, the
+// buyer pays the pair and the fee to the factory, when it buys the pair pays both the seller and
+// the fee.
+fn payment(flows: &ValueFlows, pool: &pools::Pool, direction: Direction) -> Option<(BigInt, BigInt)> {
+    let flow = |account: &[u8]| {
+        flows
+            .get(&(pool.payment_token.clone(), account.to_vec()))
+            .cloned()
+            .unwrap_or_else(BigInt::zero)
+    };
+    let pool_flow = flow(&pool.address);
+    let mut protocol_fee = flow(&PAIR_FACTORY_ADDRESS);
+    if protocol_fee < BigInt::zero() {
+        protocol_fee = BigInt::zero();
+    }
+
+    let price = match direction {
+        Direction::Out => pool_flow + protocol_fee.clone(),
+        Direction::In => BigInt::zero() - pool_flow,
+    };
+    if price <= BigInt::zero() {
+        return None;
+    }
+
+    Some((price, protocol_fee))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const POOL: [u8; 20] = [0x10; 20];
+    const COLLECTION: [u8; 20] = [0x11; 20];
+    const TRADER: [u8; 20] = [0x02; 20];
+
+    fn swap(direction: Direction) -> Swap {
+        Swap {
+            tx_hash: vec![0xaa; 32],
+            pool: pools::Pool {
+                amm: MARKETPLACE_NAME.to_string(),
+                address: POOL.to_vec(),
+                nft_contract: COLLECTION.to_vec(),
+                ..Default::default()
+            },
+            direction,
+        }
+    }
+
+    fn transfer(from: [u8; 20], to: [u8; 20], token_id: u8) -> transfers::Transfer {
+        transfers::Transfer {
+            schema: "erc721".to_string(),
+            contract_address: COLLECTION.to_vec(),
+            token_id: vec![token_id],
+            from_address: from.to_vec(),
+            to_address: to.to_vec(),
+            quantity: "1".to_string(),
+            tx_hash: vec![0xaa; 32],
+            tx_from: TRADER.to_vec(),
+            ordinal: token_id as u64,
+            ..Default::default()
+        }
+    }
+
+    fn value_flows(flows: &[([u8; 20], i64)]) -> HashMap<Vec<u8>, ValueFlows> {
+        let flows = flows
+            .iter()
+            .map(|(account, value)| ((Vec::new(), account.to_vec()), BigInt::from(*value)))
+            .collect();
+
+        HashMap::from([(vec![0xaa; 32], flows)])
+    }
+
+    #[test]
+    fn it_sales_from_swap_out() {
+        let transfers = vec![transfer(POOL, TRADER, 1), transfer(POOL, TRADER, 2), transfer(TRADER, POOL, 3)];
+        let flows = value_flows(&[(TRADER, -200), (POOL, 190), (PAIR_FACTORY_ADDRESS, 10)]);
+
+        let sales = sales_from_swaps(&[swap(Direction::Out)], &transfers, &flows);
+        assert_eq!(sales.len(), 2);
+        for (sale, token_id) in sales.iter().zip([1, 2]) {
+            assert_eq!(sale.token_id, vec![token_id]);
+            assert_eq!(sale.seller, POOL.to_vec());
+            assert_eq!(sale.buyer, TRADER.to_vec());
+            assert_eq!(sale.maker, POOL.to_vec());
+            assert_eq!(sale.taker, TRADER.to_vec());
+            assert_eq!(sale.price, "200");
+            assert_eq!(sale.protocol_fee, "10");
+            assert_eq!(sale.bundle_id, format!("{}-{}-out", Hex([0xaa; 32]), Hex(POOL)));
+        }
+    }
+
+    #[test]
+    fn it_sales_from_swap_in() {
+        let transfers = vec![transfer(TRADER, POOL, 3)];
+        let flows = value_flows(&[(POOL, -100), (TRADER, 98), (PAIR_FACTORY_ADDRESS, 2)]);
+
+        let sales = sales_from_swaps(&[swap(Direction::In)], &transfers, &flows);
+        assert_eq!(sales.len(), 1);
+        assert_eq!(sales[0].seller, TRADER.to_vec());
+        assert_eq!(sales[0].buyer, POOL.to_vec());
+        assert_eq!(sales[0].price, "100");
+        assert_eq!(sales[0].protocol_fee, "2");
+        assert_eq!(sales[0].bundle_id, format!("{}-{}-in", Hex([0xaa; 32]), Hex(POOL)));
+    }
+
+    #[test]
+    fn it_skips_swaps_without_payment() {
+        let transfers = vec![transfer(TRADER, POOL, 3)];
+        let flows = value_flows(&[(POOL, 100)]);
+
+        assert!(sales_from_swaps(&[swap(Direction::In)], &transfers, &flows).is_empty());
+        assert!(sales_from_swaps(&[swap(Direction::In)], &transfers, &HashMap::new()).is_empty());
+    }
+}
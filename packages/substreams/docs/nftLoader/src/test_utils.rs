@@ -0,0 +1,53 @@
+use ethabi::{ParamType, Token};
+use prost_types::Timestamp;
+use substreams_ethereum::pb::eth::v2 as eth;
+
+pub const TX_HASH: [u8; 32] = [0xaa; 32];
+
+/// A block holding a single successful transaction, of hash `TX_HASH`, emitting `logs`.
+pub fn block(number: u64, logs: Vec<eth::Log>) -> eth::Block {
+    eth::Block {
+        number,
+        header: Some(eth::BlockHeader {
+            number,
+            timestamp: Some(Timestamp {
+                seconds: number as i64 * 12,
+                nanos: 0,
+            }),
+            ..Default::default()
+        }),
+        transaction_traces: vec![eth::TransactionTrace {
+            hash: TX_HASH.to_vec(),
+            status: eth::TransactionTraceStatus::Succeeded as i32,
+            receipt: Some(eth::TransactionReceipt {
+                logs,
+                ..Default::default()
+            }),
+            ..Default::default()
+        }],
+        ..Default::default()
+    }
+}
+
+/// The log of the event `name(params)` emitted by `address`, the first topics being the indexed
+/// parameters and `data` the others. Its index and ordinal within the block are both `index`.
+pub fn log(
+    address: &[u8],
+    name: &str,
+    params: &[ParamType],
+    indexed: &[Token],
+    data: &[Token],
+    index: u32,
+) -> eth::Log {
+    let mut topics = vec![ethabi::long_signature(name, params).as_bytes().to_vec()];
+    topics.extend(indexed.iter().map(|token| ethabi::encode(std::slice::from_ref(token))));
+
+    eth::Log {
+        address: address.to_vec(),
+        topics,
+        data: ethabi::encode(data),
+        index,
+        block_index: index,
+        ordinal: index as u64,
+    }
+}
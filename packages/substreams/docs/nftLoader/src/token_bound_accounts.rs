@@ -0,0 +1,99 @@
+use hex_literal::hex;
+use substreams::store::{StoreSet, StoreSetProto};
+use substreams::Hex;
+use substreams_database_change::pb::database::{table_change::Operation, DatabaseChanges};
+use substreams_ethereum::pb::eth::v2 as eth;
+use substreams_ethereum::Event;
+
+use crate::abi::erc6551_registry::events::AccountCreated as AccountCreatedEvent;
+use crate::abi::erc6551_registry::events::Erc6551AccountCreated as Erc6551AccountCreatedEvent;
+use crate::pb::ownership;
+
+// The reference registry emits `AccountCreated`, the canonical one (v0.3) `ERC6551AccountCreated`.
+pub const REGISTRY_ADDRESSES: [[u8; 20]; 2] = [
+    hex!("02101dfb77fde026414827fdc604ddaf224f0921"),
+    hex!("000000006551c19487814612e58fe06813775758"),
+];
+
+pub fn account_key(account: &[u8]) -> String {
+    format!("account:{}", Hex(account))
+}
+
+pub fn accounts_from_block(blk: &eth::Block) -> Vec<ownership::TokenBoundAccount> {
+    let header = blk.header.as_ref().unwrap();
+
+    blk.receipts()
+        .flat_map(|receipt| {
+            let hash = &receipt.transaction.hash;
+            let timestamp = Some(header.timestamp.as_ref().unwrap().clone());
+
+            receipt
+                .receipt
+                .logs
+                .iter()
+                .filter(|log| REGISTRY_ADDRESSES.iter().any(|address| log.address == address))
+                .filter_map(move |log| {
+                    let mut account = if let Some(event) = AccountCreatedEvent::match_and_decode(log) {
+                        ownership::TokenBoundAccount {
+                            account: event.account,
+                            implementation: event.implementation,
+                            chain_id: event.chain_id.to_string(),
+                            token_contract: event.token_contract,
+                            token_id: event.token_id.to_bytes_be().1,
+                            salt: event.salt.to_bytes_be().1,
+                            ..Default::default()
+                        }
+                    } else if let Some(event) = Erc6551AccountCreatedEvent::match_and_decode(log) {
+                        ownership::TokenBoundAccount {
+                            account: event.account,
+                            implementation: event.implementation,
+                            chain_id: event.chain_id.to_string(),
+                            token_contract: event.token_contract,
+                            token_id: event.token_id.to_bytes_be().1,
+                            salt: event.salt.to_vec(),
+                            ..Default::default()
+                        }
+                    } else {
+                        return None;
+                    };
+
+                    account.registry = log.address.to_vec();
+                    account.tx_hash = hash.to_vec();
+                    account.ordinal = log.block_index as u64;
+                    account.block_number = blk.number;
+                    account.timestamp = timestamp.clone();
+
+                    Some(account)
+                })
+        })
+        .collect()
+}
+
+/// Indexes the token-bound accounts by address, so transfers to them can be attributed to the
+/// NFT owning the account.
+pub fn store_accounts(
+    accounts: &[ownership::TokenBoundAccount],
+    store: &StoreSetProto<ownership::TokenBoundAccount>,
+) {
+    for account in accounts {
+        store.set(account.ordinal, account_key(&account.account), account);
+    }
+}
+
+pub fn push_account_create(changes: &mut DatabaseChanges, value: ownership::TokenBoundAccount) {
+    let key = Hex(&value.account).to_string();
+
+    changes
+        .push_change("token_bound_accounts", &key, value.ordinal, Operation::Create)
+        .change("account", (None, Hex(value.account)))
+        .change("implementation", (None, Hex(value.implementation)))
+        .change("chain_id", (None, value.chain_id))
+        .change("token_contract", (None, Hex(value.token_contract)))
+        .change("token_id", (None, Hex(value.token_id)))
+        .change("salt", (None, Hex(value.salt)))
+        .change("registry", (None, Hex(value.registry)))
+        .change("tx_hash", (None, Hex(value.tx_hash)))
+        .change("block_number", (None, value.block_number))
+        .change("ordinal", (None, value.ordinal))
+        .change("timestamp", (None, value.timestamp.unwrap()));
+}
@@ -0,0 +1,237 @@
+use substreams::scalar::BigInt;
+use substreams::Hex;
+use substreams_ethereum::pb::eth::rpc::RpcResponse;
+use substreams_ethereum::rpc::RpcBatch;
+
+use crate::abi::erc1155::functions::Uri as UriFunction;
+use crate::abi::erc721::functions::TokenUri as TokenUriFunction;
+use crate::rpc;
+
+// Embedded metadata (and embedded images) above this size are not stored, generative collections
+// returning full SVGs would otherwise bloat the `nfts` table.
+pub const METADATA_MAX_SIZE: usize = 64 * 1024;
+
+// Arweave URIs are made resolvable through the gateway `packages/gql` already fetches from.
+const ARWEAVE_GATEWAY: &str = "https://arweave.net/";
+
+/// The fields of the metadata embedded in a `data:` token URI.
+#[derive(Default)]
+pub struct Metadata {
+    pub json: String,
+    pub name: String,
+    pub image: String,
+    pub attributes: String,
+}
+
+/// Adds `tokenURI(id)` for ERC721 or `uri(id)` for ERC1155 to the batch.
+pub fn add_token_uri_call(batch: RpcBatch, contract_address: &[u8], token_id: &[u8], schema: &str) -> RpcBatch {
+    let token_id = BigInt::from_unsigned_bytes_be(token_id);
+
+    match schema {
+        "erc1155" => batch.add(UriFunction { param0: token_id }, contract_address.to_vec()),
+        _ => batch.add(TokenUriFunction { token_id }, contract_address.to_vec()),
+    }
+}
+
+/// Decodes the response to the call of `add_token_uri_call`, empty if the call failed.
+pub fn decode_token_uri(response: Option<&RpcResponse>, schema: &str) -> String {
+    let uri = match schema {
+        "erc1155" => rpc::decode::<_, UriFunction>(response),
+        _ => rpc::decode::<_, TokenUriFunction>(response),
+    };
+
+    uri.unwrap_or_default()
+}
+
+/// Decodes the metadata of a `data:` URI, either base64 (`data:application/json;base64,...`) or
+/// plain (`data:application/json,...`, `data:application/json;utf8,...`), possibly percent-encoded.
+/// Returns `None` when the URI is not a data URI or does not hold a JSON object.
+pub fn decode_data_uri(uri: &str) -> Option<Metadata> {
+    let uri = uri.trim();
+    if !uri.starts_with("data:") {
+        return None;
+    }
+
+    let (header, payload) = uri["data:".len()..].split_once(',')?;
+    let json = if header.split(';').any(|parameter| parameter.eq_ignore_ascii_case("base64")) {
+        let bytes = base64::decode(payload.trim()).ok()?;
+        String::from_utf8(bytes).ok()?
+    } else {
+        percent_decode(payload)
+    };
+
+    let value: serde_json::Value = serde_json::from_str(&json).ok()?;
+    let object = value.as_object()?;
+
+    let field = |name: &str| match object.get(name) {
+        Some(serde_json::Value::String(value)) => cap(value.clone()),
+        _ => String::new(),
+    };
+    let attributes = match object.get("attributes") {
+        Some(attributes) if !attributes.is_null() => cap(attributes.to_string()),
+        _ => String::new(),
+    };
+
+    Some(Metadata {
+        json: cap(json),
+        name: field("name"),
+        image: field("image"),
+        attributes,
+    })
+}
+
+/// Drops the values above `METADATA_MAX_SIZE`, such as `data:` token URIs embedding a full SVG.
+pub fn cap(value: String) -> String {
+    if value.len() <= METADATA_MAX_SIZE {
+        value
+    } else {
+        String::new()
+    }
+}
+
+/// Normalizes a raw token URI into a canonical resolvable form:
+/// - IPFS URIs (`ipfs://ipfs/<cid>`, `https://<gateway>/ipfs/<cid>`, `https://<cid>.ipfs.<gateway>`
+///   and bare CIDs) become `ipfs://<cid>[/<path>]`,
+/// - Arweave URIs (`ar://<id>`) become `https://arweave.net/<id>`,
+/// - the ERC1155 `{id}` template is substituted by the lowercase hex ID, padded to 64 characters.
+///
+/// Other URIs, including `data:` URIs, are returned as is.
+pub fn canonical_uri(uri: &str, token_id: &[u8]) -> String {
+    let uri = uri.trim();
+    if uri.starts_with("data:") {
+        return uri.to_string();
+    }
+
+    let uri = if uri.contains("{id}") {
+        let id = format!("{:0>64}", Hex(token_id).to_string());
+        uri.replace("{id}", &id)
+    } else {
+        uri.to_string()
+    };
+
+    if let Some(path) = strip_prefix_ignore_case(&uri, "ipfs://") {
+        return format!("ipfs://{}", path.trim_start_matches("ipfs/"));
+    }
+    if let Some(path) = strip_prefix_ignore_case(&uri, "ar://") {
+        return format!("{}{}", ARWEAVE_GATEWAY, path);
+    }
+    if is_cid(uri.split('/').next().unwrap_or_default()) {
+        return format!("ipfs://{}", uri);
+    }
+
+    let location = strip_prefix_ignore_case(&uri, "https://").or_else(|| strip_prefix_ignore_case(&uri, "http://"));
+    if let Some(location) = location {
+        let (host, path) = location.split_once('/').unwrap_or((location, ""));
+
+        // Path gateways, `https://ipfs.io/ipfs/<cid>/1.json`.
+        if let Some(path) = path.strip_prefix("ipfs/") {
+            if is_cid(path.split('/').next().unwrap_or_default()) {
+                return format!("ipfs://{}", path);
+            }
+        }
+
+        // Subdomain gateways, `https://<cid>.ipfs.dweb.link/1.json`.
+        if let Some((cid, _)) = host.split_once(".ipfs.") {
+            if is_cid(cid) && path.is_empty() {
+                return format!("ipfs://{}", cid);
+            } else if is_cid(cid) {
+                return format!("ipfs://{}/{}", cid, path);
+            }
+        }
+    }
+
+    uri
+}
+
+fn strip_prefix_ignore_case<'a>(value: &'a str, prefix: &str) -> Option<&'a str> {
+    match value.get(..prefix.len()) {
+        Some(start) if start.eq_ignore_ascii_case(prefix) => Some(&value[prefix.len()..]),
+        _ => None,
+    }
+}
+
+// CIDv0 are base58 multihashes starting with `Qm`, CIDv1 are usually encoded in base32 and start
+// with `b` (`bafy...` for dag-pb). Both are only made of alphanumeric characters.
+fn is_cid(value: &str) -> bool {
+    let cid_v0 = value.len() == 46 && value.starts_with("Qm");
+    let cid_v1 = value.len() >= 50 && value.starts_with("baf");
+
+    (cid_v0 || cid_v1) && value.chars().all(|c| c.is_ascii_alphanumeric())
+}
+
+fn percent_decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+
+    let mut i = 0;
+    while i < bytes.len() {
+        let escaped = if bytes[i] == b'%' && i + 2 < bytes.len() {
+            std::str::from_utf8(&bytes[i + 1..i + 3])
+                .ok()
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+        } else {
+            None
+        };
+
+        match escaped {
+            Some(byte) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            None => {
+                decoded.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_decode_data_uri() {
+        let metadata = decode_data_uri(
+            "data:application/json;base64,eyJuYW1lIjoiIzEiLCJpbWFnZSI6ImlwZnM6Ly9RbSIsImF0dHJpYnV0ZXMiOlt7InRyYWl0X3R5cGUiOiJCZyIsInZhbHVlIjoiUmVkIn1dfQ==",
+        )
+        .unwrap();
+        assert_eq!(metadata.name, "#1");
+        assert_eq!(metadata.image, "ipfs://Qm");
+        assert_eq!(metadata.attributes, r#"[{"trait_type":"Bg","value":"Red"}]"#);
+
+        let metadata = decode_data_uri("data:application/json;utf8,%7B%22name%22%3A%22%231%22%7D").unwrap();
+        assert_eq!(metadata.json, r##"{"name":"#1"}"##);
+        assert_eq!(metadata.name, "#1");
+        assert_eq!(metadata.attributes, "");
+
+        let attributes = format!(r#"[{{"trait_type":"Svg","value":"{}"}}]"#, "x".repeat(METADATA_MAX_SIZE));
+        let metadata = decode_data_uri(&format!(r##"data:application/json,{{"name":"#1","attributes":{}}}"##, attributes));
+        let metadata = metadata.unwrap();
+        assert_eq!(metadata.json, "");
+        assert_eq!(metadata.name, "#1");
+        assert_eq!(metadata.attributes, "");
+
+        assert!(decode_data_uri("ipfs://Qm/1.json").is_none());
+        assert!(decode_data_uri("data:application/json,not json").is_none());
+    }
+
+    #[test]
+    fn it_canonical_uri() {
+        let cid = "QmUpPCBsGayB41RWVn81NUmscZbmPHNQYzY7fyh2LVLkCV";
+        let expected = format!("ipfs://{}/1.json", cid);
+
+        assert_eq!(canonical_uri(&format!("ipfs://{}/1.json", cid), &[1]), expected);
+        assert_eq!(canonical_uri(&format!("ipfs://ipfs/{}/1.json", cid), &[1]), expected);
+        assert_eq!(canonical_uri(&format!("https://gateway.pinata.cloud/ipfs/{}/1.json", cid), &[1]), expected);
+        assert_eq!(canonical_uri(&format!("{}/1.json", cid), &[1]), expected);
+        assert_eq!(canonical_uri("ar://abc/1.json", &[1]), "https://arweave.net/abc/1.json");
+        assert_eq!(
+            canonical_uri("https://api.example.com/{id}.json", &[0x01, 0x2a]),
+            "https://api.example.com/000000000000000000000000000000000000000000000000000000000000012a.json"
+        );
+        assert_eq!(canonical_uri("https://api.example.com/1", &[1]), "https://api.example.com/1");
+    }
+}
@@ -0,0 +1,310 @@
+use substreams::scalar::BigInt;
+use substreams::store::{
+    StoreGet, StoreGetBigInt, StoreGetInt64, StoreMin, StoreMinInt64, StoreSet, StoreSetBigInt, StoreSetInt64,
+};
+use substreams::Hex;
+use substreams_ethereum::pb::eth::v2 as eth;
+
+use crate::pb::sales;
+use crate::pb::sales::sale::WashReason;
+use crate::pb::transfers;
+use crate::wash_reason_to_string;
+
+// Sales at or above this score are left out of the market stats.
+pub const WASH_SCORE_THRESHOLD: u32 = 50;
+
+const SCORE_FUNDED_BY_COUNTERPARTY: u32 = 50;
+const SCORE_ROUND_TRIP: u32 = 40;
+const SCORE_ZERO_COST: u32 = 30;
+
+// An NFT coming back to a wallet that sold it within this window is a round trip.
+const ROUND_TRIP_WINDOW_SECONDS: i64 = 30 * 24 * 60 * 60;
+
+const ZERO_ADDRESS: [u8; 20] = [0u8; 20];
+
+pub fn funding_key(from: &[u8], to: &[u8]) -> String {
+    format!("funding:{}:{}", Hex(from), Hex(to))
+}
+
+pub fn sold_key(contract_address: &[u8], token_id: &[u8], account: &[u8]) -> String {
+    format!("sold:{}:{}:{}", Hex(contract_address), Hex(token_id), Hex(account))
+}
+
+pub fn cost_key(contract_address: &[u8], token_id: &[u8], account: &[u8]) -> String {
+    format!("cost:{}:{}:{}", Hex(contract_address), Hex(token_id), Hex(account))
+}
+
+/// Records the block at which each wallet first sent ETH to another one, from the calls with a value
+/// that were not reverted.
+pub fn store_funding(blk: &eth::Block, store: &StoreMinInt64) {
+    let block_number = blk.number as i64;
+
+    for receipt in blk.receipts() {
+        for call in receipt.transaction.calls.iter().filter(|call| !call.state_reverted) {
+            let has_value = call
+                .value
+                .as_ref()
+                .map(|value| value.bytes.iter().any(|byte| *byte != 0))
+                .unwrap_or(false);
+            if !has_value || call.caller == call.address {
+                continue;
+            }
+
+            store.min(call.begin_ordinal, funding_key(&call.caller, &call.address), block_number);
+        }
+    }
+}
+
+/// Records when each wallet last sent away each NFT, as the timestamp of the block in seconds.
+pub fn store_sold_at(transfers: &[transfers::Transfer], store: &StoreSetInt64) {
+    for transfer in transfers.iter().filter(|transfer| transfer.from_address != ZERO_ADDRESS) {
+        let seconds = transfer.timestamp.as_ref().map(|timestamp| timestamp.seconds).unwrap_or_default();
+
+        store.set(
+            transfer.ordinal,
+            sold_key(&transfer.contract_address, &transfer.token_id, &transfer.from_address),
+            &seconds,
+        );
+    }
+}
+
+/// Records what each wallet paid for each NFT it received, zero when it was transferred without a
+/// sale. Minted NFTs are left out, their cost being tracked by the mints.
+pub fn store_costs(transfers: &[transfers::Transfer], sales: &[sales::Sale], store: &StoreSetBigInt) {
+    for (ordinal, key, cost) in costs(transfers, sales) {
+        store.set(ordinal, key, &cost);
+    }
+}
+
+// The costs to set, in ordinal order. The cost of a sale is keyed on the ordinal of the transfer
+// of the NFT to the buyer, since sales of some marketplaces take the ordinal of their settlement
+// event, and overrides the zero cost of that transfer.
+fn costs(transfers: &[transfers::Transfer], sales: &[sales::Sale]) -> Vec<(u64, String, BigInt)> {
+    let transfers: Vec<&transfers::Transfer> = transfers
+        .iter()
+        .filter(|transfer| transfer.from_address != ZERO_ADDRESS)
+        .collect();
+
+    let mut costs: Vec<(u64, String, BigInt)> = transfers
+        .iter()
+        .map(|transfer| {
+            let key = cost_key(&transfer.contract_address, &transfer.token_id, &transfer.to_address);
+
+            (transfer.ordinal, key, BigInt::zero())
+        })
+        .collect();
+
+    for sale in sales {
+        let price = BigInt::try_from(sale.price.clone()).unwrap_or_else(|_| BigInt::zero());
+        let ordinal = transfers
+            .iter()
+            .find(|transfer| {
+                transfer.tx_hash == sale.tx_hash
+                    && transfer.contract_address == sale.contract_address
+                    && transfer.token_id == sale.token_id
+                    && transfer.to_address == sale.buyer
+            })
+            .map(|transfer| transfer.ordinal)
+            .unwrap_or(sale.ordinal);
+
+        costs.push((ordinal, cost_key(&sale.contract_address, &sale.token_id, &sale.buyer), price));
+    }
+
+    // The sort is stable, so the cost of a sale comes after the zero cost of its transfer.
+    costs.sort_by_key(|(ordinal, _, _)| *ordinal);
+
+    costs
+}
+
+// What the stores know about the counterparties of a sale.
+#[derive(Default)]
+struct History {
+    // The blocks at which the buyer first sent ETH to the seller and the other way around.
+    funded_at: Vec<u64>,
+    // When the buyer last sent the NFT away, in seconds.
+    buyer_sold_at: Option<i64>,
+    // What the seller paid for the NFT.
+    seller_cost: Option<BigInt>,
+}
+
+/// Scores how likely each sale is a wash trade from the funding, transfer and sale history of its
+/// buyer and seller.
+pub fn score(
+    mut sales: Vec<sales::Sale>,
+    funding: &StoreGetInt64,
+    sold_at: &StoreGetInt64,
+    costs: &StoreGetBigInt,
+) -> Vec<sales::Sale> {
+    for sale in sales.iter_mut() {
+        let history = History {
+            funded_at: [
+                funding_key(&sale.buyer, &sale.seller),
+                funding_key(&sale.seller, &sale.buyer),
+            ]
+            .iter()
+            .filter_map(|key| funding.get_last(key))
+            .map(|block_number| block_number as u64)
+            .collect(),
+            buyer_sold_at: sold_at.get_at(sale.ordinal, sold_key(&sale.contract_address, &sale.token_id, &sale.buyer)),
+            seller_cost: costs.get_at(sale.ordinal, cost_key(&sale.contract_address, &sale.token_id, &sale.seller)),
+        };
+
+        score_sale(sale, &history);
+    }
+
+    sales
+}
+
+fn score_sale(sale: &mut sales::Sale, history: &History) {
+    let mut reasons: Vec<(WashReason, u32)> = Vec::new();
+
+    // Funding within the sale transaction is the payment itself, only earlier blocks count.
+    if history.funded_at.iter().any(|block_number| *block_number < sale.block_number) {
+        reasons.push((WashReason::FundedByCounterparty, SCORE_FUNDED_BY_COUNTERPARTY));
+    }
+
+    let seconds = sale.timestamp.as_ref().map(|timestamp| timestamp.seconds).unwrap_or_default();
+    let round_trip = history
+        .buyer_sold_at
+        .map(|sold_at| seconds - sold_at <= ROUND_TRIP_WINDOW_SECONDS)
+        .unwrap_or(false);
+    if round_trip {
+        reasons.push((WashReason::RoundTrip, SCORE_ROUND_TRIP));
+    }
+
+    let zero_cost = sale.seller != ZERO_ADDRESS && history.seller_cost == Some(BigInt::zero());
+    if zero_cost {
+        reasons.push((WashReason::ZeroCost, SCORE_ZERO_COST));
+    }
+
+    sale.wash_score = reasons.iter().map(|(_, score)| score).sum::<u32>().min(100);
+    sale.wash_reasons = reasons
+        .into_iter()
+        .map(|(reason, _)| wash_reason_to_string(reason))
+        .collect();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prost_types::Timestamp;
+
+    const DAY_SECONDS: i64 = 24 * 60 * 60;
+
+    fn sale() -> sales::Sale {
+        sales::Sale {
+            contract_address: vec![0x11; 20],
+            token_id: vec![0x2a],
+            seller: vec![0x01; 20],
+            buyer: vec![0x02; 20],
+            price: "100".to_string(),
+            tx_hash: vec![0xaa; 32],
+            ordinal: 10,
+            block_number: 1_000,
+            timestamp: Some(Timestamp {
+                seconds: 100 * DAY_SECONDS,
+                nanos: 0,
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn scored(history: History) -> sales::Sale {
+        let mut sale = sale();
+        score_sale(&mut sale, &history);
+
+        sale
+    }
+
+    #[test]
+    fn it_score_funded_by_counterparty() {
+        let sale = scored(History {
+            funded_at: vec![999],
+            ..Default::default()
+        });
+        assert_eq!(sale.wash_score, SCORE_FUNDED_BY_COUNTERPARTY);
+        assert_eq!(sale.wash_reasons, vec![wash_reason_to_string(WashReason::FundedByCounterparty)]);
+
+        // Funding in the block of the sale is the payment.
+        let sale = scored(History {
+            funded_at: vec![1_000],
+            ..Default::default()
+        });
+        assert_eq!(sale.wash_score, 0);
+        assert!(sale.wash_reasons.is_empty());
+    }
+
+    #[test]
+    fn it_score_round_trip() {
+        let sale = scored(History {
+            buyer_sold_at: Some(80 * DAY_SECONDS),
+            ..Default::default()
+        });
+        assert_eq!(sale.wash_score, SCORE_ROUND_TRIP);
+        assert_eq!(sale.wash_reasons, vec![wash_reason_to_string(WashReason::RoundTrip)]);
+
+        let sale = scored(History {
+            buyer_sold_at: Some(60 * DAY_SECONDS),
+            ..Default::default()
+        });
+        assert_eq!(sale.wash_score, 0);
+    }
+
+    #[test]
+    fn it_score_zero_cost() {
+        let sale = scored(History {
+            seller_cost: Some(BigInt::zero()),
+            ..Default::default()
+        });
+        assert_eq!(sale.wash_score, SCORE_ZERO_COST);
+        assert_eq!(sale.wash_reasons, vec![wash_reason_to_string(WashReason::ZeroCost)]);
+
+        let sale = scored(History {
+            seller_cost: Some(BigInt::from(100)),
+            ..Default::default()
+        });
+        assert_eq!(sale.wash_score, 0);
+    }
+
+    #[test]
+    fn it_score_is_capped() {
+        let sale = scored(History {
+            funded_at: vec![999],
+            buyer_sold_at: Some(99 * DAY_SECONDS),
+            seller_cost: Some(BigInt::zero()),
+        });
+        assert_eq!(sale.wash_score, 100);
+        assert_eq!(sale.wash_reasons.len(), 3);
+    }
+
+    #[test]
+    fn it_costs_keyed_on_transfer_ordinal() {
+        let sale = sale();
+        let transfer = |from: u8, to: u8, ordinal: u64| transfers::Transfer {
+            contract_address: sale.contract_address.clone(),
+            token_id: sale.token_id.clone(),
+            from_address: vec![from; 20],
+            to_address: vec![to; 20],
+            tx_hash: sale.tx_hash.clone(),
+            ordinal,
+            ..Default::default()
+        };
+        // The settlement event of the sale (ordinal 10) comes after the transfer to the buyer
+        // (ordinal 7), and the buyer sends the NFT away afterwards (ordinal 12).
+        let transfers = vec![transfer(0x01, 0x02, 7), transfer(0x02, 0x03, 12)];
+
+        let costs: Vec<(u64, String, String)> = costs(&transfers, std::slice::from_ref(&sale))
+            .into_iter()
+            .map(|(ordinal, key, cost)| (ordinal, key, cost.to_string()))
+            .collect();
+        let key = |account: u8| cost_key(&sale.contract_address, &sale.token_id, &[account; 20]);
+        assert_eq!(
+            costs,
+            vec![
+                (7, key(0x02), "0".to_string()),
+                (7, key(0x02), "100".to_string()),
+                (12, key(0x03), "0".to_string()),
+            ]
+        );
+    }
+}